@@ -117,11 +117,41 @@ fn parse_complex_time_sentence(bench: &mut Bencher) {
     bench.iter(|| parser.parse(&sentence, &context));
 }
 
+/// Relative phrases resolved again and again against the same reference, as by a service
+const REPEATED_PHRASES: [&str; 4] = [
+    "tomorrow at 5pm",
+    "next monday",
+    "in 3 days",
+    "the last friday of the month",
+];
+
+fn parse_repeated_phrases(bench: &mut Bencher) {
+    let parser = build_parser(Lang::EN).unwrap();
+    let context = ResolverContext::default();
+    bench.iter(|| {
+        for sentence in REPEATED_PHRASES.iter() {
+            let _ = parser.parse(sentence, &context);
+        }
+    });
+}
+
+fn parse_repeated_phrases_cached(bench: &mut Bencher) {
+    let parser = build_parser(Lang::EN).unwrap();
+    let context = ResolverContext::default().with_cache(256);
+    bench.iter(|| {
+        for sentence in REPEATED_PHRASES.iter() {
+            let _ = parser.parse(sentence, &context);
+        }
+    });
+}
+
 benchmark_group!(benches,
                  parse_small_numbers,
                  parse_big_numbers,
                  parse_intent_sentences,
-                 parse_complex_time_sentence
+                 parse_complex_time_sentence,
+                 parse_repeated_phrases,
+                 parse_repeated_phrases_cached
                  );
 
 benchmark_main!(benches);
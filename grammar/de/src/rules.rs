@@ -1356,18 +1356,20 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("dd/mm/yy or dd/mm/yyyy",
                       b.reg(r#"(0?[1-9]|[12]\d|3[01])[-\./](0?[1-9]|1[0-2])[-\./](\d{2,4})"#)?,
-                      |text_match| helpers::year_month_day(
-                          text_match.group(3).parse()?,
-                          text_match.group(2).parse()?,
+                      |text_match| helpers::numeric_date(
+                          DateOrder::DayMonthYear,
+                          Some(text_match.group(3).parse()?),
                           text_match.group(1).parse()?,
-                      )
+                          text_match.group(2).parse()?)
     );
     // Warning: this pattern matches for months: (1[0-2]|0?[1-9]) but not this one: (0?[1-9]|1[0-2])
     b.rule_1_terminal("dd/mm",
                       b.reg(r#"(0?[1-9]|[12]\d|3[01])[\./](1[0-2]|0?[1-9])\.?"#)?,
-                      |text_match| helpers::month_day(
-                          text_match.group(2).parse()?,
-                          text_match.group(1).parse()?)
+                      |text_match| helpers::numeric_date(
+                          DateOrder::DayMonthYear,
+                          None,
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)
     );
     // End of Written dates in numeric formats
     b.rule_1_terminal("breakfast (latent)",
@@ -2032,6 +2034,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: temp.value().unit,
                  latent: temp.value().latent,
+                 qualifier: None,
              })
     );
     b.rule_2("<temperature> minus",
@@ -2041,6 +2044,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: -1.0 * temp.value().value,
                  unit: temp.value().unit,
                  latent: temp.value().latent,
+                 qualifier: None,
              })
     );
     b.rule_1("number as temp",
@@ -2049,6 +2053,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value(),
                  unit: None,
                  latent: true,
+                 qualifier: None,
              })
     );
     b.rule_2("below <temp>",
@@ -2060,6 +2065,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: temp.value().latent,
+                         qualifier: None,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -2075,6 +2081,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         qualifier: None,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -2090,6 +2097,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         qualifier: None,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -2105,6 +2113,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         qualifier: None,
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -2118,6 +2127,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("degree"),
                  latent: false,
+                 qualifier: None,
              })
     );
     b.rule_2("<temp> celsius",
@@ -2127,6 +2137,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("celsius"),
                  latent: false,
+                 qualifier: None,
              })
     );
     b.rule_2("<temp> kelvin",
@@ -2136,6 +2147,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("kelvin"),
                  latent: false,
+                 qualifier: None,
              })
     );
     b.rule_2("<temp> fahrenheit",
@@ -2145,6 +2157,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("fahrenheit"),
                  latent: false,
+                 qualifier: None,
              })
     );
     b.rule_2("<temp> °F",
@@ -2154,6 +2167,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: temp.value().value,
                  unit: Some("fahrenheit"),
                  latent: false,
+                 qualifier: None,
              })
     );
     Ok(())
@@ -2355,6 +2369,10 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
                       |text_match| FloatValue::new(text_match.group(1).replace(".", "").replace(",", ".").parse()?)
     );
+    b.rule_1_terminal("number in scientific notation",
+                      b.reg(r#"(\d*,?\d+)e([-+]?\d{1,3})"#)?,
+                      |text_match| helpers::scientific_number(&text_match.group(1).replace(",", "."), text_match.group(2))
+    );
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|minus|negativ"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
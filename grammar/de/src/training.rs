@@ -328,6 +328,8 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     //example!(v, check_float(32.75), "32,75", "zweiunddreißig komma fünfundsiebzig");
     example!(v, check_float(10.08), "10,08", "zehn komma null acht");
     example!(v, check_float(1000000.0), "1.000.000,00");
+    example!(v, check_float(1000.5), "1.000,50");
+    example!(v, check_integer(1500), "1,5e3");
     example!(v, check_ordinal(44), "der vierundvierzigste");
     example!(v, check_integer(455628), "vierhundertfünfundfünfzigtausendsechshundertachtundzwanzig");
 }
\ No newline at end of file
@@ -17,20 +17,31 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
                     ::rustling::BoundariesChecker::detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules_number::rules_numbers(&mut b)?;
+    rules_number::rules_ordinal_range(&mut b)?;
+    rules_number::rules_number_range(&mut b)?;
+    rules_number::rules_number_comparison(&mut b)?;
+    rules_number::rules_phone_number(&mut b)?;
     rules_amount::rules_temperature(&mut b)?;
+    rules_amount::rules_temperature_range(&mut b)?;
     rules_amount::rules_finance(&mut b)?;
     rules_amount::rules_percentage(&mut b)?;
+    rules_amount::rules_quantity(&mut b)?;
     rules_datetime::rules_cycle(&mut b)?;
     rules_datetime::rules_datetime(&mut b)?;
     rules_datetime::rules_datetime_with_duration(&mut b)?;
     rules_datetime::rules_datetime_with_cycle(&mut b)?;
+    rules_datetime::rules_datetime_recurring(&mut b)?;
     rules_celebrations::rules_celebration(&mut b)?;
     rules_duration::rules_duration(&mut b)?;
+    rules_duration::rules_age(&mut b)?;
     Ok(b.build())
 }
 
+/// Dimensions parsed by the rules of the language
+pub const DIMS: &[rustling_ontology_values::DimensionKind] = &[Number, Ordinal, OrdinalRange, Datetime, Duration, Temperature, AmountOfMoney, Percentage, Quantity, PhoneNumber, Age, NumberRange, NumberComparison, TemperatureRange];
+
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage];
+    DIMS.to_vec()
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
@@ -38,9 +49,13 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     training::examples_numbers(&mut v);
     training::examples_datetime(&mut v);
     training::examples_durations(&mut v);
+    training::examples_age(&mut v);
     training::examples_temperature(&mut v);
+    training::examples_temperature_range(&mut v);
     training::examples_finance(&mut v);
     training::examples_percentage(&mut v);
+    training::examples_quantity(&mut v);
+    training::examples_recurring(&mut v);
     v
 }
 
@@ -66,4 +81,18 @@ mod test {
         let examples = examples();
         assert_examples(&rules, examples);
     }
+    #[test]
+    fn test_counter_examples() {
+        let rules = rule_set().unwrap();
+        let mut counter_examples = vec![];
+        training::counter_examples(&mut counter_examples);
+        for (kind, text) in counter_examples {
+            let stash = rules.apply_all(&text.to_lowercase()).unwrap();
+            let full_matches = stash
+                        .into_iter()
+                        .filter(|candidate| candidate.root_node.byte_range == Range(0, text.len()) && candidate.value.kind() == kind)
+                        .count();
+            assert_eq!(0, full_matches, "Unexpected full match of {:?} for: {:?}", kind, text);
+        }
+    }
 }
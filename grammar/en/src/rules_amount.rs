@@ -9,6 +9,36 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
         b.reg(r"(?:%|p\.c\.|per ?cents?)")?,
         |number, _| Ok(PercentageValue(number.value().value()))
     );
+    // The digits are read as written to keep their leading zeros, e.g. "point 05 percent"
+    b.rule_2("point <digits> per cent",
+        b.reg(r#"(?:dot|point) (\d+)"#)?,
+        b.reg(r"(?:%|p\.c\.|per ?cents?)")?,
+        |digits, _| {
+            let value: f64 = format!("0.{}", digits.group(1)).parse()?;
+            Ok(PercentageValue(value))
+        }
+    );
+    b.rule_3("point <integer> per cent",
+        b.reg(r#"dot|point"#)?,
+        integer_check!(|integer: &IntegerValue| !integer.prefixed),
+        b.reg(r"(?:%|p\.c\.|per ?cents?)")?,
+        |_, integer, _| {
+            let value: f64 = format!("0.{}", integer.value().value).parse()?;
+            Ok(PercentageValue(value))
+        }
+    );
+    b.rule_1_terminal("half a per cent",
+        b.reg(r"(?:an? )?half(?: of)?(?: an?| one)? per ?cents?")?,
+        |_| Ok(PercentageValue(0.5))
+    );
+    b.rule_1_terminal("a quarter of a per cent",
+        b.reg(r"(?:an? |one )?quarter(?: of)?(?: an?| one)? per ?cents?")?,
+        |_| Ok(PercentageValue(0.25))
+    );
+    b.rule_1_terminal("three quarters of a per cent",
+        b.reg(r"three quarters?(?: of)?(?: an?| one)? per ?cents?")?,
+        |_| Ok(PercentageValue(0.75))
+    );
     Ok(())
 }
 
@@ -156,6 +186,103 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    // Mass
+    b.rule_2("<number> milligrams",
+             number_check!(),
+             b.reg(r#"(?:mg|milligrams?|milligrammes?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "milligram"))
+    );
+    b.rule_2("<number> grams",
+             number_check!(),
+             b.reg(r#"(?:gr|grams?|grammes?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "gram"))
+    );
+    // A lone letter is only a unit right after the digits, e.g. "250g"
+    b.rule_1_terminal("<number>g (grams)",
+                      b.reg(r#"(\d+(?:\.\d+)?)g"#)?,
+                      |text_match| Ok(QuantityValue::new(text_match.group(1).parse()?, "gram"))
+    );
+    b.rule_2("<number> kilograms",
+             number_check!(),
+             b.reg(r#"(?:kg|kilos?|kilograms?|kilogrammes?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "kilogram"))
+    );
+    b.rule_2("<number> ounces",
+             number_check!(),
+             b.reg(r#"(?:oz|ounces?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "ounce"))
+    );
+    // Volume
+    b.rule_2("<number> millilitres",
+             number_check!(),
+             b.reg(r#"(?:ml|millilit(?:er|re)s?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "millilitre"))
+    );
+    b.rule_2("<number> centilitres",
+             number_check!(),
+             b.reg(r#"(?:cl|centilit(?:er|re)s?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "centilitre"))
+    );
+    b.rule_2("<number> litres",
+             number_check!(),
+             b.reg(r#"lit(?:er|re)s?\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "litre"))
+    );
+    b.rule_1_terminal("<number>l (litres)",
+                      b.reg(r#"(\d+(?:\.\d+)?)l"#)?,
+                      |text_match| Ok(QuantityValue::new(text_match.group(1).parse()?, "litre"))
+    );
+    b.rule_2("<number> gallons",
+             number_check!(),
+             b.reg(r#"(?:gal|gallons?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "gallon"))
+    );
+    // Distance
+    b.rule_2("<number> millimetres",
+             number_check!(),
+             b.reg(r#"(?:mm|millimet(?:er|re)s?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "millimetre"))
+    );
+    b.rule_2("<number> centimetres",
+             number_check!(),
+             b.reg(r#"(?:cm|centimet(?:er|re)s?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "centimetre"))
+    );
+    // "5m" is left to the minutes and the millions
+    b.rule_2("<number> metres",
+             number_check!(),
+             b.reg(r#"met(?:er|re)s?\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "metre"))
+    );
+    b.rule_2("<number> kilometres",
+             number_check!(),
+             b.reg(r#"(?:km|kilomet(?:er|re)s?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "kilometre"))
+    );
+    b.rule_2("<number> feet",
+             number_check!(),
+             b.reg(r#"(?:ft|foot|feet)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "foot"))
+    );
+    b.rule_2("<number> yards",
+             number_check!(),
+             b.reg(r#"(?:yd|yards?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "yard"))
+    );
+    b.rule_2("<number> miles",
+             number_check!(),
+             b.reg(r#"(?:mi|miles?)\.?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), "mile"))
+    );
+    b.rule_2("<quantity> of <product>",
+             quantity_check!(|quantity: &QuantityValue| quantity.product.is_none()),
+             b.reg(r#"of ([a-z]+)"#)?,
+             |quantity, text_match| Ok(quantity.value().clone().product(&*text_match.group(1)))
+    );
+    Ok(())
+}
+
 pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("number as temp",
              number_check!(),
@@ -164,6 +291,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: None,
                      latent: true,
+                     qualifier: None,
                  })
              });
     b.rule_2("<latent temp> degrees",
@@ -174,6 +302,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: true,
+                     qualifier: a.value().qualifier,
                  })
              });
     b.rule_2("<temp> Celcius",
@@ -184,6 +313,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: a.value().qualifier,
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -194,6 +324,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: a.value().qualifier,
                  })
              });
     b.rule_2("<temp> Kelvin",
@@ -204,8 +335,78 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("kelvin"),
                      latent: false,
+                     qualifier: a.value().qualifier,
                  })
              });
+    b.rule_2("feels like <temp>",
+             b.reg(r#"feels? like|feeling like"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             |_, a| Ok(TemperatureValue {
+                 latent: false,
+                 qualifier: Some(TemperatureQualifier::FeelsLike),
+                 ..a.value().clone()
+             }));
+    b.rule_2("wind chill of <temp>",
+             b.reg(r#"(?:a |the )?wind ?chill(?: of| at| is)?"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             |_, a| Ok(TemperatureValue {
+                 latent: false,
+                 qualifier: Some(TemperatureQualifier::WindChill),
+                 ..a.value().clone()
+             }));
+    b.rule_2("heat index of <temp>",
+             b.reg(r#"(?:a |the )?heat index(?: of| at| is)?"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             |_, a| Ok(TemperatureValue {
+                 latent: false,
+                 qualifier: Some(TemperatureQualifier::HeatIndex),
+                 ..a.value().clone()
+             }));
+    b.rule_2("actual temperature of <temp>",
+             b.reg(r#"(?:the )?actual temperature(?: of| is)?"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             |_, a| Ok(TemperatureValue {
+                 latent: false,
+                 qualifier: Some(TemperatureQualifier::Actual),
+                 ..a.value().clone()
+             }));
     Ok(())
 }
 
+
+pub fn rules_temperature_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    // A unit given once applies to both bounds, e.g. "20 to 25 degrees", but there must be one
+    // for the numbers to be temperatures
+    let with_unit = |low: &TemperatureValue, high: &TemperatureValue| {
+        if low.unit.is_none() && high.unit.is_none() {
+            Err(RuleError::Invalid.into())
+        } else {
+            TemperatureRangeValue::new(low.clone(), high.clone())
+        }
+    };
+    b.rule_3("<temp> to <temp>",
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             b.reg(r#"to|through|thru|-|–"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             move |low, _, high| with_unit(low.value(), high.value()));
+    b.rule_4("between <temp> and <temp>",
+             b.reg(r#"from|between"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             b.reg(r#"to|through|thru|and|-|–"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             move |_, low, _, high| with_unit(low.value(), high.value()));
+    // Forecasts, e.g. "a high of 30, low of 18"
+    b.rule_4("a high of <temp>, low of <temp>",
+             b.reg(r#"(?:a |the )?highs? (?:of|around|near)"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             b.reg(r#",?\s*(?:and |with )?(?:a |the )?lows? (?:of|around|near)"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             |_, high, _, low| TemperatureRangeValue::new(low.value().clone(), high.value().clone()));
+    b.rule_4("a low of <temp>, high of <temp>",
+             b.reg(r#"(?:a |the )?lows? (?:of|around|near)"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             b.reg(r#",?\s*(?:and |with )?(?:a |the )?highs? (?:of|around|near)"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.qualifier.is_none()),
+             |_, low, _, high| TemperatureRangeValue::new(low.value().clone(), high.value().clone()));
+    Ok(())
+}
@@ -4,7 +4,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
 
 
 pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
@@ -152,23 +152,24 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"december|dec\.?"#)?,
                       |_| helpers::month(12)
     );
-    // Quarters identified by an ordinal are similar to months
+    // Quarters identified by an ordinal are similar to months, they are quarters of the fiscal
+    // year when the resolver is given its start
     b.rule_2("<ordinal> quarter",
              ordinal_check_by_range!(1, 4),
              cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
-             |ordinal, _| helpers::cycle_nth_after(Grain::Quarter, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+             |ordinal, _| Ok(helpers::cycle_nth_after(Grain::Quarter, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)?.mark_fiscal_quarter())
     );
     b.rule_3("the <ordinal> quarter",
              b.reg(r#"the"#)?,
              ordinal_check_by_range!(1, 4),
              cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
-             |_, ordinal, _| helpers::cycle_nth_after(Grain::Quarter, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+             |_, ordinal, _| Ok(helpers::cycle_nth_after(Grain::Quarter, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)?.mark_fiscal_quarter())
     );
     b.rule_3("<ordinal> quarter <year>",
              ordinal_check_by_range!(1, 4),
              cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
              datetime_check!(form!(Form::Year(_))),
-             |ordinal, _, datetime| helpers::cycle_nth_after(Grain::Quarter, ordinal.value().value - 1, datetime.value())
+             |ordinal, _, datetime| Ok(helpers::cycle_nth_after(Grain::Quarter, ordinal.value().value - 1, datetime.value())?.mark_fiscal_quarter())
     );
     b.rule_2("Q1-4 <year>",
              b.reg(r#"q ?([1234]|one|two|three|four)"#)?,
@@ -185,9 +186,25 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      "four" => 3,
                      _ => return Err(RuleError::Invalid.into()),
                  };
-                 helpers::cycle_nth_after(Grain::Quarter, n, year.value())
+                 Ok(helpers::cycle_nth_after(Grain::Quarter, n, year.value())?.mark_fiscal_quarter())
              }
     );
+    b.rule_1_terminal("Q1-4",
+                      b.reg(r#"q([1234])"#)?,
+                      |q| {
+                          let n: i64 = q.group(1).parse()?;
+                          Ok(helpers::cycle_nth_after(Grain::Quarter, n - 1, &helpers::cycle_nth(Grain::Year, 0)?)?.mark_fiscal_quarter())
+                      }
+    );
+    b.rule_2_terminal("Q1-4 FY<year>",
+                      b.reg(r#"q ?([1234])"#)?,
+                      b.reg(r#"(?:fy|fiscal year) ?(\d{4}|\d{2})"#)?,
+                      |q, fy| {
+                          let n: i64 = q.group(1).parse()?;
+                          let year = helpers::year(fy.group(1).parse()?)?;
+                          Ok(helpers::cycle_nth_after(Grain::Quarter, n - 1, &year)?.mark_fiscal_quarter())
+                      }
+    );
     /* END OF DATETIME - DATE - STANDALONE SINGLE GRAIN */
 
 
@@ -220,13 +237,16 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("by the end of the week",
                       b.reg(r#"by (?:the )?end of (?:the )?week"#)?,
-                      |_| helpers::cycle_nth(Grain::Day, 0)?
-                          .span_to(&helpers::day_of_week(Weekday::Sun)?, true)
+                      |_| Ok(helpers::cycle_nth(Grain::Week, 0)?.mark_before_end_all())
     );
     b.rule_1_terminal("EOD|End of day",
                       b.reg(r#"(?:eod|end of (?:the )?day)"#)?,
                       |_| helpers::hour(20, false)
     );
+    b.rule_1_terminal("by the end of the day",
+                      b.reg(r#"by (?:the )?(?:eod|end of (?:the )?day)"#)?,
+                      |_| Ok(helpers::cycle_nth(Grain::Day, 0)?.mark_before_end_all())
+    );
     b.rule_1_terminal("EOM|End of month",
                       b.reg(r#"(?:the )?(?:eom|end of (?:the )?month)"#)?,
                       |_| {
@@ -239,8 +259,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("by the end of month",
                       b.reg(r#"by (?:the )?(?:eom|end of (?:the )?month)"#)?,
-                      |_| helpers::cycle_nth(Grain::Day, 0)?
-                          .span_to(&helpers::cycle_nth(Grain::Month, 0)?, true)
+                      |_| Ok(helpers::cycle_nth(Grain::Month, 0)?.mark_before_end_all())
     );
     b.rule_1_terminal("EOY|End of year",
                       b.reg(r#"(?:the )?(?:eoy|end of (?:the )?year)"#)?,
@@ -253,23 +272,70 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("by the end of year",
                       b.reg(r#"by (?:the )?(?:eoy|end of (?:the )?year)"#)?,
-                      |_| {
-                          let current_year = helpers::cycle_nth(Grain::Year, 0)?;
-                          let end = current_year.intersect(&helpers::month(12)?)?;
-                          helpers::cycle_nth(Grain::Second, 0)?
-                              .span_to(&end, true)
+                      |_| Ok(helpers::cycle_nth(Grain::Year, 0)?.mark_before_end_all())
+    );
+    b.rule_1_terminal("the turn of the century|decade|year",
+                      b.reg(r#"(?:at )?(?:the )?turn of the (century|decade|year)"#)?,
+                      |text_match| match text_match.group(1).as_ref() {
+                          "century" => helpers::turn_of(Grain::Century),
+                          "decade" => helpers::turn_of(Grain::Decade),
+                          _ => helpers::turn_of(Grain::Year),
                       }
     );
-    b.rule_2("this|next <day-of-week>",
-             b.reg(r#"this|(?:the )?next"#)?,
+    b.rule_2("beginning of <datetime>",
+             b.reg(r#"(?:the )?(?:beginning|start) of"#)?,
+             datetime_check!(|datetime: &DatetimeValue| datetime.constraint.grain() <= Grain::Month
+                 && datetime.part_of_period.is_none()
+                 && (!datetime.latent || form!(Form::Year(_))(datetime))),
+             |_, datetime| Ok(datetime.value().clone().not_latent().part_of_period(PartOfPeriod::Start))
+    );
+    b.rule_2("early|late <datetime>",
+             b.reg(r#"(early|late)(?: in)?"#)?,
+             datetime_check!(|datetime: &DatetimeValue| (datetime.constraint.grain() <= Grain::Month || datetime.constraint.grain() == Grain::Week)
+                 && datetime.part_of_period.is_none()
+                 && (!datetime.latent || form!(Form::Year(_))(datetime))),
+             |text_match, datetime| {
+                 let part_of_period = match text_match.group(1).as_ref() {
+                     "early" => PartOfPeriod::Early,
+                     _ => PartOfPeriod::Late,
+                 };
+                 Ok(datetime.value().clone().not_latent().part_of_period(part_of_period))
+             }
+    );
+    b.rule_2("middle of <datetime>",
+             b.reg(r#"(?:the )?middle of|mid-?"#)?,
+             datetime_check!(|datetime: &DatetimeValue| (datetime.constraint.grain() <= Grain::Month || datetime.constraint.grain() == Grain::Week)
+                 && datetime.part_of_period.is_none()
+                 && (!datetime.latent || form!(Form::Year(_))(datetime))),
+             |_, datetime| Ok(datetime.value().clone().not_latent().part_of_period(PartOfPeriod::Middle))
+    );
+    b.rule_1_terminal("mid-week",
+                      b.reg(r#"mid-?week|(?:the )?middle of the week"#)?,
+                      |_| Ok(helpers::cycle_nth(Grain::Week, 0)?.part_of_period(PartOfPeriod::Middle))
+    );
+    b.rule_2("end of <datetime>",
+             b.reg(r#"(?:the )?end of"#)?,
+             datetime_check!(|datetime: &DatetimeValue| datetime.constraint.grain() <= Grain::Month
+                 && datetime.part_of_period.is_none()
+                 && (!datetime.latent || form!(Form::Year(_))(datetime))),
+             |_, datetime| Ok(datetime.value().clone().not_latent().part_of_period(PartOfPeriod::End))
+    );
+    // The day of the week keeps its form so that the resolver applies its weekday policy
+    b.rule_2("this <day-of-week>",
+             b.reg(r#"the|this|current|coming"#)?,
+             datetime_check!(form!(Form::DayOfWeek{..})),
+             |_, a| Ok(a.value().clone())
+    );
+    b.rule_2("next <day-of-week>",
+             b.reg(r#"(?:the |this )?next"#)?,
              datetime_check!(form!(Form::DayOfWeek{..})),
              |_, a| {
-                 a.value().the_nth_not_immediate(0)
+                 Ok(a.value().the_nth_not_immediate(0)?.mark_next_weekday())
              }
     );
     b.rule_2("this <datetime>",
              b.reg(r#"the|this|current|coming"#)?,
-             datetime_check!(|datetime: &DatetimeValue| !form!(Form::PartOfDay(_))(datetime) && !form!(Form::Meal)(datetime)),
+             datetime_check!(|datetime: &DatetimeValue| !form!(Form::PartOfDay(_))(datetime) && !form!(Form::Meal)(datetime) && !form!(Form::DayOfWeek{..})(datetime)),
              |_, a| {
                  Ok(a.value().the_nth(0)?
                      .form(a.value().form.clone())
@@ -278,13 +344,23 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_2("next <datetime>",
              b.reg(r#"(?:the |this )?next"#)?,
-             datetime_check!(|datetime: &DatetimeValue| !form!(Form::PartOfDay(_))(datetime) && !form!(Form::Meal)(datetime)),
+             datetime_check!(|datetime: &DatetimeValue| !form!(Form::PartOfDay(_))(datetime) && !form!(Form::Meal)(datetime) && !form!(Form::DayOfWeek{..})(datetime) && !form!(Form::PartOfWeek)(datetime)),
              |_, a| {
                  Ok(a.value().the_nth(0)?
                      .form(a.value().form.clone())
                      .datetime_kind(a.value().datetime_kind.clone()))
              }
     );
+    // On a saturday or a sunday, "next weekend" is the following one
+    b.rule_2("next weekend",
+             b.reg(r#"(?:the |this )?next"#)?,
+             datetime_check!(form!(Form::PartOfWeek)),
+             |_, a| {
+                 Ok(a.value().the_nth_not_immediate(0)?
+                     .form(a.value().form.clone())
+                     .datetime_kind(a.value().datetime_kind.clone()))
+             }
+    );
     b.rule_2("last <datetime>",
              b.reg(r#"this past|(?:the |this )?last"#)?,
              datetime_check!(|datetime: &DatetimeValue| !form!(Form::PartOfDay(_))(datetime) && !form!(Form::Meal)(datetime)),
@@ -330,7 +406,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  if integer.value().suffixed {
                      return Err(RuleError::Invalid.into())
                  } else {
-                     helpers::year(integer.value().value as i32)
+                     Ok(helpers::year(integer.value().value as i32)?.lone_year())
                  }
              }
     );
@@ -346,6 +422,10 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(helpers::year(integer.value().value as i32)?.latent())
              }
     );
+    b.rule_1_terminal("year as short integer with an apostrophe '00-'99",
+                      b.reg(r#"'(\d{2})"#)?,
+                      |text_match| helpers::year(text_match.group(1).parse()?)
+    );
     b.rule_2("year as integer composed 1900-2199",
              integer_check_by_range!(19, 21),
              integer_check_by_range!(10, 99),
@@ -354,6 +434,42 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(helpers::year(y as i32)?.latent())
              }
     );
+    b.rule_1_terminal("the <decade> (80s)",
+                      b.reg(r#"(?:the '?|')([0-9]0)'?s"#)?,
+                      // read in the century of the two-digit year pivot, e.g. "the 60s" is 1960
+                      // and "the 20s" 2020 with the default pivot
+                      |text_match| helpers::decade(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("the <decade> (1980s, 1900s)",
+                      b.reg(r#"(?:the )?([12][0-9]{2}0)'?s"#)?,
+                      |text_match| helpers::decade(text_match.group(1).parse()?)
+    );
+    b.rule_2("the <ordinal> century",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 21),
+             b.reg(r#"century"#)?,
+             |ordinal, _| helpers::century((ordinal.value().value as i32 - 1) * 100)
+    );
+    b.rule_1_terminal("week <number> (ISO week)",
+                      b.reg(r#"(?:the )?week (?:number |no\.? |#)?(0?[1-9]|[1-4]\d|5[0-3])"#)?,
+                      |text_match| helpers::iso_week(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("week <number> of <year> (ISO week)",
+                      b.reg(r#"(?:the )?week (?:number |no\.? |#)?(0?[1-9]|[1-4]\d|5[0-3]),? (?:of |in )?(\d{4})"#)?,
+                      |text_match| helpers::iso_year_week(
+                          text_match.group(2).parse()?,
+                          text_match.group(1).parse()?)
+    );
+    b.rule_2("the <ordinal> week (ISO week)",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"week"#)?,
+             |ordinal, _| helpers::iso_week(ordinal.value().value as u32)
+    );
+    b.rule_3("the <ordinal> week of <year> (ISO week)",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 53),
+             b.reg(r#"week (?:of|in)"#)?,
+             integer_check_by_range!(1000, 2100),
+             |ordinal, _, year| helpers::iso_year_week(year.value().value as i32, ordinal.value().value as u32)
+    );
     b.rule_1("year as integer -1000-999",
              integer_check_by_range!(-1000, 999),
              |integer| {
@@ -485,6 +601,18 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  helpers::month_day(m, ordinal.value().value as u32)
              }
     );
+    // A month given otherwise than by its name, e.g. "the 15th of next month" or "the 3rd of
+    // march 2014", the day being looked for within it
+    b.rule_3("<day-of-month> (ordinal) of <month>",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 31),
+             b.reg(r#"of|in"#)?,
+             datetime_check!(|datetime: &DatetimeValue| !datetime.latent
+                 && datetime.constraint.grain() == Grain::Month
+                 && !form!(Form::Month(_))(datetime)),
+             |ordinal, _, month| {
+                 helpers::day_of_month(ordinal.value().value as u32)?.intersect(month.value())
+             }
+    );
     /* END OF DATETIME - DATE - DATES */
 
     /* DATETIME - TIME - TIME OF DAY */
@@ -524,8 +652,9 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              datetime_check!(form!(Form::TimeOfDay(_))),
              |_, a| Ok(a.value().clone().not_latent())
     );
+    // "o'clock" only follows full hours, not "5:30"
     b.rule_2("<time-of-day> o'clock",
-             datetime_check!(form!(Form::TimeOfDay(_))),
+             datetime_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour {.. }))),
              b.reg(r#"o.?clock"#)?,
              |a, _| Ok(a.value().clone().not_latent())
     );
@@ -538,9 +667,9 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     // TODO: check - invalid? military + am/pm
     b.rule_2_terminal("hhmm (military) am|pm",
                       b.reg(r#"((?:1[012]|0?\d))([0-5]\d)"#)?,
-                      b.reg(r#"([ap])\.?m?\.?"#)?,
+                      b.reg(r#"([ap])(?:\.?\s?m)?\.?"#)?,
                       |a, b| {
-                          let day_period = if b.group(1) == "a" {
+                          let day_period = if b.group(1).eq_ignore_ascii_case("a") {
                               helpers::hour(0, false)?.span_to(&helpers::hour(12, false)?, false)?
                           } else {
                               helpers::hour(12, false)?.span_to(&helpers::hour(0, false)?, false)?
@@ -550,19 +679,31 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                               a.group(2).parse()?,
                               true)?;
                           let form = anchor.form.clone();
-                          Ok(anchor.intersect(&day_period)?.form(form))
+                          Ok(anchor.intersect(&day_period)?.form(form).mark_12_hour())
                       }
     );
     b.rule_2("<time-of-day> am|pm",
              datetime_check!(form!(Form::TimeOfDay(_))),
-             b.reg(r#"(?:in the )?([ap])(?:\s|\.)?m?\.?"#)?,
+             // "5am", "5 a.m.", "5a.m." or "5 A. M.", 12am being midnight and 12pm noon
+             b.reg(r#"(?:in the )?([ap])(?:\.?\s?m)?\.?"#)?,
              |a, text_match| {
-                 let day_period = if text_match.group(1) == "a" {
+                 let day_period = if text_match.group(1).eq_ignore_ascii_case("a") {
                      helpers::hour(0, false)?.span_to(&helpers::hour(12, false)?, false)?
                  } else {
                      helpers::hour(12, false)?.span_to(&helpers::hour(0, false)?, false)?
                  };
-                 Ok(a.value().intersect(&day_period)?.form(a.value().form.clone()))
+                 Ok(a.value().intersect(&day_period)?.form(a.value().form.clone()).mark_12_hour())
+             }
+    );
+    b.rule_2("<time-of-day> <timezone>",
+             datetime_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"\(?\b(utc|gmt|e[sd]t|c[sd]t|m[sd]t|p[sd]t|bst|wet|ces?t|ees?t|msk|ist|jst|aest|aedt)\b\)?"#)?,
+             |a, text_match| {
+                 let timezone = match helpers::timezone_abbreviation(&text_match.group(1)) {
+                     Some(timezone) => timezone,
+                     None => return Err(RuleError::Invalid.into()),
+                 };
+                 Ok(a.value().clone().not_latent().timezone(timezone))
              }
     );
     b.rule_2("<time-of-day> in the morning|afternoon",
@@ -579,11 +720,11 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("noon",
                       b.reg(r#"noon|midday"#)?,
-                      |_| helpers::hour(12, false)
+                      |_| Ok(helpers::hour(12, false)?.mark_upcoming())
     );
     b.rule_1_terminal("midnight",
                       b.reg(r#"midni(?:ght|te)"#)?,
-                      |_| helpers::hour(0, false)
+                      |_| Ok(helpers::hour(0, false)?.mark_upcoming())
     );
     b.rule_1_terminal("quarter (relative minutes)",
                       b.reg(r#"(?:a|one)? ?quarter"#)?,
@@ -644,7 +785,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_3("relative minutes to|till|before <integer> (hour-of-day)",
              relative_minute_check!(),
-             b.reg(r#"to|till|before|of"#)?,
+             b.reg(r#"to|till?|before|of"#)?,
              datetime_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour {.. }))),
              |relative_minutes, _, datetime| Ok(helpers::hour_relative_minute(
                  datetime.value().form_time_of_day()?.full_hour(),
@@ -689,6 +830,15 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           text_match.group(3).parse()?,
                           true)
     );
+    b.rule_1_terminal("hh:mm:ss.mmm",
+                      b.reg(r#"((?:[01]?\d)|(?:2[0-3])):([0-5]\d):([0-5]\d)[.,](\d{3})"#)?,
+                      |text_match| helpers::hour_minute_second_millisecond(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          text_match.group(3).parse()?,
+                          text_match.group(4).parse()?,
+                          false)
+    );
     /* END OF DATETIME - TIME - TIME OF DAY - WRITTEN FORMS */
 
     /* DATETIME - DATE - DATES - WRITTEN FORMS */
@@ -727,8 +877,9 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 // Date - written form only
     b.rule_1_terminal("mm/dd/yy or mm/dd/yyyy - Ambiguous cases - interpret as US standard",
                       b.reg(r#"(0?[1-9]|1[0-2])[-/\.](0?[1-9]|1[0-2])[-/\.](\d{2,4})"#)?,
-                      |text_match| helpers::year_month_day(
-                          text_match.group(3).parse()?,
+                      |text_match| helpers::numeric_date(
+                          DateOrder::MonthDayYear,
+                          Some(text_match.group(3).parse()?),
                           text_match.group(1).parse()?,
                           text_match.group(2).parse()?)
     );
@@ -749,7 +900,9 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 // Date - written form only
     b.rule_1_terminal("mm/dd - Ambiguous cases - interpret as US standard",
                       b.reg(r#"(0?[1-9]|1[0-2])[/\.](0?[1-9]|1[0-2])"#)?,
-                      |text_match| helpers::month_day(
+                      |text_match| helpers::numeric_date(
+                          DateOrder::MonthDayYear,
+                          None,
                           text_match.group(1).parse()?,
                           text_match.group(2).parse()?)
     );
@@ -764,12 +917,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_1_terminal("morning",
                       b.reg(r#"morning"#)?,
-                      |_| {
-                          Ok(helpers::hour(4, false)?
-                              .span_to(&helpers::hour(12, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Morning)))
-                      }
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Morning)?.latent())
     );
     b.rule_1_terminal("breakfast",
                       b.reg(r#"breakfast"#)?,
@@ -807,39 +955,21 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("afternoon",
                       b.reg(r#"after ?noo?n"#)?,
-                      |_| {
-                          Ok(helpers::hour(12, false)?
-                              .span_to(&helpers::hour(19, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
-                      }
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Afternoon)?.latent())
     );
     b.rule_1_terminal("evening",
                       b.reg(r#"evening"#)?,
-                      |_| {
-                          Ok(helpers::hour(18, false)?
-                              .span_to(&helpers::hour(0, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Evening)))
-                      }
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Evening)?.latent())
     );
     b.rule_1_terminal("night",
                       b.reg(r#"night"#)?,
-                      |_| {
-                          Ok(helpers::hour(00, false)?
-                              .span_to(&helpers::hour(5, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Evening)))
-                      }
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Night)?.latent())
     );
     b.rule_1_terminal("last night",
                       b.reg(r#"last night"#)?,
                       |_| {
                           let yesterday = helpers::cycle_nth(Grain::Day, -1)?;
-                          let night = helpers::hour(00, false)?
-                              .span_to(&helpers::hour(5, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Evening));
+                          let night = helpers::part_of_day(PartOfDayForm::Night)?.latent();
                           yesterday.intersect(&night)
                       }
     );
@@ -905,15 +1035,12 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  .form(datetime.value().form.clone())
                  .datetime_kind(DatetimeKind::DatetimeComplement { date_and_time: true, today: true }))
     );
+    // Not bound to the day of the reference: after midnight the evening which just ended and the
+    // coming one are chosen between by the tie-break policy
     b.rule_1_terminal("tonight",
                       b.reg(r#"toni(?:ght|gth|te)"#)?,
-                      |_| {
-                          let period = helpers::hour(18, false)?.span_to(&helpers::hour(0, false)?, false)?;
-                          Ok(helpers::cycle_nth(Grain::Day, 0)?
-                              .intersect(&period)?
-                              .form(Form::PartOfDay(PartOfDayForm::Evening))
-                              .datetime_kind(DatetimeKind::DatetimeComplement { date_and_time: true, today: true }))
-                      }
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Evening)?
+                          .datetime_kind(DatetimeKind::DatetimeComplement { date_and_time: true, today: true }))
     );
     b.rule_1_terminal("after lunch",
                       b.reg(r#"after(?:-|\s)?lunch"#)?,
@@ -932,6 +1059,33 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                               .form(Form::PartOfDay(PartOfDayForm::Evening)))
                       }
     );
+    // The office hours are given by the resolver, see `ResolverContext::with_office_hours`
+    b.rule_1_terminal("working hours",
+                      b.reg(r#"(?:during )?(?:the )?(?:working|office|business|work) hours"#)?,
+                      |_| helpers::office_hours(OfficeHours::During)
+    );
+    b.rule_1_terminal("after hours",
+                      b.reg(r#"after(?:-|\s)?hours"#)?,
+                      |_| Ok(helpers::office_hours(OfficeHours::After)?.mark_after_start())
+    );
+    b.rule_2("working hours|after hours <datetime>",
+             b.reg(r#"((?:during )?(?:the )?(?:working|office|business|work) hours|after(?:-|\s)?hours)"#)?,
+             datetime_check!(|datetime: &DatetimeValue| !datetime.latent && datetime.constraint.grain() >= Grain::Day),
+             |text_match, datetime| {
+                 let office_hours = if text_match.group(1).starts_with("after") { OfficeHours::After } else { OfficeHours::During };
+                 Ok(datetime.value().intersect(&helpers::office_hours(office_hours)?)?
+                     .datetime_kind(DatetimeKind::TimePeriod))
+             }
+    );
+    b.rule_2("<datetime> working hours|after hours",
+             datetime_check!(|datetime: &DatetimeValue| !datetime.latent && datetime.constraint.grain() >= Grain::Day),
+             b.reg(r#"((?:during )?(?:the )?(?:working|office|business|work) hours|after(?:-|\s)?hours)"#)?,
+             |datetime, text_match| {
+                 let office_hours = if text_match.group(1).starts_with("after") { OfficeHours::After } else { OfficeHours::During };
+                 Ok(datetime.value().intersect(&helpers::office_hours(office_hours)?)?
+                     .datetime_kind(DatetimeKind::TimePeriod))
+             }
+    );
     /* END OF DATETIME - TIME - PARTS OF DAY */
 
     /* DATETIME - DATE - DATE + PARTS OF DAY */
@@ -967,47 +1121,31 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     /* DATETIME - DATE-PERIOD - GRAINS AS DATE INTERVALS */
 
-    b.rule_1_terminal("week-end - Hour grain, from Friday evening to Sunday midnight",
+    b.rule_1_terminal("week-end - Day grain, from Saturday to Sunday",
                       b.reg(r#"(?:the )?(?:week(?:\s|-)?end|wkend)"#)?,
-                      |_| {
-                          let friday = helpers::day_of_week(Weekday::Fri)?
-                              .intersect(&helpers::hour(18, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          Ok(friday.span_to(&monday, false)?.datetime_kind(DatetimeKind::DatePeriod))
-                      }
+                      |_| helpers::weekend_days()
     );
     b.rule_1_terminal("season - summer",
                       b.reg(r#"(?:the )?summer"#)?,
-                      |_| Ok(helpers::month_day(6, 21)?
-                          .span_to(&helpers::month_day(9, 23)?, false)?
-                          .form(Form::Season))
+                      |_| helpers::season(Season::Summer)
     );
     b.rule_1_terminal("season - fall",
                       b.reg(r#"(?:the )?(?:fall|autumn)"#)?,
-                      |_| Ok(helpers::month_day(9, 23)?
-                          .span_to(&helpers::month_day(12, 21)?, false)?
-                          .form(Form::Season))
+                      |_| helpers::season(Season::Fall)
     );
     b.rule_1_terminal("season - winter",
                       b.reg(r#"(?:the )?winter"#)?,
-                      |_| Ok(helpers::month_day(12, 21)?
-                          .span_to(&helpers::month_day(3, 20)?, false)?
-                          .form(Form::Season))
+                      |_| helpers::season(Season::Winter)
     );
     // FIXME: Also add support for combined years? e.g. "winter 2014-2015"
     b.rule_2("season - winter <year>",
                       b.reg(r#"(?:the )?winter(?: of)?"#)?,
                       datetime_check!(form!(Form::Year(_))),
-                      |_, year| Ok(helpers::year_month_day(year.value().form_year()?, 12, 21)?
-                          .span_to(&helpers::year_month_day(year.value().form_year()? + (1 as i32), 3, 20)?, false)?
-                          .form(Form::Season))
+                      |_, year| helpers::season_of_year(Season::Winter, year.value().form_year()?)
     );
     b.rule_1_terminal("season - spring",
                       b.reg(r#"(?:the )?spring"#)?,
-                      |_| Ok(helpers::month_day(3, 20)?
-                          .span_to(&helpers::month_day(6, 21)?, false)?
-                          .form(Form::Season))
+                      |_| helpers::season(Season::Spring)
     );
 
     /* END OF DATETIME - DATE-PERIOD - GRAINS AS DATE INTERVALS */
@@ -1033,13 +1171,18 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                               "twelve" => 12,
                               _ => return Err(RuleError::Invalid.into()),
                           };
-                          Ok(helpers::hour(hour, true)?.precision(Approximate))
+                          Ok(helpers::hour(hour, true)?.precision(Rough))
                       });
     b.rule_2("<time-of-day> approximately",
              datetime_check!(form!(Form::TimeOfDay(_))),
-             b.reg(r#"-?ish|approximately"#)?,
+             b.reg(r#"approximately"#)?,
              |datetime, _| Ok(datetime.value().clone().not_latent().precision(Precision::Approximate))
     );
+    b.rule_2("<time-of-day>-ish",
+             datetime_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"-?ish|or so"#)?,
+             |datetime, _| Ok(datetime.value().clone().not_latent().precision(Precision::Rough))
+    );
     b.rule_2("about <time-of-day>",
              b.reg(r#"about|around|approximately"#)?,
              datetime_check!(form!(Form::TimeOfDay(_))),
@@ -1143,8 +1286,8 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     // TODO: restrict datetime forms
     b.rule_2("by the end of <datetime>",
              b.reg(r#"by (?:the )?end of"#)?,
-             datetime_check!(),
-             |_, a| helpers::cycle_nth(Grain::Day, 0)?.span_to(a.value(), true)
+             datetime_check!(|datetime: &DatetimeValue| datetime.direction.is_none()),
+             |_, a| Ok(a.value().clone().mark_before_end_all())
     );
     // TODO: correct regex
     b.rule_2("until <time-of-day>",
@@ -1184,6 +1327,11 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              datetime_check!(|datetime: &DatetimeValue|  !datetime.latent),
              |_, datetime| Ok(datetime.value().clone().precision(Precision::Approximate))
     );
+    b.rule_2("roughly <datetime>",
+             b.reg(r#"roughly|more or less|give or take"#)?,
+             datetime_check!(|datetime: &DatetimeValue|  !datetime.latent),
+             |_, datetime| Ok(datetime.value().clone().precision(Precision::Rough))
+    );
     b.rule_2("exactly <datetime>",
              b.reg(r#"exactly|precisely"#)?,
              datetime_check!(|datetime: &DatetimeValue|  !datetime.latent),
@@ -1335,6 +1483,34 @@ pub fn rules_datetime_with_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingR
              |_, a| helpers::cycle_nth(a.value().grain, 1)
     );
 
+    // The time or the day of the reference in the next or last cycle, e.g. "this time next month"
+    b.rule_3("this time next|last <cycle>",
+             b.reg(r#"(?:at )?(?:this|the same|same) time"#)?,
+             b.reg(r#"(next|last|previous)"#)?,
+             cycle_check!(|cycle: &CycleValue| cycle.grain.is_greater_than_day()),
+             |_, direction, cycle| {
+                 let n = if direction.group(1) == "next" { 1 } else { -1 };
+                 helpers::cycle_shifted(Grain::Minute, cycle.value().grain, n)
+             }
+    );
+    b.rule_2("this time tomorrow|yesterday",
+             b.reg(r#"(?:at )?(?:this|the same|same) time"#)?,
+             b.reg(r#"(tomorrow|yesterday)"#)?,
+             |_, day| {
+                 let n = if day.group(1) == "tomorrow" { 1 } else { -1 };
+                 helpers::cycle_shifted(Grain::Minute, Grain::Day, n)
+             }
+    );
+    b.rule_3("same day next|last <cycle>",
+             b.reg(r#"(?:on )?(?:the )?same day"#)?,
+             b.reg(r#"(next|last|previous)"#)?,
+             cycle_check!(|cycle: &CycleValue| cycle.grain.is_greater_than_day()),
+             |_, direction, cycle| {
+                 let n = if direction.group(1) == "next" { 1 } else { -1 };
+                 helpers::cycle_shifted(Grain::Day, cycle.value().grain, n)
+             }
+    );
+
     b.rule_4("last <day-of-week> of <datetime>",
              b.reg(r#"(?:the )?last"#)?,
              datetime_check!(form!(Form::DayOfWeek{..})),
@@ -1344,6 +1520,25 @@ pub fn rules_datetime_with_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingR
                  a.value().last_of(b.value())
              }
     );
+    b.rule_3("last <day-of-week> of the month",
+             b.reg(r#"(?:the )?last"#)?,
+             datetime_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"(?:of|in) the month"#)?,
+             |_, a, _| a.value().last_of(&helpers::cycle(Grain::Month)?)
+    );
+    b.rule_3("nth <day-of-week> of the month",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 5),
+             datetime_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"(?:of|in) the month"#)?,
+             |ordinal, a, _| a.value().nth_of(ordinal.value().value - 1, &helpers::cycle(Grain::Month)?)
+    );
+    b.rule_4("nth <day-of-week> of the month",
+             b.reg(r#"the"#)?,
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 5),
+             datetime_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"(?:of|in) the month"#)?,
+             |_, ordinal, a, _| a.value().nth_of(ordinal.value().value - 1, &helpers::cycle(Grain::Month)?)
+    );
     b.rule_4("last <cycle> of <datetime>",
              b.reg(r#"(?:the )?last"#)?,
              cycle_check!(),
@@ -1359,7 +1554,10 @@ pub fn rules_datetime_with_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingR
              b.reg(r#"of|in"#)?, // of
              datetime_check!(), // march
              |ordinal, a, _, b| {
-                 b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1)
+                 match a.value().form {
+                     Form::DayOfWeek { .. } => a.value().nth_of(ordinal.value().value - 1, b.value()),
+                     _ => b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1),
+                 }
              }
     );
     b.rule_5("nth <datetime> of <datetime>",
@@ -1369,7 +1567,10 @@ pub fn rules_datetime_with_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingR
              b.reg(r#"of|in"#)?,
              datetime_check!(),
              |_, ordinal, a, _, b| {
-                 b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1)
+                 match a.value().form {
+                     Form::DayOfWeek { .. } => a.value().nth_of(ordinal.value().value - 1, b.value()),
+                     _ => b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1),
+                 }
              }
     );
     b.rule_4("nth <datetime> after <datetime>",
@@ -1391,6 +1592,11 @@ pub fn rules_datetime_with_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingR
                  a.value().the_nth_after(ordinal.value().value - 1, b.value())
              }
     );
+    b.rule_2("the week of <date>",
+             b.reg(r#"(?:the )?week of"#)?,
+             datetime_check!(|datetime: &DatetimeValue| datetime.constraint.grain() == Grain::Day && !datetime.latent),
+             |_, date| helpers::week_of(date.value())
+    );
     b.rule_4("the <cycle> after <datetime>",
              b.reg(r#"the"#)?,
              cycle_check!(),
@@ -1417,6 +1623,19 @@ pub fn rules_datetime_with_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingR
              datetime_check!(),
              |cycle, _, datetime| helpers::cycle_nth_after(cycle.value().grain, -1, datetime.value())
     );
+    b.rule_3("<day-of-week> after|before <holiday>",
+             datetime_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"(after|following|before|preceding)"#)?,
+             datetime_check!(|datetime: &DatetimeValue| datetime.holiday.is_some()),
+             |day, direction, holiday| weekday_around_holiday(day.value(), direction.group(1).as_ref(), holiday.value())
+    );
+    b.rule_4("the <day-of-week> after|before <holiday>",
+             b.reg(r#"the"#)?,
+             datetime_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"(after|following|before|preceding)"#)?,
+             datetime_check!(|datetime: &DatetimeValue| datetime.holiday.is_some()),
+             |_, day, direction, holiday| weekday_around_holiday(day.value(), direction.group(1).as_ref(), holiday.value())
+    );
     // TODO: resolution is not correct for times, i.e. rounds at grain
     b.rule_3("last n <cycle>",
              b.reg(r#"(?:for |in )?(?:the |these )?(?:last|past)"#)?,
@@ -1426,10 +1645,10 @@ pub fn rules_datetime_with_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingR
     );
     // TODO: same as previous
     b.rule_3("next n <cycle>",
-             b.reg(r#"(?:for |in )?(?:the |these )?next"#)?,
+             b.reg(r#"(?:for |in )?(?:the |these )?(?:next|coming)"#)?,
              integer_check_by_range!(1, 9999),
              cycle_check!(),
-             |_, integer, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, integer.value().value)
+             |_, integer, cycle| helpers::cycle_n_from_now(cycle.value().grain, integer.value().value)
     );
     b.rule_4("<ordinal> <cycle> of <datetime>",
              ordinal_check_by_range!(1, 9999),
@@ -1472,8 +1691,83 @@ pub fn rules_datetime_with_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingR
     Ok(())
 }
 
+fn weekday_around_holiday(day: &DatetimeValue, direction: &str, holiday: &DatetimeValue) -> RuleResult<DatetimeValue> {
+    let weekday = match day.form {
+        Form::DayOfWeek { weekday, .. } => weekday,
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    let after = match direction {
+        "after" | "following" => true,
+        _ => false,
+    };
+    holiday.holiday_offset(HolidayOffset::Weekday { weekday, after })
+}
+
 
 /* DATETIME - CYCLE DEFINITIONS */
+/// Recurring datetimes, resolved to their first occurrence and the period between occurrences.
+/// Supported forms are weekly and daily recurrences:
+/// "every monday", "every morning", "every day", "daily", "every week", "weekly",
+/// "every other day", "every 3 weeks", "every third day", "every 2nd monday", and their
+/// combinations with a time, e.g. "every monday at 9am".
+pub fn rules_datetime_recurring(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("every <day-of-week>",
+             b.reg(r#"every|each|on every"#)?,
+             datetime_check!(form!(Form::DayOfWeek{..})),
+             |_, a| Ok(a.value().clone().not_latent().recurring(PeriodComp::weeks(1).into()))
+    );
+    b.rule_2("every <part-of-day>",
+             b.reg(r#"every|each"#)?,
+             datetime_check!(form!(Form::PartOfDay(_))),
+             |_, a| Ok(a.value().clone().not_latent().recurring(PeriodComp::days(1).into()))
+    );
+    b.rule_2("every <cycle>",
+             b.reg(r#"every|each"#)?,
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Day || cycle.grain == Grain::Week),
+             |_, a| Ok(helpers::cycle_nth(a.value().grain, 0)?
+                 .recurring(PeriodComp::new(a.value().grain, 1).into()))
+    );
+    b.rule_1_terminal("daily",
+                      b.reg(r#"daily|everyday"#)?,
+                      |_| Ok(helpers::cycle_nth(Grain::Day, 0)?
+                          .recurring(PeriodComp::days(1).into()))
+    );
+    b.rule_1_terminal("weekly",
+                      b.reg(r#"weekly"#)?,
+                      |_| Ok(helpers::cycle_nth(Grain::Week, 0)?
+                          .recurring(PeriodComp::weeks(1).into()))
+    );
+    b.rule_2("every other <cycle>",
+             b.reg(r#"every (?:other|second)"#)?,
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Day || cycle.grain == Grain::Week),
+             |_, a| Ok(helpers::cycle_nth(a.value().grain, 0)?
+                 .recurring_every(PeriodComp::new(a.value().grain, 1).into(), 2))
+    );
+    b.rule_3("every <integer> <cycle>",
+             b.reg(r#"every"#)?,
+             integer_check_by_range!(2, 31),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Day || cycle.grain == Grain::Week),
+             |_, integer, cycle| Ok(helpers::cycle_nth(cycle.value().grain, 0)?
+                 .recurring_every(PeriodComp::new(cycle.value().grain, 1).into(), integer.value().value as u32))
+    );
+    // "every first monday" is rather a monthly recurrence, the ordinals start at 2
+    b.rule_3("every <ordinal> <cycle>",
+             b.reg(r#"every|each"#)?,
+             ordinal_check_by_range!(2, 31),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Day || cycle.grain == Grain::Week),
+             |_, ordinal, cycle| Ok(helpers::cycle_nth(cycle.value().grain, 0)?
+                 .recurring_every(PeriodComp::new(cycle.value().grain, 1).into(), ordinal.value().value as u32))
+    );
+    b.rule_3("every <ordinal> <day-of-week>",
+             b.reg(r#"every|each"#)?,
+             ordinal_check_by_range!(2, 31),
+             datetime_check!(form!(Form::DayOfWeek{..})),
+             |_, ordinal, a| Ok(a.value().clone().not_latent()
+                 .recurring_every(PeriodComp::weeks(1).into(), ordinal.value().value as u32))
+    );
+    Ok(())
+}
+
 pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("second (cycle)",
                       b.reg(r#"seconds?"#)?,
@@ -1503,6 +1797,14 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"(?:quarter|qtr)s?"#)?,
                       |_| CycleValue::new(Grain::Quarter)
     );
+    b.rule_1_terminal("decade (cycle)",
+                      b.reg(r#"decades?"#)?,
+                      |_| CycleValue::new(Grain::Decade)
+    );
+    b.rule_1_terminal("century (cycle)",
+                      b.reg(r#"centur(?:y|ies)"#)?,
+                      |_| CycleValue::new(Grain::Century)
+    );
     b.rule_1_terminal("year (cycle)",
                       b.reg(r#"y(?:ea)?rs?"#)?,
                       |_| CycleValue::new(Grain::Year)
@@ -4,6 +4,10 @@ use rustling_ontology_values::helpers;
 use rustling_ontology_moment::{Grain, PeriodComp, Period};
 
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("millisecond (unit-of-duration)",
+                      b.reg(r#"ms|milli(?:second)?s?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Millisecond))
+    );
     b.rule_1_terminal("second (unit-of-duration)",
                       b.reg(r#"sec(?:ond)?s?"#)?,
                       |_| Ok(UnitOfDurationValue::new(Grain::Second))
@@ -55,7 +59,21 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> <unit-of-duration>",
              integer_check_by_range!(0),
              unit_of_duration_check!(),
-             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into())
+                 .precision(integer.value().precision))
+    );
+    b.rule_2("half a <unit-of-duration>",
+             b.reg(r#"half an?|an? half"#)?,
+             unit_of_duration_check!(),
+             |_, uod| match uod.value().grain.half_period() {
+                 Some(half_period) => Ok(DurationValue::new(half_period.into())),
+                 None => Err(RuleError::Invalid.into()),
+             }
+    );
+    b.rule_2("<integer> business days",
+             integer_check_by_range!(0),
+             b.reg(r#"(?:business|working|work) days?"#)?,
+             |integer, _| Ok(DurationValue::new(PeriodComp::days(integer.value().value).into()).business_days())
     );
     b.rule_3("<integer> more <unit-of-duration>",
              integer_check_by_range!(0),
@@ -78,7 +96,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<integer> and a half hours",
              integer_check_by_range!(0),
              b.reg(r#"and (?:an? )?half hours?"#)?,
-             |integer, _| Ok(DurationValue::new(PeriodComp::minutes(integer.value().value * 60 + 30).into()))
+             |integer, _| Ok(DurationValue::new(Period::from(PeriodComp::hours(integer.value().value)) + PeriodComp::minutes(30)))
     );
     b.rule_3("<integer> <unit-of-duration> and a half",
              integer_check_by_range!(0),
@@ -98,6 +116,18 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  Ok(DurationValue::new(quarter_period + PeriodComp::new(uod.value().grain, integer.value().value)))
              }
     );
+    b.rule_3("a <unit-of-duration> and a half",
+             b.reg(r#"an?|one"#)?,
+             unit_of_duration_check!(),
+             b.reg(r#"and (?:an? )?half"#)?,
+             |_, uod, _| {
+                 let half_period: Period = match uod.value().grain.half_period() {
+                     Some(half_period) => half_period.into(),
+                     None => return Err(RuleError::Invalid.into()),
+                 };
+                 Ok(DurationValue::new(half_period + PeriodComp::new(uod.value().grain, 1)))
+             }
+    );
     b.rule_3("<integer> and a half <unit-of-duration>",
              integer_check_by_range!(0),
              b.reg(r#"and (?:an? )?half"#)?,
@@ -186,4 +216,35 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |duration, _| Ok(duration.value().clone().precision(Precision::Exact))
     );
     Ok(())
-}
\ No newline at end of file
+}
+
+fn age_unit(unit: &str) -> RuleResult<Grain> {
+    match unit {
+        "year" | "yr" => Ok(Grain::Year),
+        "month" => Ok(Grain::Month),
+        "week" => Ok(Grain::Week),
+        "day" => Ok(Grain::Day),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    // The infants are given their age in months, weeks or days, e.g. "a six-month-old"
+    b.rule_2("<number> <unit> old",
+             number_check_by_range!(0.0),
+             b.reg(r#"-?(year|yr|month|week|day)s?(?:-| )old"#)?,
+             |number, text_match| Ok(AgeValue::new(number.value().value(), age_unit(text_match.group(1).as_ref())?))
+    );
+    b.rule_2("aged <number>",
+             b.reg(r#"aged"#)?,
+             number_check_by_range!(0.0),
+             |_, number| Ok(AgeValue::new(number.value().value(), Grain::Year))
+    );
+    b.rule_3("aged <number> <unit>",
+             b.reg(r#"aged"#)?,
+             number_check_by_range!(0.0),
+             b.reg(r#"(year|yr|month|week|day)s?"#)?,
+             |_, number, text_match| Ok(AgeValue::new(number.value().value(), age_unit(text_match.group(1).as_ref())?))
+    );
+    Ok(())
+}
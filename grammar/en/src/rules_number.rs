@@ -64,22 +64,29 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"some"#)?,
                       |_| IntegerValue::new_with_grain(3, 1)
     );
+    // Vague quantities are approximate, "a few" being taken as 3 and "several" as 4
     b.rule_1_terminal("several",
                       b.reg(r#"several"#)?,
-                      |_| IntegerValue::new_with_grain(4, 1)
+                      |_| Ok(IntegerValue {
+                          value: 4,
+                          grain: Some(1),
+                          precision: Approximate,
+                          ..IntegerValue::default()
+                      })
     );
     b.rule_1_terminal("bunch",
                       b.reg(r#"a bunch of"#)?,
                       |_| IntegerValue::new_with_grain(10, 1)
     );
-    b.rule_1("few", b.reg(r#"(?:a )?few"#)?, |_| {
-        Ok(IntegerValue {
-            value: 3,
-            grain: Some(1),
-            precision: Approximate,
-            ..IntegerValue::default()
-        })
-    });
+    b.rule_1_terminal("few",
+                      b.reg(r#"(?:a )?few"#)?,
+                      |_| Ok(IntegerValue {
+                          value: 3,
+                          grain: Some(1),
+                          precision: Approximate,
+                          ..IntegerValue::default()
+                      })
+    );
     b.rule_1_terminal("integer (20..90)",
                       b.reg(r#"(twenty|thirty|fou?rty|fifty|sixty|seventy|eighty|ninety)"#)?,
                       |text_match| {
@@ -125,7 +132,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              });
 
     b.rule_1_terminal("100, 1_000, 1_000_000, 1_000_000_000",
-                      b.reg(r#"(hundred|thousand|million|billion)s?"#)?,
+                      b.reg(r#"(?:an? )?(hundred|thousand|million|billion)s?"#)?,
                       |text_match| {
                           let (value, grain) = match text_match.group(1).as_ref() {
                               "hundred" => (100, 2),
@@ -153,7 +160,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              }
     );
     b.rule_1_terminal("dozen",
-                      b.reg(r#"dozen"#)?,
+                      b.reg(r#"(?:a )?dozen"#)?,
                       |_| Ok(IntegerValue {
                           value: 12,
                           grain: Some(1),
@@ -161,6 +168,16 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           ..IntegerValue::default()
                       })
     );
+    // "score" and "gross" alone are too common in other senses, e.g. "the score" or "gross income"
+    b.rule_1_terminal("a score|gross",
+                      b.reg(r#"a (score|gross)"#)?,
+                      |text_match| Ok(IntegerValue {
+                          value: if text_match.group(1) == "score" { 20 } else { 144 },
+                          grain: Some(1),
+                          group: true,
+                          ..IntegerValue::default()
+                      })
+    );
     b.rule_2("number dozen",
              integer_check_by_range!(1, 99),
              integer_check!(|integer: &IntegerValue| integer.group),
@@ -172,6 +189,29 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..IntegerValue::default()
                  })
              });
+    b.rule_2("number score|gross",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(score|gross)"#)?,
+             |integer, text_match| {
+                 let group = if text_match.group(1) == "score" { 20 } else { 144 };
+                 Ok(IntegerValue {
+                     value: integer.value().value * group,
+                     grain: Some(1),
+                     group: true,
+                     ..IntegerValue::default()
+                 })
+             });
+    b.rule_2("half a dozen|score|gross",
+             b.reg(r#"half"#)?,
+             integer_check!(|integer: &IntegerValue| integer.group && integer.value % 2 == 0),
+             |_, group| {
+                 Ok(IntegerValue {
+                     value: group.value().value / 2,
+                     grain: Some(1),
+                     group: true,
+                     ..IntegerValue::default()
+                 })
+             });
 
     b.rule_1("decimal number",
              b.reg(r#"(\d*\.\d+)"#)?,
@@ -227,6 +267,10 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                               ..FloatValue::default()
                           })
                       });
+    b.rule_1_terminal("number in scientific notation",
+                      b.reg(r#"(\d*\.?\d+)e([-+]?\d{1,3})"#)?,
+                      |text_match| helpers::scientific_number(text_match.group(1), text_match.group(2))
+    );
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|minus\s?|negative\s?"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -443,3 +487,65 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |_, ordinal| Ok((*ordinal.value()).prefixed()));
     Ok(())
 }
+
+pub fn rules_ordinal_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("the first <integer>",
+             b.reg(r#"(?:the )?first"#)?,
+             integer_check_by_range!(2, 1000),
+             |_, integer| OrdinalRangeValue::new(1, integer.value().value));
+    b.rule_3("<ordinal> to <ordinal>",
+             ordinal_check!(),
+             b.reg(r#"to|through|thru|till|until|-"#)?,
+             ordinal_check!(),
+             |start, _, end| OrdinalRangeValue::new(start.value().value, end.value().value));
+    b.rule_4("from <ordinal> to <ordinal>",
+             b.reg(r#"from|between"#)?,
+             ordinal_check!(),
+             b.reg(r#"to|through|thru|till|until|and|-"#)?,
+             ordinal_check!(),
+             |_, start, _, end| OrdinalRangeValue::new(start.value().value, end.value().value));
+    Ok(())
+}
+
+pub fn rules_number_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_3("<number> to <number>",
+             number_check!(),
+             b.reg(r#"to|through|thru|-|–"#)?,
+             number_check!(),
+             |low, _, high| NumberRangeValue::new(low.value().value(), high.value().value()));
+    b.rule_4("between <number> and <number>",
+             b.reg(r#"from|between"#)?,
+             number_check!(),
+             b.reg(r#"to|through|thru|and|-|–"#)?,
+             number_check!(),
+             |_, low, _, high| NumberRangeValue::new(low.value().value(), high.value().value()));
+    Ok(())
+}
+
+pub fn rules_number_comparison(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<comparison> <number>",
+             b.reg(r#"(more than|greater than|over|above|at least|no less than|less than|fewer than|under|below|at most|no more than|up to)"#)?,
+             number_check!(),
+             |text_match, number| {
+                 let op = match text_match.group(1).as_ref() {
+                     "more than" | "greater than" | "over" | "above" => CompareOp::Gt,
+                     "at least" | "no less than" => CompareOp::Ge,
+                     "less than" | "fewer than" | "under" | "below" => CompareOp::Lt,
+                     _ => CompareOp::Le,
+                 };
+                 Ok(NumberComparisonValue { op, value: number.value().value() })
+             });
+    Ok(())
+}
+
+pub fn rules_phone_number(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    // Digits grouped as in North America with their area code, e.g. "(555) 123-4567" or
+    // "555-123-4567", or international numbers starting with "+". Runs of digits without
+    // separators are left to the integers, and seven digits without an area code, e.g.
+    // "555-1234", aren't read as phone numbers.
+    b.rule_1_terminal("phone number",
+                      b.reg(r#"(?:\+\d{1,3}[-. ]?)?(?:\(\d{3}\) ?|\d{3}[-. ])\d{3}[-. ]\d{4}|\+\d{1,3}(?:[-. ]\d{1,4}){2,5}|\+\d{10,14}"#)?,
+                      |text_match| Ok(PhoneNumberValue(text_match.group(0).to_string()))
+    );
+    Ok(())
+}
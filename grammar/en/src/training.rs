@@ -1,12 +1,44 @@
 use rustling_ontology_values::check::*;
 use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
-use rustling_ontology_values::ResolverContext;
+use rustling_ontology_values::{AmbiguousHourPolicy, ResolverContext, TwoDigitYearPivot};
 
 pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_percentage(0.3), "0.3%", "zero point three per cent");
     example!(v, check_percentage(15.0), "15%", "15 %", "+15%", "fifteen percent");
     example!(v, check_percentage(202.0), "202%", "202 p.c.", "202percent");
+    example!(v, check_percentage(0.5), "0.5%", "half a percent", "a half percent", "point five percent", "zero point five percent");
+    example!(v, check_percentage(0.05), "point 05 percent", "point 05%");
+    example!(v, check_percentage(0.25), "a quarter percent", "a quarter of a percent", "0.25 %");
+    example!(v, check_percentage(0.75), "three quarters of a percent");
+    example!(v, check_percentage(100.0), "one hundred percent", "a hundred percent", "100%");
+    example!(v, check_percentage(150.0), "a hundred and fifty percent", "one hundred fifty percent", "150%");
+}
+
+pub fn examples_recurring(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurring!(c, [2013, 2, 18], PeriodComp::weeks(1).into()), "every monday", "each monday", "on every monday");
+    example!(v, check_recurring!(c, [2013, 2, 12], PeriodComp::weeks(1).into()), "every tuesday");
+    example!(v, check_recurring!(c, [2013, 2, 18, 9], PeriodComp::weeks(1).into()), "every monday at 9am");
+    example!(v, check_recurring!(c, [2013, 2, 12], PeriodComp::days(1).into()), "every day", "each day", "daily");
+    example!(v, check_recurring!(c, [2013, 2, 12, 4], PeriodComp::days(1).into()), "every morning");
+    example!(v, check_recurring!(c, [2013, 2, 12], PeriodComp::days(2).into()), "every other day", "every 2nd day");
+    example!(v, check_recurring!(c, [2013, 2, 12], PeriodComp::days(3).into()), "every third day", "every 3rd day");
+    example!(v, check_recurring!(c, [2013, 2, 18], PeriodComp::weeks(2).into()), "every 2nd monday", "every second monday");
+    example!(v, check_recurring!(c, [2013, 2, 11], PeriodComp::weeks(3).into(), Grain::Week), "every third week");
+    example!(v, check_recurring!(c, [2013, 2, 11], PeriodComp::weeks(1).into(), Grain::Week), "every week", "weekly");
+    example!(v, check_recurring!(c, [2013, 2, 11], PeriodComp::weeks(3).into(), Grain::Week), "every 3 weeks", "every three weeks");
+}
+
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(3.0, Some("kilogram"), None), "3 kg", "3kg", "three kilos", "3 kilograms");
+    example!(v, check_quantity(3.0, Some("kilogram"), Some("flour")), "3 kg of flour", "three kilograms of flour");
+    example!(v, check_quantity(250.0, Some("gram"), None), "250g", "250 gr", "two hundred fifty grams");
+    example!(v, check_quantity(500.0, Some("millilitre"), None), "500 ml", "500ml", "five hundred milliliters");
+    example!(v, check_quantity(1.5, Some("litre"), Some("milk")), "1.5l of milk", "1.5 liters of milk", "one and a half liters of milk");
+    example!(v, check_quantity(2.0, Some("mile"), None), "2 miles", "two miles", "2 mi");
+    example!(v, check_quantity(10.0, Some("kilometre"), None), "10 km", "ten kilometers", "10 kilometres");
+    example!(v, check_quantity(6.0, Some("foot"), None), "6 ft", "six feet");
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -14,9 +46,20 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(32.0, Some("celsius")), "thirty two degrees celsius", "thirty two degrees centigrade", "32°C", "32 °c");
     example!(v, check_temperature(-27.0, Some("celsius")), "minus 27 celsius", "-27C", "- 27 c");
     example!(v, check_temperature(-5.0, Some("fahrenheit")), "minus five degrees fahrenheit", "-5 °F", "- 5°f");
+    example!(v, check_temperature(-5.0, Some("degree")), "minus 5 degrees", "negative five degrees", "-5°");
     example!(v, check_temperature(168.0, Some("fahrenheit")), "one hundred and sixty-eight fahrenheit", "168 F", "168f");
     example!(v, check_temperature(10.0, Some("kelvin")), "ten degrees kelvin", "10 °K", "10°k");
     example!(v, check_temperature(21.0, Some("kelvin")), "21 kelvin", "21 K", "21k");
+    example!(v, check_temperature_with_qualifier(30.0, Some("fahrenheit"), TemperatureQualifier::FeelsLike), "feels like 30 F", "feels like 30 degrees fahrenheit");
+    example!(v, check_temperature_with_qualifier(-5.0, Some("degree"), TemperatureQualifier::WindChill), "wind chill of -5 degrees", "a wind chill of minus 5 degrees");
+    example!(v, check_temperature_with_qualifier(100.0, Some("fahrenheit"), TemperatureQualifier::HeatIndex), "heat index of 100 F");
+}
+
+pub fn examples_temperature_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature_range(20.0, 25.0, Some("degree")), "between 20 and 25 degrees", "20 to 25 degrees", "20° - 25°");
+    example!(v, check_temperature_range(-5.0, 3.0, Some("celsius")), "from -5 to 3 degrees celsius", "-5°C to 3°C");
+    example!(v, check_temperature_range(18.0, 30.0, None), "a high of 30, low of 18", "a low of 18 and a high of 30");
+    example!(v, check_temperature_range(20.0, 77.0, Some("celsius")), "between 20°C and 77°F");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -60,10 +103,25 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 16]), "saturday", "sat", "sat.");
     example!(v, check_moment!(c, [2013, 2, 17]), "sunday", "sun", "sun.");
     example!(v, check_moment!(c, [2013, 3, 1]), "the 1st of march", "first of march", "march first");
-    example!(v, check_moment!(c, [2013, 3, 3]), "march 3");
+    example!(v, check_moment!(c, [2013, 3, 3]), "march 3", "the 3rd of march");
+    example!(v, check_moment!(c, [2013, 3, 15]), "the 15th of next month", "15th of next month");
+    example!(v, check_moment!(c, [2013, 2, 20]), "the 20th of this month");
+    example!(v, check_moment!(c, [2014, 3, 3]), "the 3rd of march 2014");
     example!(v, check_moment!(c, [2013, 3, 15]), "the ides of march");
+    example!(v, check_moment!(c, [2013, 3, 12]), "the second tuesday of march", "second tuesday in march");
+    example!(v, check_moment!(c, [2024, 1, 1]), "first monday of 2024", "the first monday of 2024");
+    example!(v, check_moment!(c, [2024, 2, 23]), "last friday of february 2024", "the last friday of february 2024");
+    example!(v, check_moment!(c, [2013, 2, 15]), "the third friday of the month");
+    example!(v, check_moment!(c, [2013, 2, 22]), "last friday of the month");
     example!(v, check_moment!(c, [2015, 3, 3]), "march 3 2015", "march 3rd 2015", "march third 2015");
     example!(v, check_moment!(c, [2015, 3, 3]), "3/3/2015", "3/3/15", "2015-3-3", "2015-03-03");
+    example!(v, check_moment!(c, [2024, 3, 4]), "03/04/2024");
+    let day_first = c.clone().with_date_order(DateOrder::DayMonthYear);
+    let month_first = c.clone().with_date_order(DateOrder::MonthDayYear);
+    example!(v, check_moment!(day_first, [2024, 4, 3]), "03/04/2024");
+    example!(v, check_moment!(day_first, [2024, 3, 4]), "2024-03-04");
+    example!(v, check_moment!(month_first, [2024, 4, 13]), "13/04/2024");
+    example!(v, check_moment_span!(day_first, [2024, 4, 1], [2024, 4, 8], Grain::Week), "the week of 03/04/2024");
     example!(v, check_moment!(c, [2015, 8, 31]), "31/08/2015", "31/08/15", "2015-08-31", "2015-08-31");
     example!(v, check_moment!(c, [2015, 8, 31]), "08/31/2015", "08/31/15", "2015-08-31", "2015-08-31");
     example!(v, check_moment!(c, [2013, 3, 3]), "3/3");
@@ -90,9 +148,44 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 4, 1], Grain::Quarter), "next quarter", "next qtr");
     example!(v, check_moment!(c, [2013, 7, 1], Grain::Quarter), "third quarter", "3rd quarter", "third qtr", "3rd qtr", "the 3rd qtr");
     example!(v, check_moment!(c, [2018, 10, 1], Grain::Quarter), "4th quarter 2018", "4th qtr 2018");//, "the 4th qtr of 2018");
+    example!(v, check_moment!(c, [2024, 4, 1], Grain::Quarter), "Q2 2024", "q2 2024", "second quarter 2024");
+    example!(v, check_moment!(c, [2013, 7, 1], Grain::Quarter), "Q3", "q3");
+    example!(v, check_moment!(c, [2025, 1, 1], Grain::Quarter), "Q1 FY2025", "q1 fy25", "Q1 fiscal year 2025");
+    let fiscal_april = c.clone().with_fiscal_year_start(4);
+    example!(v, check_moment!(fiscal_april, [2025, 4, 1], Grain::Quarter), "Q1 FY2025");
+    example!(v, check_moment!(fiscal_april, [2026, 1, 1], Grain::Quarter), "q4 fy25");
+    example!(v, check_moment!(fiscal_april, [2025, 7, 1], Grain::Quarter), "Q2 2025");
+    let recent_years = c.clone().prefer_year_range(1990..=2100);
+    example!(v, check_moment!(recent_years, [2024]), "in 2024");
+    example!(v, check_moment!(recent_years, [1960]), "in 1960");
     example!(v, check_moment!(c, [2012]), "last year", "last yr");
     example!(v, check_moment!(c, [2013]), "this year", "current year", "this yr");
     example!(v, check_moment!(c, [2014]), "next year", "next yr");
+    example!(v, check_moment!(c, [2013, 2, 19]), "in 5 business days", "in five working days");
+    example!(v, check_moment!(c, [1980], Grain::Decade), "the 80s", "the '80s", "the 1980s");
+    example!(v, check_moment!(c, [2020], Grain::Decade), "the 20s", "the '20s");
+    let pivot_2000 = c.clone().with_two_digit_year_pivot(TwoDigitYearPivot(2000));
+    example!(v, check_moment!(pivot_2000, [2060], Grain::Decade), "the 60s");
+    example!(v, check_moment!(c, [1999]), "'99", "in '99");
+    example!(v, check_moment!(c, [2021]), "'21", "in '21");
+    example!(v, check_moment!(c, [2013, 6, 3], Grain::Week), "week 23", "the 23rd week");
+    example!(v, check_moment!(c, [2024, 6, 3], Grain::Week), "week 23 2024", "week 23 of 2024", "the 23rd week of 2024");
+    example!(v, check_moment!(c, [2021, 1, 4], Grain::Week), "week 1 of 2021", "week 1 2021");
+    example!(v, check_moment!(c, [2020], Grain::Decade), "next decade");
+    example!(v, check_moment!(c, [2000], Grain::Century), "this century");
+    example!(v, check_moment!(c, [1900], Grain::Century), "the 20th century");
+    example!(v, check_moment!(c, [2000], Grain::Decade), "the 2000s");
+    let centuries_around = ResolverContext::builder()
+        .reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second))
+        .window(300)
+        .build()
+        .unwrap();
+    example!(v, check_moment!(centuries_around, [1900], Grain::Decade), "the 1900s");
+    example!(v, check_moment!(centuries_around, [1800], Grain::Decade), "the 1800s");
+    example!(v, check_moment!(centuries_around, [1800], Grain::Century), "the 19th century");
+    example!(v, check_moment_span_with_precision!(c, [1999], [2002], Precision::Approximate), "the turn of the century", "at the turn of the century");
+    example!(v, check_moment_span_with_precision!(c, [2009], [2012], Precision::Approximate), "the turn of the decade");
+    example!(v, check_moment_span_with_precision!(c, [2012, 12], [2013, 3], Precision::Approximate), "the turn of the year");
     example!(v, check_moment!(c, [2013, 2, 10]), "last sunday");
     example!(v, check_moment!(c, [2013, 2, 5]), "last tuesday");
     example!(v, check_moment!(c, [2013, 2, 13]), "next wednesday");
@@ -106,28 +199,57 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 11, 10]), "ten minutes after eleven", "fifty minutes before noon", "50 minutes before 12");
     example!(v, check_moment!(c, [2013, 2, 12, 11, 10]), "exactly ten minutes after eleven", "fifty minutes before noon precisely");
     example!(v, check_moment_with_precision!(c, [2013, 2, 12, 11, 10], Precision::Approximate), "about 50 minutes before 12", "50 minutes before 12 approximately");
+    example!(v, check_moment!(c, [2013, 2, 12, 17]), "2 hours after 3pm", "two hours after 3 pm");
+    example!(v, check_moment!(c, [2013, 2, 12, 11, 30]), "30 minutes before noon", "thirty minutes before 12pm");
     example!(v, check_moment!(c, [2013, 2, 12, 6, 15]), "fifteen past six am", "quarter past six am", "quarter past six in the morning");
     example!(v, check_moment!(c, [2013, 2, 10, 6, 15]), "two days ago at fifteen past six am", "the day before yesterday at quarter past six am", "quarter past six in the morning two days ago");
     example!(v, check_moment!(c, [2013, 2, 12, 18, 15]), "fifteen past six pm", "quarter past six pm", "quarter past six in the evening");
     example!(v, check_moment!(c, [2013, 2, 12, 18, 15]), "precisely fifteen past six pm", "quarter past six pm sharp");
     example!(v, check_moment_with_precision!(c, [2013, 2, 12, 18, 15], Precision::Approximate), "approximately quarter past six in the evening", "15 past 6 in the afternoon approximately");
     example!(v, check_moment!(c, [2013, 2, 12, 15]), "at 3pm", "@ 3pm", "3PM", "3pm", "3 oclock pm", "3 o'clock in the afternoon");
-    example!(v, check_moment_with_precision!(c, [2013, 2, 12, 15], Precision::Approximate), "3ish pm", "3pm approximately", "at about 3pm");
+    example!(v, check_moment_with_precision!(c, [2013, 2, 12, 15], Precision::Approximate), "3pm approximately", "at about 3pm");
+    example!(v, check_moment_with_precision!(c, [2013, 2, 12, 15], Precision::Rough), "3ish pm", "3pm-ish", "roughly at 3pm");
+    example!(v, check_moment!(c, [2013, 2, 12, 15]), "exactly 3pm");
     example!(v, check_moment!(c, [2013, 2, 12, 14, 50]), "at ten to three", "at 10 to 3 pm", "10 to 3 in the afternoon");
     example!(v, check_moment!(c, [2013, 2, 13, 3, 15]), "at 15 past 3am", "a quarter past 3am", "3:15 in the morning", "3:15am", "3:15AM", "3:15a");
     example!(v, check_moment!(c, [2013, 2, 12, 15, 15]), "at 15 past 3pm", "a quarter past 3pm", "3:15 in the afternon", "15:15", "3:15pm", "3:15PM", "3:15p");
     example!(v, check_moment!(c, [2013, 2, 12, 18, 45]), "at 15 to 7pm", "a quarter to 7pm", "6:45 in the afternon", "18:45", "6:45pm", "6:45PM", "6:45p");
     example!(v, check_moment!(c, [2013, 2, 12, 15, 20]), "at 20 past 3pm", "3:20 in the afternoon", "3:20 in afternoon", "twenty after 3pm", "3:20p");
     example!(v, check_moment!(c, [2013, 2, 12, 15, 30]), "at half past three pm", "half past 3 pm", "15:30", "3:30pm", "3:30PM", "330 p.m.", "3:30 p m");
-    example!(v, check_moment!(c, [2013, 2, 12, 15, 30]), "3:30", "half three");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 30]), "3:30", "half three", "half past three");
     example!(v, check_moment!(c, [2013, 2, 12, 15, 23, 24]), "15:23:24");
+    example!(v, check_moment!(c, [2013, 2, 12, 12, 0, 0, 500]), "12:00:00.500");
     example!(v, check_moment!(c, [2013, 2, 12, 11, 45]), "a quarter to noon", "11:45am");
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 45]), "quarter to five", "a quarter till five", "a quarter til 5");
+    example!(v, check_moment!(c, [2013, 2, 12, 11, 50]), "ten to noon", "10 minutes to noon", "ten til noon");
+    example!(v, check_moment!(c, [2013, 2, 12, 23, 45]), "quarter to midnight", "a quarter before midnight");
     example!(v, check_moment!(c, [2013, 2, 12, 20]), "8 tonight", "eight tonight", "8 this evening");
+    let afternoon = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(14, 0, 0)), Grain::Second));
+    example!(v, check_moment!(afternoon, [2013, 2, 13, 12]), "noon", "midday");
+    example!(v, check_moment!(afternoon, [2013, 2, 12, 17]), "at 5", "5 o'clock");
+    example!(v, check_moment!(afternoon, [2013, 2, 13, 11, 50]), "ten to noon");
+    example!(v, check_moment!(afternoon, [2013, 2, 12, 23, 45]), "quarter to midnight");
+    let mornings = afternoon.clone().with_ambiguous_hour_policy(AmbiguousHourPolicy::PreferAm);
+    let evenings = afternoon.clone().with_ambiguous_hour_policy(AmbiguousHourPolicy::PreferPm);
+    example!(v, check_moment!(mornings, [2013, 2, 13, 5]), "at 5", "5 o'clock");
+    example!(v, check_moment!(mornings, [2013, 2, 12, 17]), "at 5pm");
+    example!(v, check_moment!(evenings, [2013, 2, 12, 17]), "at 5");
+    example!(v, check_moment!(mornings, [2013, 2, 13, 3, 30]), "half past three");
+    example!(v, check_moment!(evenings, [2013, 2, 12, 15, 30]), "half past three");
+    example!(v, check_moment!(mornings, [2013, 2, 13, 4, 45]), "quarter to five");
+    example!(v, check_moment!(evenings, [2013, 2, 12, 16, 45]), "quarter to five");
+    let late_evening = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(23, 0, 0)), Grain::Second));
+    example!(v, check_moment_span!(late_evening, [2013, 2, 12, 4], [2013, 2, 12, 12]), "this morning");
+    example!(v, check_moment_span!(late_evening, [2013, 2, 12, 18], [2013, 2, 13, 0]), "this evening", "tonight");
+    let after_midnight = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 13).and_hms(1, 0, 0)), Grain::Second));
+    example!(v, check_moment!(after_midnight, [2013, 2, 14, 0]), "midnight");
+    example!(v, check_moment_span!(after_midnight, [2013, 2, 13, 18], [2013, 2, 14, 0]), "tonight");
     // Mixing date and time
     example!(v, check_moment!(c, [2013, 9, 20, 19, 30]), "at 7:30 PM on Fri, Sep 20");
     example!(v, check_moment!(c, [2013, 2, 16, 9]), "at 9am on Saturday", "on Saturday for 9am");
     example!(v, check_moment!(c, [2014, 7, 18, 19, 0]), "Fri, Jul 18, 2014 07:00 PM");
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 1]), "in a sec", "one second from now");
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 0, 500]), "in 500 milliseconds", "in 500ms");
     example!(v, check_moment!(c, [2013, 2, 12, 4, 31, 0]), "in a minute", "in one minute");
     example!(v, check_moment!(c, [2013, 2, 12, 4, 32, 0]), "in 2 minutes", "in 2 more minutes", "2 minutes from now");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 30, 0]), "in 60 minutes");
@@ -135,16 +257,26 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 7, 0, 0]), "in 2.5 hours", "in 2 and a half hours");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 30]), "in one hour", "in 1h");
     example!(v, check_moment!(c, [2013, 2, 12, 6, 30]), "in a couple hours", "in a couple of hours");
-    example!(v, check_moment!(c, [2013, 2, 12, 7, 30]), "in a few hours", "in few hours");
+    example!(v, check_moment_with_precision!(c, [2013, 2, 12, 7, 30], Precision::Approximate), "in a few hours", "in few hours");
     example!(v, check_moment!(c, [2013, 2, 13, 4, 30]), "in 24 hours", "in 24hrs", "in 24 hrs");
     example!(v, check_moment!(c, [2013, 2, 13]), "in a day", "a day from now");
+    example!(v, check_moment!(c, [2013, 2, 15]), "3 days from now", "in 3 days");
     example!(v, check_moment!(c, [2016, 2]), "3 years from today");
     example!(v, check_moment!(c, [2013, 2, 19]), "in 7 days");
     example!(v, check_moment!(c, [2013, 2, 19]), "in 1 week", "in a week");
+    example!(v, check_moment!(c, [2013, 3, 12, 4, 30]), "this time next month", "at the same time next month");
+    let end_of_january = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2012, 1, 31).and_hms(10, 15, 0)), Grain::Second));
+    example!(v, check_moment!(end_of_january, [2012, 2, 29, 10, 15]), "this time next month");
+    example!(v, check_moment!(c, [2013, 2, 19, 4, 30]), "this time next week");
+    example!(v, check_moment!(c, [2012, 2, 12, 4, 30]), "same time last year");
+    example!(v, check_moment!(c, [2013, 2, 13, 4, 30]), "this time tomorrow");
+    example!(v, check_moment!(c, [2013, 2, 19]), "same day next week", "on the same day next week");
+    example!(v, check_moment!(c, [2013, 3, 12]), "the same day next month");
     example!(v, check_moment!(c, [2013, 2, 5]), "7 days ago");
-    example!(v, check_moment!(c, [2013, 1, 29]), "14 days ago", "a fortnight ago");
+    example!(v, check_moment!(c, [2013, 1, 29]), "14 days ago", "a fortnight ago", "2 weeks ago");
     example!(v, check_moment!(c, [2013, 2, 5]), "a week ago", "one week ago", "1 week ago");
     example!(v, check_moment!(c, [2013, 1, 22]), "three weeks ago");
+    example!(v, check_moment!(c, [2013, 1, 12]), "a month ago", "one month ago");
     example!(v, check_moment!(c, [2012, 11, 12]), "three months ago");
     example!(v, check_moment!(c, [2011, 2]), "two years ago");
     example!(v, check_moment!(c, [2001]), "2001");
@@ -157,24 +289,47 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 12]), "one year after christmas");
     example!(v, check_moment!(c, [2014, 3, 1], Grain::Month), "march 2014", "in march 2014", "for march 2014");
     example!(v, check_moment!(c, [2005, 5, 1], Grain::Month), "may 2005", "in may 2005", "for may 2005");
-    example!(v, check_moment_span!(c, [2014, 6, 21], [2014, 9, 24]), "summer 2014", "in summer 2014", "for summer 2014");
-    example!(v, check_moment_span!(c, [2014, 12, 21], [2015, 3, 21]), "winter 2014", "in winter 2014", "for winter 2014");
-    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "this summer", "current summer");
-    example!(v, check_moment_span!(c, [2012, 12, 21], [2013, 3, 21]), "this winter");
+    example!(v, check_moment_span!(c, [2014, 6, 1], [2014, 9, 1]), "summer 2014", "in summer 2014", "for summer 2014");
+    example!(v, check_moment_span!(c, [2014, 12, 1], [2015, 3, 1]), "winter 2014", "in winter 2014", "for winter 2014");
+    example!(v, check_moment_span!(c, [2013, 6, 1], [2013, 9, 1]), "this summer", "current summer");
+    example!(v, check_moment_span!(c, [2012, 12, 1], [2013, 3, 1]), "this winter");
+    example!(v, check_moment_span!(c, [2013, 3, 1], [2013, 6, 1]), "this spring", "spring");
+    example!(v, check_moment_span!(c, [2013, 9, 1], [2013, 12, 1]), "this fall", "next autumn");
     example!(v, check_moment!(c, [2013, 12, 25]), "xmas", "christmas", "christmas day");
+    example!(v, check_moment!(c, [2013, 3, 31]), "easter", "easter sunday");
+    example!(v, check_moment!(c, [2025, 4, 20]), "easter 2025", "easter sunday 2025");
     example!(v, check_moment!(c, [2013, 12, 31]), "new year's eve", "new years eve");
     example!(v, check_moment!(c, [2014, 1, 1]), "new year's day", "new years day");
     example!(v, check_moment!(c, [2013, 2, 14]), "valentine's day", "valentine day");
     example!(v, check_moment!(c, [2013, 5, 27]), "memorial day");
     example!(v, check_moment!(c, [2013, 5, 12]), "Mother's Day");
     example!(v, check_moment!(c, [2013, 6, 16]), "Father's Day");
+    example!(v, check_moment_span!(c, [2013, 2, 16], [2013, 2, 18]), "this weekend", "the week-end", "next weekend");
+    let saturday = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 16).and_hms(10, 0, 0)), Grain::Second));
+    example!(v, check_moment_span!(saturday, [2013, 2, 16], [2013, 2, 18]), "this weekend");
+    example!(v, check_moment_span!(saturday, [2013, 2, 23], [2013, 2, 25]), "next weekend");
     example!(v, check_moment_span!(c, [2013, 5, 24, 18], [2013, 5, 28, 0]), "memorial day week-end");
     example!(v, check_moment!(c, [2013, 7, 4]), "independence day", "4th of July", "4 of july");
     example!(v, check_moment!(c, [2013, 9, 2]), "labor day");
     example!(v, check_moment_span!(c, [2013, 8, 30, 18], [2013, 9, 3, 0]), "labor day weekend");
     example!(v, check_moment!(c, [2013, 10, 31]), "halloween");
     example!(v, check_moment!(c, [2013, 11, 28]), "thanksgiving day", "thanksgiving");
+    example!(v, check_moment!(c, [2013, 12, 24]), "the day before christmas", "day before xmas");
+    example!(v, check_moment!(c, [2013, 12, 26]), "the day after christmas");
+    example!(v, check_moment!(c, [2013, 11, 29]), "the friday after thanksgiving", "friday following thanksgiving");
+    example!(v, check_moment!(c, [2013, 11, 25]), "the monday before thanksgiving");
+    example!(v, check_moment!(c, [2024, 12, 24]), "the day before christmas 2024");
+    example!(v, check_moment!(c, [2024, 11, 29]), "the friday after thanksgiving 2024");
+    example!(v, check_moment!(c, [2024, 12, 2]), "the monday after thanksgiving 2024");
     example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 0]), "this evening", "tonight");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 9, 0], [2013, 2, 12, 17, 0]), "during office hours", "working hours today", "today during business hours");
+    example!(v, check_moment_span!(c, [2013, 2, 13, 9, 0], [2013, 2, 13, 17, 0]), "working hours tomorrow", "tomorrow during the office hours");
+    let late_office_hours = c.clone().with_office_hours((10, 30), (19, 0));
+    example!(v, check_moment_span!(late_office_hours, [2013, 2, 12, 10, 30], [2013, 2, 12, 19, 0]), "during office hours");
+    example!(v, check_moment_span!(late_office_hours, [2013, 2, 12, 19], [2013, 2, 13, 0]), "after hours tonight");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 0]), "after hours tonight", "tonight after hours");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 17, 0], [2013, 2, 13, 0, 0]), "after hours today");
+    example!(v, check_moment_with_direction!(c, [2013, 2, 12, 17, 0], Direction::After), "after hours", "after-hours");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 29, 58], [2013, 2, 12, 4, 30, 00]), "last 2 seconds", "last two seconds");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 01], [2013, 2, 12, 4, 30, 04]), "next 3 seconds", "next three seconds");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 28], [2013, 2, 12, 4, 30]), "last 2 minutes", "last two minutes");
@@ -183,14 +338,18 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 2, 11, 4], [2013, 2, 12, 4]), "last 24 hours", "last twenty four hours", "last twenty four hrs", "last 24 hrs", "last 24hrs");
     example!(v, check_moment_span!(c, [2013, 2, 12, 5], [2013, 2, 12, 8]), "next 3 hours", "next three hours");
     example!(v, check_moment_span!(c, [2013, 2, 10], [2013, 2, 12]), "last 2 days", "last two days", "past 2 days");
-    example!(v, check_moment_span!(c, [2013, 2, 13], [2013, 2, 16]), "next 3 days", "next three days");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 15]), "next 3 days", "next three days", "the next 3 days");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 17]), "the coming 5 days");
     example!(v, check_moment_span!(c, [2013, 2, 13], [2013, 2, 16]), "next few days");
     example!(v, check_moment_span!(c, [2013, 1, 28], [2013, 2, 11], Grain::Week), "last 2 weeks", "last two weeks", "past 2 weeks");
-    example!(v, check_moment_span!(c, [2013, 2, 18], [2013, 3, 11], Grain::Week), "next 3 weeks", "next three weeks");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 3, 5]), "next 3 weeks", "next three weeks");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 26]), "the next 2 weeks");
     example!(v, check_moment_span!(c, [2012, 12], [2013, 02]), "last 2 months", "last two months");
     example!(v, check_moment_span!(c, [2013, 3], [2013, 6]), "next 3 months", "next three months");
     example!(v, check_moment_span!(c, [2011], [2013]), "last 2 years", "last two years");
     example!(v, check_moment_span!(c, [2014], [2017]), "next 3 years", "next three years");
+    example!(v, check_moment_span!(c, [2013, 7, 1], [2013, 7, 8], Grain::Week), "the week of July 4th", "week of July 4th");
+    example!(v, check_moment_span!(c, [2024, 7, 1], [2024, 7, 8], Grain::Week), "the week of July 4th 2024", "the week of 07/04/2024");
     example!(v, check_moment_span!(c, [2013, 7, 13], [2013, 7, 16]), "July 13-15", "July 13 to 15", "July 13 thru 15", "July 13 through 15", "July 13 - July 15", "from July 13 to July 15");
     example!(v, check_moment_span!(c, [2013, 8, 8], [2013, 8, 13]), "Aug 8 - Aug 12");
     example!(v, check_moment_span!(c, [2008, 8, 8], [2008, 12, 13]), "Aug 8 to december 12 2008");
@@ -201,29 +360,50 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 2, 14, 9], [2013, 2, 14, 11]), "Thursday from 9a to 11a");
     example!(v, check_moment_span!(c, [2013, 2, 12, 11, 30], [2013, 2, 12, 13, 30]), "11:30-1:30");
     example!(v, check_moment!(c, [2013, 9, 21, 13, 30]), "1:30 PM on Sat, Sep 21");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 22], [2013, 2, 13, 2]), "from 10pm to 2am");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 22], [2013, 2, 13, 22]), "from 10pm to 10pm");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 26]), "within 2 weeks");
     example!(v, check_moment_with_direction!(c, [2013, 2, 12, 14, 0], Direction::After), "from 2:00pm");
     example!(v, check_moment_with_direction!(c, [2013, 2, 12, 14, 0], Direction::Before), "until 2:00pm", "through 2:00pm");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 12, 14]), "by 2:00pm");
-    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 12, 20]), "by EOD");
-    example!(v, check_moment_span!(c, [2013, 2, 12], [2013, 3, 1, 0]), "by EOM");
-    example!(v, check_moment_span!(c, [2013, 2, 12], [2013, 4, 1, 0]), "by the end of next month");
+    example!(v, check_moment_with_direction!(c, [2013, 2, 13], Direction::Before), "by EOD", "by end of day", "by the end of the day");
+    example!(v, check_moment_with_direction!(c, [2013, 3, 1], Direction::Before, Grain::Month), "by EOM", "by end of month");
+    example!(v, check_moment_with_direction!(c, [2013, 2, 18], Direction::Before, Grain::Week), "by the end of the week");
+    example!(v, check_moment_with_direction!(c, [2013, 4, 1], Direction::Before, Grain::Month), "by the end of next month");
+    example!(v, check_moment_with_direction!(c, [2014, 1, 1], Direction::Before, Grain::Year), "by the end of the year");
+    example!(v, check_moment!(c, [2025, 1]), "the beginning of 2025", "beginning of 2025", "the start of 2025");
+    example!(v, check_moment_span!(c, [2013, 3, 29], [2013, 4, 1]), "the end of march", "end of march");
+    example!(v, check_moment_span!(c, [2013, 7, 11], [2013, 7, 22]), "mid july", "the middle of july");
+    example!(v, check_moment_span!(c, [2013, 3, 1], [2013, 3, 11]), "early march", "early in march");
+    example!(v, check_moment_span!(c, [2013, 4, 21], [2013, 5, 1]), "late april", "late in april");
+    example!(v, check_moment_span!(c, [2013, 2, 13], [2013, 2, 15]), "mid-week", "midweek", "the middle of the week");
+    example!(v, check_moment_span!(c, [2013, 2, 18], [2013, 2, 20]), "early next week");
     example!(v, check_moment!(c, [2013, 2, 12, 14]), "today at 2pm", "at 2pm");
     example!(v, check_moment!(c, [2013, 4, 25, 16, 0]), "4/25 at 4:00pm");
     example!(v, check_moment!(c, [2013, 2, 13, 15]), "3pm tomorrow");
+    example!(v, check_12_hour(c.clone(), moment!(2013, 2, 12, 17), true), "5pm");
+    example!(v, check_12_hour(c.clone(), moment!(2013, 2, 13, 17), true), "tomorrow at 5pm");
+    example!(v, check_12_hour(c.clone(), moment!(2013, 2, 12, 17), false), "at 17:00");
+    // A timezone named in the sentence replaces the local one, tomorrow being the day after the reference in it
+    let tomorrow_in = |tz: Tz, hour| Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0).with_timezone(&tz).date().succ().and_hms(hour, 0, 0).with_timezone(&Local));
+    example!(v, check_moment(c.clone(), tomorrow_in(Tz::America__New_York, 15), Grain::Hour, Precision::Exact, None), "tomorrow at 3pm est");
+    example!(v, check_moment(c.clone(), tomorrow_in(Tz::UTC, 12), Grain::Hour, Precision::Exact, None), "tomorrow at noon utc");
     example!(v, check_moment_with_direction!(c, [2013, 2, 12, 14], Direction::After), "after 2 pm");
     example!(v, check_moment_with_direction!(c, [2013, 2, 17], Direction::After), "after 5 days");
     example!(v, check_moment_with_direction!(c, [2013, 2, 12, 11], Direction::Before), "before 11 am");
     example!(v, check_moment_span!(c, [2013, 2, 13], [2013, 2, 13, 11]), "tomorrow before 11 am", "13th feb. 2013 until 11am");
-    example!(v, check_moment_span!(c, [2013, 2, 12, 12], [2013, 2, 12, 19]), "in the afternoon");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 12], [2013, 2, 12, 18]), "in the afternoon", "this afternoon");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4], [2013, 2, 12, 12]), "this morning");
     example!(v, check_moment!(c, [2013, 2, 12, 13, 30]), "at 1:30pm", "1:30pm", "at thirteen thirty");
     example!(v, check_moment!(c, [2013, 2, 12, 4, 45, 0]), "in 15 minutes");
     example!(v, check_moment_span!(c, [2013, 2, 12, 13], [2013, 2, 12, 17]), "after lunch");
     example!(v, check_moment!(c, [2013, 2, 12, 10, 30]), "10:30");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4], [2013, 2, 12, 12]), "morning");
     example!(v, check_moment!(c, [2013, 2, 18]), "next monday");
-    example!(v, check_moment!(c, [2013, 2, 12, 12]), "at 12pm", "at noon");
-    example!(v, check_moment!(c, [2013, 2, 13, 0]), "at 12am", "at midnight");
+    example!(v, check_moment!(c, [2013, 2, 12, 12]), "at 12pm", "at noon", "12 p.m.", "12 P.M.", "12p.m.");
+    example!(v, check_moment!(c, [2013, 2, 13, 0]), "at 12am", "at midnight", "12 a.m.", "12 A.M.", "12a.m.");
+    example!(v, check_moment!(c, [2013, 2, 12, 17]), "5p.m.", "5 P.M.", "5PM", "5 p. m.", "at 5 pm.");
+    example!(v, check_moment!(c, [2013, 2, 12, 5]), "5a.m.", "5 A.M.", "5AM", "5 a. m.");
     example!(v, check_moment!(c, [2013, 3]), "March", "in March");
     example!(v, check_moment!(c, [2016, 12, 15]), "12.15.2016", "12.15.16");
     example!(v, check_moment!(c, [2017, 05, 10]), "wednesday the 10th of may");
@@ -238,6 +418,7 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 2, 0]), "during two months", "for 2 months");
     example!(v, check_duration!([1]), "during a year");
     example!(v, check_duration!([0, 0, 0, 0, 0, 1, 3]), "during one minute and three seconds", "for 1 minute and 3 seconds", "for 1min3sec");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 0, 0, 250]), "250ms", "250 milliseconds");
     example!(v, check_duration!([0, 0, 0, 0, 7, 15], Precision::Approximate), "during about seven hours and a quarter");
     example!(v, check_duration!([0, 0, 0, 0, 3, 30], Precision::Approximate), "about three and a half hours", "around 3 hours and a half");
     example!(v, check_duration!([0, 0, 0, 0, 1, 30], Precision::Approximate), "about one hour and a half");
@@ -245,8 +426,15 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "during about a quarter of an hour", "for approximately 1/4hour", "around a quarter-hour");
     example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "for three-quarters of an hour", "for 3/4h", "for 3/4 h", "for 3/4 hour");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "during one hour", "for 1h");
+    example!(v, check_duration!([0, 0, 0, 0, 1, 30]), "an hour and a half", "1 hour and 30 minutes", "one hour thirty minutes", "1h30", "one and a half hours");
+    example!(v, check_duration!([0, 0, 0, 0, 2, 30]), "two and a half hours", "2 hours and a half", "2 and a half hours");
     example!(v, check_duration!([0, 0, 2]), "for 2 weeks");
     example!(v, check_duration!([0, 0, 0, 2], Precision::Approximate), "around two days");
+    example!(v, check_duration!([0, 0, 0, 2]), "a couple of days", "couple days");
+    example!(v, check_duration!([0, 0, 3], Precision::Approximate), "a few weeks", "for a few weeks");
+    example!(v, check_duration!([0, 0, 0, 4], Precision::Approximate), "several days");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 30]), "half an hour", "for half an hour");
+    example!(v, check_duration!([0, 0, 0, 0, 12]), "half a day");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -258,12 +446,25 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(16), "16", "sixteen");
     example!(v, check_integer(17), "17", "seventeen");
     example!(v, check_integer(18), "18", "eighteen");
+    example!(v, check_integer(12), "a dozen", "one dozen");
+    example!(v, check_integer(24), "two dozen");
+    example!(v, check_integer(6), "half a dozen");
+    example!(v, check_integer(20), "a score");
+    example!(v, check_integer(60), "three score");
+    example!(v, check_integer(144), "a gross");
     example!(v, check_float(1.1), "1.1", "1.10", "01.10", "one point ten", "one point one");
     example!(v, check_float(0.3), "0.3", "0.30", "zero point three");
     example!(v, check_float(0.5), "0.5", "0.50", "zero point five");
     example!(v, check_float(0.05), "0.05", "zero point zero five");
     example!(v, check_float(32.75), "32.75", "thirty-two point seventy-five");
     example!(v, check_float(10.08), "10.08", "ten point zero eight");
+    example!(v, check_float(-3.2), "-3.2", "minus 3.2", "negative three point two");
+    example!(v, check_float(1.0), "1.000");
+    example!(v, check_float(0.0025), "2.5e-3");
+    example!(v, check_integer(1500), "1.5e3", "15e2");
+    example!(v, check_integer(1000000), "1,000,000", "1e6");
+    example!(v, check_integer(-40), "negative forty", "minus forty", "-40");
+    example!(v, check_integer(-5), "minus 5", "minus five");
     example!(v,
              check_integer(100000),
              "100,000",
@@ -300,6 +501,7 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              check_integer(721012),
              "seven hundred twenty-one thousand twelve",
              "seven hundred twenty-one thousand and twelve");
+    example!(v, check_integer(1521082), "one million five hundred twenty-one thousand eighty-two");
     example!(v,
              check_integer(31256721),
              "thirty-one million two hundred fifty-six thousand seven hundred twenty-one");
@@ -307,4 +509,49 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(3), "the 3rd", "3rd", "third");
     example!(v, check_ordinal(2), "the 2nd", "2nd", "second");
     example!(v, check_ordinal(21), "the twenty first");
+    example!(v, check_ordinal_range(1, 2), "the first two", "first 2");
+    example!(v, check_ordinal_range(3, 6), "third to sixth", "from the 3rd to the 6th");
+    example!(v, check_ordinal_range(2, 5), "2nd through 5th", "2nd-5th");
+    example!(v, check_number_range(5.0, 10.0, true, true), "between 5 and 10", "5 to 10", "5–10", "from five to ten");
+    example!(v, check_number_range(1.5, 2.5, true, true), "between 1.5 and 2.5");
+    example!(v, check_number_comparison(CompareOp::Gt, 100.0), "more than 100", "over 100", "more than a hundred");
+    example!(v, check_number_comparison(CompareOp::Ge, 3.0), "at least 3", "no less than three");
+    example!(v, check_number_comparison(CompareOp::Lt, 10.0), "under 10", "less than ten");
+    example!(v, check_number_comparison(CompareOp::Le, 50.0), "at most 50", "no more than fifty", "up to 50");
+    example!(v, check_phone_number("555-123-4567"), "555-123-4567");
+    example!(v, check_phone_number("(555) 123-4567"), "(555) 123-4567");
+    example!(v, check_phone_number("+1 (555) 123-4567"), "+1 (555) 123-4567");
+    example!(v, check_phone_number("+33 6 12 34 56 78"), "+33 6 12 34 56 78");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(5.0, Grain::Year), "5 years old", "five year old", "5-year-old");
+    example!(v, check_age(30.0, Grain::Year), "aged 30", "aged thirty years");
+    example!(v, check_age(6.0, Grain::Month), "six months old", "6-month-old", "aged 6 months");
+    example!(v, check_age(3.0, Grain::Week), "three weeks old");
+}
+
+/// Texts which must not be fully matched as the given kind of dimension
+#[cfg(test)]
+pub fn counter_examples(v: &mut Vec<(DimensionKind, &'static str)>) {
+    // Decades are given with "the" or an apostrophe
+    v.push((DimensionKind::Datetime, "10s"));
+    v.push((DimensionKind::Datetime, "30s"));
+    // Lone letters are only units right after the digits, and never for metres
+    v.push((DimensionKind::Quantity, "5m"));
+    v.push((DimensionKind::Quantity, "5 m"));
+    v.push((DimensionKind::Quantity, "2 l"));
+    v.push((DimensionKind::Quantity, "3 g"));
+    // February has no 31st
+    v.push((DimensionKind::Datetime, "the 31st of february"));
+    // Short numbers are no phone numbers, nor seven digits without an area code
+    v.push((DimensionKind::PhoneNumber, "1234"));
+    v.push((DimensionKind::PhoneNumber, "555-1234"));
+    // Words which look like timezones once lowercased
+    v.push((DimensionKind::Datetime, "3pm west"));
+    v.push((DimensionKind::Datetime, "5 pt"));
+    v.push((DimensionKind::Datetime, "at 5 et"));
+    // Phone numbers are neither numbers nor ranges
+    v.push((DimensionKind::Number, "555-123-4567"));
+    v.push((DimensionKind::NumberRange, "555-123-4567"));
 }
@@ -29,8 +29,11 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
     Ok(b.build())
 }
 
+/// Dimensions parsed by the rules of the language
+pub const DIMS: &[rustling_ontology_values::DimensionKind] = &[Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage];
+
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage];
+    DIMS.to_vec()
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
@@ -187,6 +187,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             value: a.value().value(),
             unit: None,
             latent: true,
+            qualifier: None,
         })
     });
     b.rule_2("<latent temp> temp",
@@ -197,6 +198,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Celcius",
@@ -207,6 +209,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     // FIXME: Check double Kelvin removal
@@ -218,6 +221,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("kelvin"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -228,6 +232,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<latent temp> temp bajo cero",
@@ -237,6 +242,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
                      latent: false,
+                     qualifier: None,
                      ..*a.value()
                  })
              });
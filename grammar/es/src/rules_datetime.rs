@@ -492,7 +492,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  } else {
                      helpers::hour(12, false)?.span_to(&helpers::hour(0, false)?, false)?
                  };
-                 Ok(a.value().intersect(&day_period)?.form(a.value().form.clone()))
+                 Ok(a.value().intersect(&day_period)?.form(a.value().form.clone()).mark_12_hour())
              }
     );
     b.rule_1_terminal("quarter (relative minutes)",
@@ -557,17 +557,19 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("dd/mm/yy or dd/mm/yyyy",
                       b.reg(r#"(0?[1-9]|[12]\d|3[01])[-\./](0?[1-9]|1[0-2])[-\./](\d{2,4})"#)?,
-                      |text_match| helpers::year_month_day(
-                          text_match.group(3).parse()?,
-                          text_match.group(2).parse()?,
+                      |text_match| helpers::numeric_date(
+                          DateOrder::DayMonthYear,
+                          Some(text_match.group(3).parse()?),
                           text_match.group(1).parse()?,
-                      )
+                          text_match.group(2).parse()?)
     );
     b.rule_1_terminal("dd/mm",
                       b.reg(r#"(0?[1-9]|[12]\d|3[01])[\./](1[0-2]|0?[1-9])"#)?,
-                      |text_match| helpers::month_day(
-                          text_match.group(2).parse()?,
-                          text_match.group(1).parse()?)
+                      |text_match| helpers::numeric_date(
+                          DateOrder::DayMonthYear,
+                          None,
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)
     );
     // End of Written dates in numeric formats
     b.rule_1_terminal("beginning of day",
@@ -295,6 +295,7 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(0.03), "0,03", "cero punto cero tres", "cero coma cero tres");
     example!(v, check_float(32.75), "32,75", "treinta y dos punto setenta y cinco", "treinta y dos coma setenta y cinco");
     example!(v, check_float(10.08), "10,08", "diez punto cero ocho", "diez coma cero ocho");
+    example!(v, check_integer(1500), "1,5e3");
     // TODO: Check if want/need support for ordinal special character/overscript
     example!(v, check_ordinal(1), "1o", "1a", "primer", "primero", "primera", "1º", "1ª");
     example!(v, check_ordinal(3), "3o", "3o", "3a", "3º", "3ª", "tercero", "tercera");
@@ -9,6 +9,18 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
         b.reg(r"(?:%|p\.c\.|p. cents?|pour[ -]?cents?)")?,
         |number, _| Ok(PercentageValue(number.value().value()))
     );
+    b.rule_1_terminal("demi pour cent",
+        b.reg(r"(?:un )?demi(?: de)? pour[ -]?cents?")?,
+        |_| Ok(PercentageValue(0.5))
+    );
+    b.rule_1_terminal("quart de pour cent",
+        b.reg(r"(?:un )?quart(?: de)? pour[ -]?cents?")?,
+        |_| Ok(PercentageValue(0.25))
+    );
+    b.rule_1_terminal("trois quarts de pour cent",
+        b.reg(r"trois quarts?(?: de)? pour[ -]?cents?")?,
+        |_| Ok(PercentageValue(0.75))
+    );
     Ok(())
 }
 
@@ -187,6 +199,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                     value: a.value().value(),
                     unit: None,
                     latent: true,
+                    qualifier: None,
                 })
             });
     b.rule_2("<latent temp> degrees",
@@ -197,6 +210,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Celcius",
@@ -207,6 +221,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -217,6 +232,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Kelvin",
@@ -227,6 +243,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("kelvin"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> en dessous de zero",
@@ -236,6 +253,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
                      latent: false,
+                     qualifier: None,
                      ..*a.value()
                  })
              });
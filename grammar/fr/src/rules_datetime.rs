@@ -1,7 +1,7 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain};
+use rustling_ontology_moment::{Weekday, Grain, Tz};
 
 pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
@@ -382,6 +382,21 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"h\.?(?:eure)?s?(?: pile| exactement| pr[eé]cises?)?"#)?,
              |a, _| Ok(a.value().clone().not_latent())
     );
+    b.rule_2("<time-of-day> <fuseau horaire>",
+             datetime_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"(?:(heure de paris|heure fran[cç]aise)|(utc|gmt))"#)?,
+             |a, text_match| {
+                 let timezone = if text_match.group(1).is_empty() {
+                     match helpers::timezone_abbreviation(&text_match.group(2)) {
+                         Some(timezone) => timezone,
+                         None => return Err(RuleError::Invalid.into()),
+                     }
+                 } else {
+                     Tz::Europe__Paris
+                 };
+                 Ok(a.value().clone().not_latent().timezone(timezone))
+             }
+    );
     b.rule_2("<time-of-day> (heures) pile",
              datetime_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
              b.reg(r#"pile"#)?,
@@ -586,17 +601,19 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     // But this seems too permissive?
     b.rule_1_terminal("dd/mm/yy or dd/mm/yyyy",
                       b.reg(r#"(0?[1-9]|[12]\d|3[01])[-\./ ](0?[1-9]|1[0-2])[-\./ ](\d{2,4})"#)?,
-                      |text_match| helpers::year_month_day(
-                          text_match.group(3).parse()?,
-                          text_match.group(2).parse()?,
+                      |text_match| helpers::numeric_date(
+                          DateOrder::DayMonthYear,
+                          Some(text_match.group(3).parse()?),
                           text_match.group(1).parse()?,
-                      )
+                          text_match.group(2).parse()?)
     );
     b.rule_1_terminal("dd/mm",
                       b.reg(r#"(0?[1-9]|[12]\d|3[01])[\./ ](1[0-2]|0?[1-9])"#)?,
-                      |text_match| helpers::month_day(
-                          text_match.group(2).parse()?,
-                          text_match.group(1).parse()?)
+                      |text_match| helpers::numeric_date(
+                          DateOrder::DayMonthYear,
+                          None,
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)
     );
     // End of Written time/date in numeric formats
     b.rule_1_terminal("matin",
@@ -140,3 +140,33 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+
+fn age_unit(unit: &str) -> RuleResult<Grain> {
+    if unit.starts_with("an") {
+        Ok(Grain::Year)
+    } else if unit == "mois" {
+        Ok(Grain::Month)
+    } else if unit.starts_with("semaine") {
+        Ok(Grain::Week)
+    } else if unit.starts_with("jour") {
+        Ok(Grain::Day)
+    } else {
+        Err(RuleError::Invalid.into())
+    }
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    // "<number> ans" alone is left to the durations, e.g. "pendant 5 ans"
+    b.rule_3("âgé de <number> <unit>",
+             b.reg(r#"[âa]g[ée]e?s? d['e]|(?:[àa] )?l'[âa]ge d['e]"#)?,
+             number_check_by_range!(0.0),
+             b.reg(r#"(ans?|ann[ée]es?|mois|semaines?|jours?)"#)?,
+             |_, number, text_match| Ok(AgeValue::new(number.value().value(), age_unit(text_match.group(1).as_ref())?))
+    );
+    b.rule_2("<number> <unit> d'âge",
+             number_check_by_range!(0.0),
+             b.reg(r#"(ans?|ann[ée]es?|mois|semaines?|jours?) d'[âa]ge"#)?,
+             |number, text_match| Ok(AgeValue::new(number.value().value(), age_unit(text_match.group(1).as_ref())?))
+    );
+    Ok(())
+}
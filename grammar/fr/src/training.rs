@@ -7,6 +7,11 @@ pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_percentage(0.3), "0,3%", "zéro virgule trois pour cent");
     example!(v, check_percentage(15.0), "15%", "+15%", "quinze pour cent");
     example!(v, check_percentage(355.0), "355 %", "355 pourcent");
+    example!(v, check_percentage(0.5), "0,5%", "un demi pour cent", "zéro virgule cinq pour cent");
+    example!(v, check_percentage(0.25), "un quart de pour cent", "0,25 %");
+    example!(v, check_percentage(0.75), "trois quarts de pour cent");
+    example!(v, check_percentage(100.0), "cent pour cent", "100%");
+    example!(v, check_percentage(150.0), "cent cinquante pour cent", "150 %");
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -14,6 +19,7 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
     example!(v, check_temperature(32.0, Some("celsius")), "trente deux degrés celsius", "trente deux degrés centigrade", "32°C", "32 °c");
     example!(v, check_temperature(-27.0, Some("celsius")), "moins 27 celsius", "-27C", "- 27 c");
     example!(v, check_temperature(-5.0, Some("fahrenheit")), "moins cinq degrés fahrenheit", "-5 °F", "- 5°f");
+    example!(v, check_temperature(-5.0, Some("degree")), "moins 5 degrés", "moins cinq degrés", "-5°");
     example!(v, check_temperature(168.0, Some("fahrenheit")), "cent soixante-huit fahrenheit", "168 F", "168f");
     example!(v, check_temperature(10.0, Some("kelvin")), "dix degrés kelvin", "10 °K", "10°k");
     example!(v, check_temperature(21.0, Some("kelvin")), "21 kelvin", "21 K", "21k");
@@ -258,6 +264,13 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 5, 0, 0]), "dans une demi heure", "dans 1/2h", "dans 1/2 h", "dans 1/2 heure");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 15, 0]), "dans trois quarts d'heure", "dans 3/4h", "dans 3/4 h", "dans 3/4 heure");
     example!(v, check_moment!(c, [2016, 12, 15]), "15.12.2016", "15.12.16");
+    example!(v, check_moment!(c, [2024, 4, 3]), "03/04/2024");
+    let month_first = c.clone().with_date_order(DateOrder::MonthDayYear);
+    example!(v, check_moment!(month_first, [2024, 3, 4]), "03/04/2024");
+    example!(v, check_12_hour(c.clone(), moment!(2013, 2, 12, 17), false), "17h");
+    // A timezone named in the sentence replaces the local one, tomorrow being the day after the reference in it
+    let tomorrow_in = |tz: Tz, hour| Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0).with_timezone(&tz).date().succ().and_hms(hour, 0, 0).with_timezone(&Local));
+    example!(v, check_moment(c.clone(), tomorrow_in(Tz::Europe__Paris, 14), Grain::Hour, Precision::Exact, None), "demain à 14h heure de paris");
     example!(v, check_moment!(c, [2014, 3, 1], Grain::Month), "mars 2014", "en mars 2014", "pour mars 2014");
     example!(v, check_moment!(c, [2005, 5, 1], Grain::Month), "mai 2005", "en mai 2005", "pour mai 2005");
     example!(v, check_moment_span!(c, [2014, 6, 21], [2014, 9, 24]), "été 2014", "pour l'été 2014");
@@ -341,9 +354,13 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(1.1), "1,1", "1,10", "un virgule dix");
     example!(v, check_float(0.5), "0,5", "0,50", "zéro virgule cinq", "zero point cinq");
     example!(v, check_float(0.3), "0,3", "0,30", "zéro virgule trois", "zero point trois");
+    example!(v, check_float(-3.2), "-3,2", "moins 3,2", "moins trois virgule deux");
+    example!(v, check_integer(-40), "moins quarante", "négatif quarante", "-40");
     example!(v, check_float(0.03), "0,03", "zéro virgule zéro trois", "zero point zero trois");
     example!(v, check_float(32.75), "32,75", "trente-deux virgule soixante-quinze");
     example!(v, check_float(10.08), "10,08", "dix virgule zéro huit", "dix point zéro huit");
+    example!(v, check_float(1000.5), "1.000,50");
+    example!(v, check_integer(1500), "1,5e3");
     example!(v, check_ordinal(1), "1er", "1ere", "le 1er");
     example!(v,
              check_ordinal(3),
@@ -354,3 +371,9 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "troisième",
              "troisieme");
 }
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(30.0, Grain::Year), "âgé de 30 ans", "âgée de trente ans", "à l'âge de 30 ans");
+    example!(v, check_age(6.0, Grain::Month), "âgé de six mois", "6 mois d'âge");
+    example!(v, check_age(5.0, Grain::Year), "5 ans d'âge", "agés de cinq ans");
+}
@@ -201,6 +201,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             value: a.value().value(),
             unit: None,
             latent: true,
+            qualifier: None,
         })
     });
     // FIXME: should be with number check
@@ -212,6 +213,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Celsius",
@@ -222,6 +224,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -232,6 +235,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Kelvin",
@@ -242,6 +246,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("kelvin"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> temp below zero",
@@ -251,6 +256,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
                      latent: false,
+                     qualifier: None,
                      ..*a.value()
                  })
              });
@@ -227,6 +227,10 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           let value: f64 = reformatted_string.parse()?;
                           FloatValue::new(value)
                       });
+    b.rule_1_terminal("number in scientific notation",
+                      b.reg(r#"(\d*,?\d+)e([-+]?\d{1,3})"#)?,
+                      |text_match| helpers::scientific_number(&text_match.group(1).replace(",", "."), text_match.group(2))
+    );
     b.rule_2("numbers prefix with -, negative or minus",
              b.reg(r#"-|meno"#)?,
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -299,6 +299,7 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(0.03), "0,03", "zero punto zero tre", "zero virgola zero tre");
     example!(v, check_float(32.75), "32,75", "trenta due punto settanta cinque", "trenta due virgola settanta cinque");
     example!(v, check_float(10.08), "10,08", "dieci punto zero otto");//, "dieci virgola zero otto");
+    example!(v, check_integer(1500), "1,5e3");
     example!(v, check_ordinal(1), "1o", "1a", "il 1o", "la 1a", "1°");
     example!(v, check_ordinal(3), "3o", "il 3o", "3a", "la 3a", "3°", "terzo", "terza");
 }
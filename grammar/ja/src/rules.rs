@@ -498,6 +498,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: Some("degree"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Celsius",
@@ -508,6 +509,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -518,6 +520,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Kelvin",
@@ -528,6 +531,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: Some("kelvin"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_3("<temp> Celsius",
@@ -539,6 +543,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_3("<temp> Celsius below zero",
@@ -550,6 +555,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: -1.0 * a.value().value(),
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_3("<temp> Fahrenheit",
@@ -561,6 +567,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: None,
                  })
         }
     );
@@ -573,6 +580,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: -1.0 * a.value().value(),
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: None,
                  })
         }
     );
@@ -583,6 +591,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
                      latent: false,
+                     qualifier: None,
                      ..*a.value()
                  })
              });
@@ -104,6 +104,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             value: a.value().value(),
             unit: None,
             latent: true,
+            qualifier: None,
         })
     });
     b.rule_2(
@@ -116,6 +117,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                     value: -1.0 * temp.value().value,
                     unit: temp.value().unit,
                     latent: false,
+                    qualifier: None,
                 })
             } else {
                 Ok(temp.value().clone())
@@ -132,6 +134,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                     value: -1.0 * temp.value().value,
                     unit: temp.value().unit,
                     latent: false,
+                    qualifier: None,
                 })
             } else {
                 Ok(temp.value().clone())
@@ -148,6 +151,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                 value: a.value().value,
                 unit: Some("degree"),
                 latent: false,
+                qualifier: None,
             })
         },
     );
@@ -160,6 +164,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                 value: a.value().value,
                 unit: Some("celsius"),
                 latent: false,
+                qualifier: None,
             })
         },
     );
@@ -172,6 +177,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                 value: a.value().value,
                 unit: Some("celsius"),
                 latent: false,
+                qualifier: None,
             })
         },
     );
@@ -184,6 +190,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                 value: a.value().value,
                 unit: Some("fahrenheit"),
                 latent: false,
+                qualifier: None,
             })
         },
     );
@@ -196,6 +203,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                 value: a.value().value,
                 unit: Some("fahrenheit"),
                 latent: false,
+                qualifier: None,
             })
         },
     );
@@ -196,6 +196,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             value: a.value().value(),
             unit: None,
             latent: true,
+            qualifier: None,
                 })
             });
     b.rule_2("<latent temp> temp",
@@ -206,6 +207,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Celsius",
@@ -216,6 +218,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Kelvin",
@@ -226,6 +229,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("kelvin"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -236,6 +240,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: None,
                  })
              });
     b.rule_2("<latent temp> below zero",
@@ -245,6 +250,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
                      latent: false,
+                     qualifier: None,
                      ..*a.value()
                  })
              });
@@ -255,6 +261,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
                      latent: false,
+                     qualifier: None,
                      ..*a.value()
                  })
              });
@@ -491,7 +491,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  } else {
                      helpers::hour(12, false)?.span_to(&helpers::hour(0, false)?, false)?
                  };
-                 Ok(a.value().intersect(&day_period)?.form(a.value().form.clone()))
+                 Ok(a.value().intersect(&day_period)?.form(a.value().form.clone()).mark_12_hour())
              }
     );
     b.rule_1_terminal("morning",
@@ -990,11 +990,11 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("dd[/-.]mm[/-.]yyyy",
                       b.reg(r#"(?:no dia )?(3[01]|[12]\d|0?[1-9])[-/.](0?[1-9]|1[0-2])[-/.](\d{2,4})"#)?,
-                      |text_match| helpers::year_month_day(
-                          text_match.group(3).parse()?,
-                          text_match.group(2).parse()?,
-                          text_match.group(1).parse()?
-                      )
+                      |text_match| helpers::numeric_date(
+                          DateOrder::DayMonthYear,
+                          Some(text_match.group(3).parse()?),
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)
     );
     b.rule_1_terminal("yyyy-mm-dd",
                       b.reg(r#"(?:no dia )?(\d{2,4})-(0?[1-9]|1[0-2])-(3[01]|[12]\d|0?[1-9])"#)?,
@@ -1006,10 +1006,11 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("dd[/-]mm",
                       b.reg(r#"(?:no dia )?(3[01]|[12]\d|0?[1-9])[-/](0?[1-9]|1[0-2])"#)?,
-                      |text_match| helpers::month_day(
-                          text_match.group(2).parse()?,
-                          text_match.group(1).parse()?
-                      )
+                      |text_match| helpers::numeric_date(
+                          DateOrder::DayMonthYear,
+                          None,
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)
     );
     //    b.rule_3("<time> <part-of-day>",
 //            datetime_check!(|time: &DatetimeValue| excluding_form!(Form::Year(_))(time) && excluding_form!(Form::Month(_))(time)),
@@ -34,6 +34,7 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(0.03), "0,03", "zero vírgula zero três");
     example!(v, check_float(32.75), "32,75", "trinta e dois vírgula setenta e cinco");
     example!(v, check_float(10.08), "10,08", "dez vírgula zero oito");
+    example!(v, check_integer(1500), "1,5e3");
     example!(v, check_ordinal(1), "1o", "1a", "primeiro", "primeira", "1º", "1ª");
     example!(v, check_ordinal(3), "3o", "3a", "3º", "3ª", "terceiro", "terceira");
     example!(v, check_ordinal(2), "segundo", "2º", "2o");
@@ -20,6 +20,9 @@ macro_rules! lang_enum {
             $( $lang, )*
         }
 
+        /// All the languages, in the order of `Lang::all`
+        pub const LANGS: &[Lang] = &[$( Lang::$lang, )*];
+
         impl Lang {
             pub fn all() -> Vec<Lang> {
                 vec![
@@ -81,6 +84,28 @@ pub fn dims(lang: Lang) -> Vec<rustling_ontology_values::DimensionKind> {
     }
 }
 
+/// Languages supported by the ontology, see `supported_dimensions` for the dimensions of each of
+/// them.
+pub fn supported_languages() -> &'static [Lang] {
+    LANGS
+}
+
+/// Dimensions parsed by the rules of a given language, e.g. to show only the entity types of the
+/// language. Same as `dims`, without allocating.
+pub fn supported_dimensions(lang: Lang) -> &'static [rustling_ontology_values::DimensionKind] {
+    match lang {
+        Lang::DE => de::DIMS,
+        Lang::EN => en::DIMS,
+        Lang::ES => es::DIMS,
+        Lang::FR => fr::DIMS,
+        Lang::PT => pt::DIMS,
+        Lang::JA => ja::DIMS,
+        Lang::KO => ko::DIMS,
+        Lang::ZH => zh::DIMS,
+        Lang::IT => it::DIMS,
+    }
+}
+
 /// Obtain examples for a given language.
 pub fn examples(lang: Lang) -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     match lang {
@@ -683,6 +683,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: None,
                      latent: true,
+                     qualifier: None,
                  })
              });
 
@@ -694,6 +695,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("degree"),
                      latent: false,
+                     qualifier: None,
                  })
              });
 
@@ -705,6 +707,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: None,
                  })
              });
 
@@ -717,6 +720,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: b.value().value,
                      unit: Some("celsius"),
                      latent: false,
+                     qualifier: None,
                  })
              }
     );
@@ -729,6 +733,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: None,
                  })
              });
 
@@ -741,6 +746,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: b.value().value,
                      unit: Some("fahrenheit"),
                      latent: false,
+                     qualifier: None,
                  })
              }
     );
@@ -97,12 +97,20 @@ pub struct TestOutput {
 pub enum SlotValue {
     Number(NumberValue),
     Ordinal(OrdinalValue),
+    OrdinalRange(OrdinalRangeValue),
     Percentage(PercentageValue),
     InstantTime(InstantTimeValue),
     TimeInterval(TimeIntervalValue),
     AmountOfMoney(AmountOfMoneyValue),
     Temperature(TemperatureValue),
     Duration(DurationValue),
+    Quantity(QuantityValue),
+    PhoneNumber(PhoneNumberValue),
+    Age(AgeValue),
+    NumberRange(NumberRangeValue),
+    NumberComparison(NumberComparisonValue),
+    TemperatureRange(TemperatureRangeValue),
+    Custom(CustomValue),
 }
 
 impl From<Output> for SlotValue {
@@ -117,6 +125,10 @@ impl From<Output> for SlotValue {
             Output::Ordinal(ordinal) => SlotValue::Ordinal(OrdinalValue {
                 value: ordinal.0 as i64,
             }),
+            Output::OrdinalRange(range) => SlotValue::OrdinalRange(OrdinalRangeValue {
+                start: range.start,
+                end: range.end,
+            }),
             Output::Percentage(percentage) => SlotValue::Percentage(PercentageValue {
                 value: percentage.0.into(),
             }),
@@ -125,14 +137,22 @@ impl From<Output> for SlotValue {
                 grain: datetime.grain.into(),
                 precision: datetime.precision.into(),
             }),
+            // Duckling has no recurring times, only the first occurrence is kept
+            Output::DatetimeRecurring(datetime_recurring) => {
+                SlotValue::InstantTime(InstantTimeValue {
+                    value: datetime_recurring.first.moment,
+                    grain: datetime_recurring.first.grain.into(),
+                    precision: datetime_recurring.first.precision.into(),
+                })
+            }
             Output::DatetimeInterval(datetime_interval) => match datetime_interval.interval_kind {
-                DatetimeIntervalKind::After(datetime) => {
+                DatetimeIntervalKind::After { datetime, .. } => {
                     SlotValue::TimeInterval(TimeIntervalValue {
                         from: Some(datetime.moment),
                         to: None,
                     })
                 }
-                DatetimeIntervalKind::Before(datetime) => {
+                DatetimeIntervalKind::Before { datetime, .. } => {
                     SlotValue::TimeInterval(TimeIntervalValue {
                         from: None,
                         to: Some(datetime.moment),
@@ -155,16 +175,50 @@ impl From<Output> for SlotValue {
                 unit: temperature.unit.map(|it| it.to_string()),
             }),
             Output::Duration(duration) => SlotValue::Duration(DurationValue {
-                years: *duration.period.0.get(Grain::Year as usize).unwrap_or(&0),
-                quarters: *duration.period.0.get(Grain::Quarter as usize).unwrap_or(&0),
-                months: *duration.period.0.get(Grain::Month as usize).unwrap_or(&0),
-                weeks: *duration.period.0.get(Grain::Week as usize).unwrap_or(&0),
-                days: *duration.period.0.get(Grain::Day as usize).unwrap_or(&0),
-                hours: *duration.period.0.get(Grain::Hour as usize).unwrap_or(&0),
-                minutes: *duration.period.0.get(Grain::Minute as usize).unwrap_or(&0),
-                seconds: *duration.period.0.get(Grain::Second as usize).unwrap_or(&0),
+                years: *duration.period.0.get(moment::Grain::Year as usize).unwrap_or(&0)
+                    + 10 * *duration.period.0.get(moment::Grain::Decade as usize).unwrap_or(&0)
+                    + 100 * *duration.period.0.get(moment::Grain::Century as usize).unwrap_or(&0),
+                quarters: *duration.period.0.get(moment::Grain::Quarter as usize).unwrap_or(&0),
+                months: *duration.period.0.get(moment::Grain::Month as usize).unwrap_or(&0),
+                weeks: *duration.period.0.get(moment::Grain::Week as usize).unwrap_or(&0),
+                days: *duration.period.0.get(moment::Grain::Day as usize).unwrap_or(&0),
+                hours: *duration.period.0.get(moment::Grain::Hour as usize).unwrap_or(&0),
+                minutes: *duration.period.0.get(moment::Grain::Minute as usize).unwrap_or(&0),
+                seconds: *duration.period.0.get(moment::Grain::Second as usize).unwrap_or(&0),
+                milliseconds: *duration.period.0.get(moment::Grain::Millisecond as usize).unwrap_or(&0),
                 precision: duration.precision.into(),
             }),
+            Output::Quantity(quantity) => SlotValue::Quantity(QuantityValue {
+                value: quantity.value,
+                unit: quantity.unit,
+                product: quantity.product,
+            }),
+            Output::PhoneNumber(phone_number) => SlotValue::PhoneNumber(PhoneNumberValue {
+                value: phone_number.0,
+            }),
+            Output::Age(age) => SlotValue::Age(AgeValue {
+                value: age.value,
+                unit: age.unit.into(),
+            }),
+            Output::NumberRange(range) => SlotValue::NumberRange(NumberRangeValue {
+                low: range.low,
+                high: range.high,
+                low_inclusive: range.low_inclusive,
+                high_inclusive: range.high_inclusive,
+            }),
+            Output::NumberComparison(comparison) => SlotValue::NumberComparison(NumberComparisonValue {
+                op: comparison.op.into(),
+                value: comparison.value,
+            }),
+            Output::TemperatureRange(range) => SlotValue::TemperatureRange(TemperatureRangeValue {
+                low: range.low,
+                high: range.high,
+                unit: range.unit.map(|it| it.to_string()),
+            }),
+            Output::Custom(custom) => SlotValue::Custom(CustomValue {
+                tag: custom.tag,
+                value: custom.value,
+            }),
         }
     }
 }
@@ -202,6 +256,12 @@ pub struct OrdinalValue {
     pub value: i64,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Copy, Debug)]
+pub struct OrdinalRangeValue {
+    pub start: i64,
+    pub end: i64,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Debug)]
 pub struct PercentageValue {
     pub value: f64,
@@ -242,6 +302,51 @@ pub struct TemperatureValue {
     pub unit: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct TemperatureRangeValue {
+    pub low: f64,
+    pub high: f64,
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct QuantityValue {
+    pub value: f64,
+    pub unit: Option<String>,
+    pub product: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PhoneNumberValue {
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct AgeValue {
+    pub value: f64,
+    pub unit: Grain,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct NumberRangeValue {
+    pub low: f64,
+    pub high: f64,
+    pub low_inclusive: bool,
+    pub high_inclusive: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct NumberComparisonValue {
+    pub op: CompareOp,
+    pub value: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CustomValue {
+    pub tag: String,
+    pub value: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct DurationValue {
     pub years: i64,
@@ -252,6 +357,8 @@ pub struct DurationValue {
     pub hours: i64,
     pub minutes: i64,
     pub seconds: i64,
+    #[serde(default)]
+    pub milliseconds: i64,
     pub precision: Precision,
 }
 
@@ -265,6 +372,9 @@ pub enum Grain {
     Hour = 5,
     Minute = 6,
     Second = 7,
+    Decade = 8,
+    Century = 9,
+    Millisecond = 10,
 }
 
 impl From<moment::Grain> for Grain {
@@ -278,6 +388,9 @@ impl From<moment::Grain> for Grain {
             moment::Grain::Hour => Grain::Hour,
             moment::Grain::Minute => Grain::Minute,
             moment::Grain::Second => Grain::Second,
+            moment::Grain::Decade => Grain::Decade,
+            moment::Grain::Century => Grain::Century,
+            moment::Grain::Millisecond => Grain::Millisecond,
         }
     }
 }
@@ -286,6 +399,7 @@ impl From<moment::Grain> for Grain {
 pub enum Precision {
     Approximate,
     Exact,
+    Rough,
 }
 
 impl From<dimension::Precision> for Precision {
@@ -293,6 +407,26 @@ impl From<dimension::Precision> for Precision {
         match o {
             dimension::Precision::Approximate => Precision::Approximate,
             dimension::Precision::Exact => Precision::Exact,
+            dimension::Precision::Rough => Precision::Rough,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Copy, Clone)]
+pub enum CompareOp {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl From<dimension::CompareOp> for CompareOp {
+    fn from(o: dimension::CompareOp) -> CompareOp {
+        match o {
+            dimension::CompareOp::Gt => CompareOp::Gt,
+            dimension::CompareOp::Ge => CompareOp::Ge,
+            dimension::CompareOp::Lt => CompareOp::Lt,
+            dimension::CompareOp::Le => CompareOp::Le,
         }
     }
 }
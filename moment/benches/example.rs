@@ -6,7 +6,6 @@ extern crate chrono;
 use rustling_ontology_moment::*;
 use bencher::Bencher;
 use chrono::TimeZone;
-use chrono::offset::local::Local;
 
 fn build_context(moment: Moment<Local>) -> Context<Local> {
     let now = Interval::starting_at(moment, Grain::Second);
@@ -67,8 +67,8 @@ impl<V: Copy + Clone> BidirectionalWalker<V> {
 mod tests {
     use super::*;
     use crate::period::*;
-    use crate::{Interval, Moment};
-    use chrono::{Local, TimeZone};
+    use crate::{Interval, Local, Moment};
+    use chrono::TimeZone;
 
     #[test]
     fn test_interval_iterator() {
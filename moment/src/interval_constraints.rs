@@ -1,17 +1,32 @@
 use crate::bidirectional_walker::*;
 use crate::period::*;
 use crate::walker::*;
-use crate::{last_day_in_month, Interval, Moment, MomentError, MomentResult};
-use chrono::{Datelike, Local, TimeZone, Timelike, Weekday};
+use crate::{last_day_in_month, Interval, Local, Moment, MomentError, MomentResult};
+use chrono::{Datelike, NaiveDate, TimeZone, Timelike, Weekday};
+use std::collections::hash_map::DefaultHasher;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops;
 use std::rc::Rc;
 
+/// Years supported by chrono
+pub(crate) const CHRONO_YEAR_RANGE: (i32, i32) = (-262_000, 262_000);
+
+/// Years which can be safely converted to and from the local timezone, from the 1st of January
+/// of the first one to the 1st of January of the last one. They are the years of chrono on 32
+/// and 64 bits targets, `Local` computing the offsets out of the `time_t` of the operating system
+/// with chrono-tz.
+pub fn local_year_range() -> (i32, i32) {
+    CHRONO_YEAR_RANGE
+}
+
 #[derive(Clone, PartialEq)]
 pub struct Context<T: TimeZone> {
     pub reference: Interval<T>,
     pub min: Interval<T>,
     pub max: Interval<T>,
+    /// First day of the weeks walked by `Cycle(Grain::Week)`, monday by default
+    pub week_start: Weekday,
 }
 
 impl<T: TimeZone> Copy for Context<T> where <T as TimeZone>::Offset: Copy {}
@@ -20,8 +35,8 @@ impl<T: TimeZone> fmt::Debug for Context<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "Context {{ reference: {:?}, min: {:?}, max: {:?} }}",
-            self.reference, self.min, self.max
+            "Context {{ reference: {:?}, min: {:?}, max: {:?}, week_start: {:?} }}",
+            self.reference, self.min, self.max, self.week_start
         )
     }
 }
@@ -49,25 +64,23 @@ where
             reference,
             min,
             max,
+            week_start: Weekday::Mon,
         }
     }
 
-    /// Returns a context based on the given reference date. To avoid undefined behaviour for 
-    /// 32 bits operating system. The max and min date restricted to 1970 and 2038. To avoid this 
-    /// restriction, `new` function should be used. 
+    /// Returns the same context, with weeks starting on the given day
+    pub fn with_week_start(self, week_start: Weekday) -> Context<T> {
+        Context { week_start, ..self }
+    }
+
+    /// Returns a context based on the given reference date, with the min and max dates 70 years
+    /// around it. The min and max dates are also restricted to `local_year_range`. To avoid any
+    /// restriction, `new` function should be used.
     pub fn for_reference(now: Interval<T>) -> Context<T> {
-        // TODO: Should be refactor with the min, max date offer by chrono crate
         let now_end = now.end_moment();
-        let max_year = if 2038 > now_end.year() + 70 {
-            now_end.year() + 70
-        } else {
-            2038
-        };
-        let min_year = if 1970 < now.start.year() - 70 {
-            now.start.year() - 70
-        } else {
-            1970
-        };
+        let (first_year, last_year) = local_year_range();
+        let max_year = ::std::cmp::min(now_end.year() + 70, last_year);
+        let min_year = ::std::cmp::max(now.start.year() - 70, first_year);
         let min_interval = Interval::starting_at(
             Moment(now.timezone().ymd(min_year, 1, 1).and_hms(0, 0, 0)),
             Grain::Second,
@@ -92,6 +105,13 @@ where
     fn grain_right(&self) -> Grain;
     fn coarse_grain_step(&self) -> Grain;
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T>;
+
+    /// Feeds the hasher with the kind and the parameters of the constraint, so that the same
+    /// constraint built twice hashes the same. Returns `false` if it can't be hashed, e.g. as it
+    /// holds a closure, which is the default.
+    fn hash_structure(&self, _state: &mut DefaultHasher) -> bool {
+        false
+    }
 }
 
 #[derive(Clone)]
@@ -145,10 +165,25 @@ fn is_valid_second(s: u32) -> bool {
     s <= 59
 }
 
+fn is_valid_millisecond(ms: u32) -> bool {
+    ms <= 999
+}
+
 impl<T: TimeZone + 'static> RcConstraint<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
+    /// Hash of the structure of the constraint, `None` if one of its parts can't be hashed, see
+    /// `IntervalConstraint::hash_structure`
+    pub fn structure_hash(&self) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        if self.hash_structure(&mut hasher) {
+            Some(hasher.finish())
+        } else {
+            None
+        }
+    }
+
     pub fn shift_by(&self, period: Period) -> RcConstraint<T> {
         ShiftBy::new(self, period)
     }
@@ -192,6 +227,13 @@ where
         TakeLastOf::new(inner, self)
     }
 
+    /// The `n`th interval of this constraint within each interval of `container`, from 0, e.g.
+    /// the second tuesday of each march. The walk stops at the first interval of `container`
+    /// with less than `n + 1` of them.
+    pub fn nth_of(&self, n: i64, container: &RcConstraint<T>) -> RcConstraint<T> {
+        TakeNthOf::new(n, container, self)
+    }
+
     pub fn the_nth(&self, n: i64) -> NthConstraint<T> {
         NthConstraint(self.clone(), n)
     }
@@ -213,6 +255,11 @@ impl<T: TimeZone> IntervalConstraint<T> for Year
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Year", self.0).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Year
     }
@@ -249,6 +296,282 @@ where
     }
 }
 
+/// Decade starting with the given year, e.g. `Decade(1980)` for the 80s
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Decade(pub i32);
+
+impl Decade {
+    pub fn new<T: TimeZone>(y: i32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = Decade(y);
+        if y.rem_euclid(10) == 0 {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone> IntervalConstraint<T> for Decade
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Decade", self.0).hash(state);
+        true
+    }
+
+    fn grain(&self) -> Grain {
+        Grain::Decade
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Decade
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Decade
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Decade
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Decade
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        years_walker(self.0, 10, Grain::Decade, origin, context)
+    }
+}
+
+/// Century starting with the given year, e.g. `Century(1800)` for the 1800s
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Century(pub i32);
+
+impl Century {
+    pub fn new<T: TimeZone>(y: i32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = Century(y);
+        if y.rem_euclid(100) == 0 {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone> IntervalConstraint<T> for Century
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Century", self.0).hash(state);
+        true
+    }
+
+    fn grain(&self) -> Grain {
+        Grain::Century
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Century
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Century
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Century
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Century
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        years_walker(self.0, 100, Grain::Century, origin, context)
+    }
+}
+
+/// ISO 8601 week of any year, from monday to sunday, e.g. `IsoWeek(1)` for the week of the
+/// first thursday of the year. Week 53 only exists in long years.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IsoWeek(pub u32);
+
+impl IsoWeek {
+    pub fn new<T: TimeZone + 'static>(w: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = IsoWeek(w);
+        if 1 <= w && w <= 53 {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for IsoWeek
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("IsoWeek", self.0).hash(state);
+        true
+    }
+
+    fn grain(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
+        let week = self.0;
+        let start = origin.start;
+        let tz = origin.timezone();
+        let iso_year = origin.start.iso_week().year();
+        let anchor = Interval::starting_at(Moment(tz.ymd(iso_year, 1, 4).and_hms(0, 0, 0)), Grain::Year);
+        let in_iso_year = move |year: Interval<T>| iso_week_interval(&year.start.timezone(), year.start.year(), week);
+        let forward_walker = Walker::generator(anchor, |prev| prev + PeriodComp::years(1))
+            .filter_map(in_iso_year.clone())
+            .filter(move |interval| start < interval.end_moment());
+        let backward_walker = Walker::generator(anchor, |prev| prev - PeriodComp::years(1))
+            .filter_map(in_iso_year)
+            .filter(move |interval| interval.end_moment() <= start);
+        BidirectionalWalker::new()
+            .forward(forward_walker)
+            .backward(backward_walker)
+    }
+}
+
+/// ISO 8601 week of the given ISO year, e.g. `IsoYearWeek(2021, 1)` from monday the 4th of
+/// january 2021, or `IsoYearWeek(2020, 53)` from monday the 28th of december 2020
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IsoYearWeek(pub i32, pub u32);
+
+impl IsoYearWeek {
+    pub fn new<T: TimeZone + 'static>(y: i32, w: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = IsoYearWeek(y, w);
+        if NaiveDate::from_isoywd_opt(y, w, Weekday::Mon).is_some() {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for IsoYearWeek
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("IsoYearWeek", self.0, self.1).hash(state);
+        true
+    }
+
+    fn grain(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        if self.0 > context.max.start.year() || self.0 < context.min.start.year() {
+            return BidirectionalWalker::new();
+        }
+        match iso_week_interval(&origin.timezone(), self.0, self.1) {
+            Some(week) if origin.start < week.end_moment() => {
+                BidirectionalWalker::new().forward_values(vec![week])
+            }
+            Some(week) => BidirectionalWalker::new().backward_values(vec![week]),
+            None => BidirectionalWalker::new(),
+        }
+    }
+}
+
+/// Interval of the ISO week of the given ISO year, `None` if the year has no such week
+fn iso_week_interval<T: TimeZone>(tz: &T, iso_year: i32, week: u32) -> Option<Interval<T>>
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    let monday = NaiveDate::from_isoywd_opt(iso_year, week, Weekday::Mon)?;
+    let moment = tz.from_local_datetime(&monday.and_hms(0, 0, 0)).earliest()?;
+    Some(Interval::starting_at(Moment(moment), Grain::Week))
+}
+
+/// Walker over the `years` years starting with `first`, forward unless they are over at the
+/// origin
+fn years_walker<T: TimeZone>(
+    first: i32,
+    years: i32,
+    grain: Grain,
+    origin: &Interval<T>,
+    context: &Context<T>,
+) -> IntervalWalker<T>
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    let last = first + years - 1;
+    if first > context.max.start.year() || last < context.min.start.year() {
+        return BidirectionalWalker::new();
+    }
+    let moment = Moment(origin.timezone().ymd(first, 1, 1).and_hms(0, 0, 0));
+    let interval = Interval::starting_at(moment, grain);
+    if origin.start.year() <= last {
+        BidirectionalWalker::new().forward_values(vec![interval])
+    } else {
+        BidirectionalWalker::new().backward_values(vec![interval])
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct YearMonthDay {
     pub year: i32,
@@ -291,6 +614,11 @@ impl<T: TimeZone> IntervalConstraint<T> for YearMonthDay
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("YearMonthDay", self.year, self.month, self.day).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Day
     }
@@ -369,6 +697,11 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for MonthDay
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("MonthDay", self.0, self.1).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Day
     }
@@ -470,6 +803,11 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for Month
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Month", self.0).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Month
     }
@@ -543,6 +881,11 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for DayOfMonth
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("DayOfMonth", self.0).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Day
     }
@@ -614,6 +957,11 @@ impl<T: TimeZone> IntervalConstraint<T> for DayOfWeek
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("DayOfWeek", self.0).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Day
     }
@@ -699,6 +1047,11 @@ impl<T: TimeZone> IntervalConstraint<T> for HourMinute
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("HourMinute", self.hour, self.minute, self.is_12_clock).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Minute
     }
@@ -785,6 +1138,11 @@ impl<T: TimeZone> IntervalConstraint<T> for Hour
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Hour", self.hour, self.is_12_clock).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Hour
     }
@@ -852,6 +1210,11 @@ impl<T: TimeZone> IntervalConstraint<T> for Minute
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Minute", self.0).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Minute
     }
@@ -914,6 +1277,11 @@ impl<T: TimeZone> IntervalConstraint<T> for Second
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Second", self.0).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         Grain::Second
     }
@@ -946,6 +1314,67 @@ where
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Millisecond(pub u32);
+
+impl Millisecond {
+    pub fn new<T: TimeZone>(ms: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = Millisecond(ms);
+        if is_valid_millisecond(ms) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone> IntervalConstraint<T> for Millisecond
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Millisecond", self.0).hash(state);
+        true
+    }
+
+    fn grain(&self) -> Grain {
+        Grain::Millisecond
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Millisecond
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Millisecond
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Millisecond
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Second
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
+        let millisecond = (origin.start.nanosecond() / 1_000_000) as i64;
+        let offset = (self.0 as i64 - millisecond + 1000) % 1000;
+        let anchor = origin.start_round_to(Grain::Millisecond) + PeriodComp::milliseconds(offset);
+
+        BidirectionalWalker::new()
+            .forward_with(anchor, |prev| prev + PeriodComp::seconds(1))
+            .backward_with(anchor - PeriodComp::seconds(1), |prev| {
+                prev - PeriodComp::seconds(1)
+            })
+    }
+}
+
 pub struct NthConstraint<T: TimeZone>(RcConstraint<T>, i64);
 
 impl<T: TimeZone + 'static> NthConstraint<T>
@@ -977,6 +1406,11 @@ impl<T: TimeZone> IntervalConstraint<T> for Cycle
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Cycle", self.0).hash(state);
+        true
+    }
+
     fn grain(&self) -> Grain {
         self.0
     }
@@ -997,8 +1431,18 @@ where
         self.0
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        let anchor = origin.start_round_to(self.0);
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let anchor = if self.0 == Grain::Week && context.week_start != Weekday::Mon {
+            let offset = (origin.start.weekday().num_days_from_monday() + 7
+                - context.week_start.num_days_from_monday())
+                % 7;
+            Interval::starting_at(
+                origin.start.round_to(Grain::Day) - PeriodComp::days(offset as i64),
+                Grain::Week,
+            )
+        } else {
+            origin.start_round_to(self.0)
+        };
         let grain = self.0;
         BidirectionalWalker::new()
             .forward_with(anchor, move |prev| prev + PeriodComp::new(grain, 1))
@@ -1032,6 +1476,11 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for TakeTheNth<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("TakeTheNth", self.n, self.not_immediate).hash(state);
+        self.inner.hash_structure(state)
+    }
+
     fn grain(&self) -> Grain {
         self.inner.grain()
     }
@@ -1111,6 +1560,11 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for TakeN<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("TakeN", self.n, self.not_immediate).hash(state);
+        self.inner.hash_structure(state)
+    }
+
     fn grain(&self) -> Grain {
         self.inner.grain()
     }
@@ -1208,6 +1662,11 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for TakeTheNthAfter<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("TakeTheNthAfter", self.n, self.not_immediate).hash(state);
+        self.after.hash_structure(state) && self.cycle.hash_structure(state)
+    }
+
     fn grain(&self) -> Grain {
         self.after.grain()
     }
@@ -1279,6 +1738,11 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for TakeLastOf<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        "TakeLastOf".hash(state);
+        self.base.hash_structure(state) && self.cycle.hash_structure(state)
+    }
+
     fn grain(&self) -> Grain {
         self.base.grain()
     }
@@ -1315,6 +1779,108 @@ where
     }
 }
 
+#[derive(Clone)]
+pub struct TakeNthOf<T: TimeZone> {
+    n: i64,
+    /// Intervals in which the nth one is taken, e.g. the months
+    container: RcConstraint<T>,
+    /// Intervals which are counted, e.g. the tuesdays
+    counted: RcConstraint<T>,
+}
+
+impl<T: TimeZone + 'static> TakeNthOf<T>
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    pub fn new(n: i64, container: &RcConstraint<T>, counted: &RcConstraint<T>) -> RcConstraint<T> {
+        rc!(TakeNthOf {
+            n: n,
+            container: container.clone(),
+            counted: counted.clone(),
+        })
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for TakeNthOf<T>
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("TakeNthOf", self.n).hash(state);
+        self.container.hash_structure(state) && self.counted.hash_structure(state)
+    }
+
+    fn grain(&self) -> Grain {
+        self.counted.grain()
+    }
+
+    fn grain_min(&self) -> Grain {
+        self.counted.grain()
+    }
+
+    fn grain_left(&self) -> Grain {
+        self.counted.grain()
+    }
+
+    fn grain_right(&self) -> Grain {
+        self.counted.grain()
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        self.container.coarse_grain_step()
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let counted = self.counted.clone();
+        let n = self.n;
+        let context = *context;
+        let nth: Rc<dyn Fn(&Interval<T>) -> Option<Interval<T>>> = Rc::new(move |container| {
+            if n < 0 {
+                return None;
+            }
+            let (start, end) = (container.start, container.end_moment());
+            counted
+                .to_walker(container, &context)
+                .forward
+                .into_iter()
+                .skip_while(move |found| found.start < start)
+                .nth(n as usize)
+                .filter(|found| found.start < end)
+        });
+        let containers = self.container.to_walker(origin, &context);
+        let origin = *origin;
+
+        // The walk stops at the first container with less than n + 1 intervals instead of
+        // skipping it, e.g. at a month with four mondays for "the fifth monday of the month"
+        let (has_nth, take_nth) = (nth.clone(), nth.clone());
+        let forward = containers
+            .forward
+            .take(12)
+            .take_while(move |i| has_nth(i).is_some())
+            .filter_map(move |i| take_nth(&i));
+        let mut prepend_to_back: Vec<Interval<T>> = forward
+            .take_while(move |i| origin.start > i.end_moment())
+            .into_iter()
+            .collect();
+        prepend_to_back.reverse();
+        let still_fore = forward
+            .skip_while(move |i| origin.start > i.end_moment())
+            .take_while(move |i| i.start <= context.max.end_moment());
+
+        let (has_nth, take_nth) = (nth.clone(), nth);
+        let still_back = containers
+            .backward
+            .take(12)
+            .take_while(move |i| has_nth(i).is_some())
+            .filter_map(move |i| take_nth(&i))
+            .take_while(move |i| context.min.start <= i.end_moment());
+
+        IntervalWalker::new()
+            .forward(still_fore)
+            .backward(Walker::vec(prepend_to_back).chain(&still_back))
+    }
+}
+
 #[derive(Clone)]
 pub struct Intersection<T: TimeZone> {
     lhs: RcConstraint<T>,
@@ -1337,6 +1903,11 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for Intersection<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        "Intersection".hash(state);
+        self.lhs.hash_structure(state) && self.rhs.hash_structure(state)
+    }
+
     fn grain(&self) -> Grain {
         ::std::cmp::max(self.lhs.grain(), self.rhs.grain())
     }
@@ -1524,6 +2095,11 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for Span<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        ("Span", self.inclusive).hash(state);
+        self.from.hash_structure(state) && self.to.hash_structure(state)
+    }
+
     fn grain(&self) -> Grain {
         ::std::cmp::max(self.from.grain(), self.to.grain())
     }
@@ -1587,6 +2163,14 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for ShiftBy<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
+    fn hash_structure(&self, state: &mut DefaultHasher) -> bool {
+        "ShiftBy".hash(state);
+        for comp in self.period.comps() {
+            (comp.grain, comp.quantity).hash(state);
+        }
+        self.base.hash_structure(state)
+    }
+
     fn grain(&self) -> Grain {
         self.base.grain()
     }
@@ -1610,7 +2194,7 @@ where
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         if let Some(period_grain) = self.period.finer_grain() {
             let period = self.period.clone();
-            let is_forward = period.coarse_num_secs() >= 0;
+            let is_forward = period.coarse_num_millis() >= 0;
             let grain_after_shift = period_grain.after_shift();
             let translate = Translate {
                 generator: self.base.clone(),
@@ -1738,6 +2322,52 @@ mod tests {
         assert_eq!(None, walker.forward.clone().next());
     }
 
+    #[test]
+    fn test_decade_and_century() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let walker = Decade(1980).to_walker(&context.reference, &context);
+        assert_eq!(None, walker.forward.clone().next());
+        let decade = walker.backward.clone().next().unwrap();
+        assert_eq!(Moment(Paris.ymd(1980, 1, 1).and_hms(0, 0, 0)), decade.start);
+        assert_eq!(Moment(Paris.ymd(1990, 1, 1).and_hms(0, 0, 0)), decade.end_moment());
+        assert_eq!(Grain::Decade, decade.grain);
+
+        let walker = Century(2000).to_walker(&context.reference, &context);
+        let century = walker.forward.clone().next().unwrap();
+        assert_eq!(Moment(Paris.ymd(2100, 1, 1).and_hms(0, 0, 0)), century.end_moment());
+        assert_eq!(None, walker.backward.clone().next());
+
+        assert!(Decade::new::<Local>(1985).is_err());
+        assert!(Century::new::<Local>(1850).is_err());
+    }
+
+    #[test]
+    fn test_iso_week() {
+        let context = build_context(Moment(Paris.ymd(2021, 01, 02).and_hms(9, 10, 11)));
+        // the first week of 2021 starts on the 4th of january, 2020 has 53 weeks
+        let week = IsoYearWeek(2021, 1).to_walker(&context.reference, &context).forward.next().unwrap();
+        assert_eq!(Moment(Paris.ymd(2021, 1, 4).and_hms(0, 0, 0)), week.start);
+        assert_eq!(Moment(Paris.ymd(2021, 1, 11).and_hms(0, 0, 0)), week.end_moment());
+        assert_eq!(Grain::Week, week.grain);
+        let week = IsoYearWeek(2020, 53).to_walker(&context.reference, &context).forward.next().unwrap();
+        assert_eq!(Moment(Paris.ymd(2020, 12, 28).and_hms(0, 0, 0)), week.start);
+        assert!(IsoYearWeek::new::<Local>(2021, 53).is_err());
+
+        let walker = IsoWeek(53).to_walker(&context.reference, &context);
+        // the current week, then the next long year
+        let mut forward = walker.forward.clone();
+        assert_eq!(Moment(Paris.ymd(2020, 12, 28).and_hms(0, 0, 0)), forward.next().unwrap().start);
+        assert_eq!(Moment(Paris.ymd(2026, 12, 28).and_hms(0, 0, 0)), forward.next().unwrap().start);
+        assert_eq!(
+            Moment(Paris.ymd(2015, 12, 28).and_hms(0, 0, 0)),
+            walker.backward.clone().next().unwrap().start
+        );
+        let walker = IsoWeek(1).to_walker(&context.reference, &context);
+        assert_eq!(Moment(Paris.ymd(2021, 1, 4).and_hms(0, 0, 0)), walker.forward.clone().next().unwrap().start);
+        assert_eq!(Moment(Paris.ymd(2019, 12, 30).and_hms(0, 0, 0)), walker.backward.clone().next().unwrap().start);
+        assert!(IsoWeek::new::<Local>(54).is_err());
+    }
+
     #[test]
     fn test_year_month_day() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
@@ -1836,6 +2466,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cycle_week_start() {
+        // Sunday
+        let context = build_context(Moment(Paris.ymd(2017, 04, 23).and_hms(9, 10, 11)));
+        let week_start = |context: &Context<Paris>| {
+            Cycle(Grain::Week)
+                .to_walker(&context.reference, context)
+                .forward
+                .next()
+                .map(|interval| interval.start)
+        };
+        assert_eq!(Some(Moment(Paris.ymd(2017, 04, 17).and_hms(0, 0, 0))), week_start(&context));
+        let sunday_start = context.with_week_start(Weekday::Sun);
+        assert_eq!(Some(Moment(Paris.ymd(2017, 04, 23).and_hms(0, 0, 0))), week_start(&sunday_start));
+        let saturday_start = context.with_week_start(Weekday::Sat);
+        assert_eq!(Some(Moment(Paris.ymd(2017, 04, 22).and_hms(0, 0, 0))), week_start(&saturday_start));
+    }
+
+    #[test]
+    fn test_cycle_quarter() {
+        let context = build_context(Moment(Paris.ymd(2017, 05, 25).and_hms(9, 10, 11)));
+        let cycle = Cycle(Grain::Quarter);
+        let walker = cycle.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 01, 1).and_hms(0, 0, 0)),
+                Grain::Quarter
+            )),
+            walker.backward.clone().next()
+        );
+        let mut forward = walker.forward.clone();
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 04, 1).and_hms(0, 0, 0)),
+                Grain::Quarter
+            )),
+            forward.next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 07, 1).and_hms(0, 0, 0)),
+                Grain::Quarter
+            )),
+            forward.next()
+        );
+    }
+
     #[test]
     fn test_take_the_nth_forward_positive() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
@@ -1997,6 +2674,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_structure_hash() {
+        let tuesday_of_march = || -> RcConstraint<Paris> {
+            rc!(DayOfWeek(Weekday::Tue)).nth_of(1, &rc!(Month(3)))
+        };
+        assert!(tuesday_of_march().structure_hash().is_some());
+        assert_eq!(tuesday_of_march().structure_hash(), tuesday_of_march().structure_hash());
+        assert_ne!(
+            tuesday_of_march().structure_hash(),
+            rc!(DayOfWeek(Weekday::Tue)).nth_of(2, &rc!(Month(3))).structure_hash()
+        );
+        let in_two_days = Cycle::rc::<Paris>(Grain::Day).shift_by(PeriodComp::days(2).into());
+        assert_ne!(
+            in_two_days.structure_hash(),
+            Cycle::rc::<Paris>(Grain::Day).shift_by(PeriodComp::days(3).into()).structure_hash()
+        );
+        let translated = Translate::new(&tuesday_of_march(), Rc::new(|i: &Interval<Paris>, _: &Context<Paris>| Some(*i)));
+        assert_eq!(None, translated.structure_hash());
+    }
+
+    #[test]
+    fn test_take_the_nth_day_of_week_of_month() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let second_tuesday = rc!(DayOfWeek(Weekday::Tue)).nth_of(1, &rc!(Month(3)));
+        let walker = second_tuesday.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 03, 13).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 03, 14).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.backward.clone().next()
+        );
+
+        // May 2017 starts on a monday, which is the first one
+        let fifth_monday = rc!(DayOfWeek(Weekday::Mon)).nth_of(4, &rc!(Month(5)));
+        let walker = fifth_monday.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 05, 29).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+        // June 2017 only has four mondays
+        let june_2017 = rc!(Month(6)).intersect(&Year::new(2017));
+        let fifth_monday_of_june = rc!(DayOfWeek(Weekday::Mon)).nth_of(4, &june_2017);
+        let walker = fifth_monday_of_june.to_walker(&context.reference, &context);
+        assert_eq!(None, walker.forward.clone().next());
+        // April 2017 only has four mondays, the five mondays of may are not walked
+        let fifth_monday_of_month =
+            rc!(DayOfWeek(Weekday::Mon)).nth_of(4, &Cycle::rc(Grain::Month));
+        let walker = fifth_monday_of_month.to_walker(&context.reference, &context);
+        assert_eq!(None, walker.forward.clone().next());
+    }
+
     #[test]
     fn test_take_the_last_day_of_month() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
@@ -2720,6 +3459,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_millisecond() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms_milli(9, 10, 11, 750)));
+        let millisecond = Millisecond(500);
+        let walker = millisecond.to_walker(&context.reference, &context);
+
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 04, 25).and_hms_milli(9, 10, 12, 500)),
+                Grain::Millisecond
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 04, 25).and_hms_milli(9, 10, 11, 500)),
+                Grain::Millisecond
+            )),
+            walker.backward.clone().next()
+        );
+        assert!(Millisecond::new::<Local>(1000).is_err());
+    }
+
     #[test]
     fn test_intersect_dom_month() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
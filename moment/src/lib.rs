@@ -1,24 +1,33 @@
 extern crate chrono;
+extern crate chrono_tz;
 #[macro_use]
 extern crate derive_new;
 #[macro_use]
 extern crate enum_primitive;
 #[macro_use]
 extern crate failure;
+#[cfg(feature = "serde")]
+extern crate serde_crate;
 extern crate vec_map;
 
 pub mod bidirectional_walker;
 pub mod interval_constraints;
+mod local;
 mod period;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod walker;
 
 use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops;
 
-use chrono::{DateTime, Datelike, Duration, Timelike};
-pub use chrono::{Local, TimeZone, Weekday};
+use chrono::{Datelike, Duration, NaiveDateTime, Timelike};
+pub use chrono::{DateTime, FixedOffset, Offset, TimeZone, Utc, Weekday};
+pub use chrono_tz::Tz;
 pub use interval_constraints::*;
+pub use local::Local;
 pub use period::*;
 
 pub type MomentResult<T> = Result<T, MomentError>;
@@ -42,6 +51,13 @@ impl<T1: TimeZone, T2: TimeZone> PartialEq<Moment<T2>> for Moment<T1> {
 
 impl<T: TimeZone> Eq for Moment<T> {}
 
+/// Hashes the instant, whatever the timezone, as for the equality
+impl<T: TimeZone> Hash for Moment<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.naive_utc().hash(state)
+    }
+}
+
 impl<T: TimeZone> PartialOrd for Moment<T> {
     fn partial_cmp(&self, other: &Moment<T>) -> Option<Ordering> {
         self.0.partial_cmp(&other.0)
@@ -103,9 +119,46 @@ impl Moment<Local> {
         self.0.day()
     }
 
+    pub fn hour(&self) -> u32 {
+        self.0.hour()
+    }
+
     pub fn ymd(y: i32, m: u32, d: u32) -> Moment<Local> {
         Moment(Local.ymd(y, m, d).and_hms(0, 0, 0))
     }
+
+    /// Returns the local moment for the given timestamp, `None` if it is outside of
+    /// `local_year_range`. The range is checked before using the local timezone.
+    pub fn from_timestamp(secs: i64) -> Option<Moment<Local>> {
+        let naive = NaiveDateTime::from_timestamp_opt(secs, 0)?;
+        let (first_year, last_year) = local_year_range();
+        if naive.year() < first_year || naive.year() >= last_year {
+            return None;
+        }
+        Some(Moment(Local.from_utc_datetime(&naive)))
+    }
+
+    /// Returns the local moment showing the same wall clock time as this moment shows in `tz`.
+    /// Constraints only walk over local moments, this is used to resolve them in another zone.
+    pub fn to_wall_clock_in<Z: TimeZone>(&self, tz: &Z) -> Moment<Local> {
+        let naive = self.0.with_timezone(tz).naive_local();
+        Moment(
+            Local
+                .from_local_datetime(&naive)
+                .earliest()
+                .unwrap_or_else(|| Local.from_utc_datetime(&naive)),
+        )
+    }
+
+    /// Inverse of `to_wall_clock_in`: reads the wall clock time of this moment as a time in `tz`.
+    pub fn from_wall_clock_in<Z: TimeZone>(&self, tz: &Z) -> Moment<Local> {
+        let naive = self.0.naive_local();
+        let in_tz = tz
+            .from_local_datetime(&naive)
+            .earliest()
+            .unwrap_or_else(|| tz.from_utc_datetime(&naive));
+        Moment(in_tz.with_timezone(&Local))
+    }
 }
 
 impl<T: TimeZone> Moment<T>
@@ -130,15 +183,28 @@ where
         };
         let target_month_days = last_day_in_month(year, month0 + 1, self.timezone());
         let day = ::std::cmp::min(target_month_days, self.day());
-        Moment(self.timezone().ymd(year, month0 + 1, day).and_hms(
+        Moment(self.timezone().ymd(year, month0 + 1, day).and_hms_nano(
             self.hour(),
             self.minute(),
             self.second(),
+            self.nanosecond(),
         ))
     }
 
-    fn round_to(self, g: Grain) -> Moment<T> {
+    /// Start of the period of the given grain containing this moment, e.g. the monday morning
+    /// for `Week`
+    pub fn round_to(self, g: Grain) -> Moment<T> {
         match g {
+            Grain::Century => Moment(
+                self.timezone()
+                    .ymd(self.year() - self.year().rem_euclid(100), 1, 1)
+                    .and_hms(0, 0, 0),
+            ),
+            Grain::Decade => Moment(
+                self.timezone()
+                    .ymd(self.year() - self.year().rem_euclid(10), 1, 1)
+                    .and_hms(0, 0, 0),
+            ),
             Grain::Year => Moment(self.timezone().ymd(self.year(), 1, 1).and_hms(0, 0, 0)),
             Grain::Month => Moment(
                 self.timezone()
@@ -148,7 +214,13 @@ where
             Grain::Day => Moment(self.date().and_hms(0, 0, 0)),
             Grain::Hour => Moment(self.date().and_hms(self.hour(), 0, 0)),
             Grain::Minute => Moment(self.date().and_hms(self.hour(), self.minute(), 0)),
-            Grain::Second => self,
+            Grain::Second => Moment(self.date().and_hms(self.hour(), self.minute(), self.second())),
+            Grain::Millisecond => Moment(self.date().and_hms_milli(
+                self.hour(),
+                self.minute(),
+                self.second(),
+                self.nanosecond() / 1_000_000,
+            )),
             Grain::Week => {
                 // shift to monday morning
                 let day_offset = self.weekday().num_days_from_monday(); // monday is 0 here
@@ -164,7 +236,7 @@ where
         Moment(
             self.timezone()
                 .ymd(self.year(), self.month(), self.day())
-                .and_hms(self.hour(), self.minute(), self.second()),
+                .and_hms_nano(self.hour(), self.minute(), self.second(), self.nanosecond()),
         )
     }
 }
@@ -185,14 +257,10 @@ where
 {
     type Output = Moment<T>;
     fn add(self, p: &'a Period) -> Moment<T> {
-        use enum_primitive::FromPrimitive;
+        // From the coarsest grain, the grains added after `Second` being numbered after it
         let mut result = self;
-        for (g, q) in p.0.iter() {
-            result = result
-                + PeriodComp {
-                    grain: Grain::from_usize(g).unwrap(), // checked
-                    quantity: *q,
-                };
+        for comp in p.comps() {
+            result = result + comp;
         }
         result
     }
@@ -215,6 +283,8 @@ where
     type Output = Moment<T>;
     fn add(self, p: &'a PeriodComp) -> Moment<T> {
         match p.grain {
+            Grain::Century => self.add_months(1200 * p.quantity as i32),
+            Grain::Decade => self.add_months(120 * p.quantity as i32),
             Grain::Year => self.add_months(12 * p.quantity as i32),
             Grain::Quarter => self.add_months(3 * p.quantity as i32),
             Grain::Month => self.add_months(p.quantity as i32),
@@ -225,6 +295,7 @@ where
             Grain::Hour => Moment(self.0 + Duration::hours(p.quantity)),
             Grain::Minute => Moment(self.0 + Duration::minutes(p.quantity)),
             Grain::Second => Moment(self.0 + Duration::seconds(p.quantity)),
+            Grain::Millisecond => Moment(self.0 + Duration::milliseconds(p.quantity)),
         }
     }
 }
@@ -266,6 +337,24 @@ impl Interval<Local> {
     pub fn ymd(y: i32, m: u32, d: u32) -> Interval<Local> {
         Interval::starting_at(Moment(Local.ymd(y, m, d).and_hms(0, 0, 0)), Grain::Day)
     }
+
+    /// See `Moment::to_wall_clock_in`
+    pub fn to_wall_clock_in<Z: TimeZone>(&self, tz: &Z) -> Interval<Local> {
+        Interval {
+            start: self.start.to_wall_clock_in(tz),
+            end: self.end.map(|end| end.to_wall_clock_in(tz)),
+            grain: self.grain,
+        }
+    }
+
+    /// See `Moment::from_wall_clock_in`
+    pub fn from_wall_clock_in<Z: TimeZone>(&self, tz: &Z) -> Interval<Local> {
+        Interval {
+            start: self.start.from_wall_clock_in(tz),
+            end: self.end.map(|end| end.from_wall_clock_in(tz)),
+            grain: self.grain,
+        }
+    }
 }
 
 impl<T: TimeZone> fmt::Debug for Interval<T> {
@@ -430,14 +519,10 @@ where
 {
     type Output = Interval<T>;
     fn add(self, p: &'a Period) -> Interval<T> {
-        use enum_primitive::FromPrimitive;
+        // From the coarsest grain, the grains added after `Second` being numbered after it
         let mut result = self;
-        for (g, q) in p.0.iter() {
-            result = result
-                + PeriodComp {
-                    grain: Grain::from_usize(g).unwrap(), // checked
-                    quantity: *q,
-                };
+        for comp in p.comps() {
+            result = result + comp;
         }
         result
     }
@@ -667,10 +752,31 @@ mod tests {
             Moment(Paris.ymd(2017, 04, 24).and_hms(0, 0, 0)),
             now.round_to(Grain::Week)
         );
+        let precise = Moment(Paris.ymd(2017, 04, 25).and_hms_micro(9, 10, 11, 500_250));
+        assert_eq!(
+            Moment(Paris.ymd(2017, 04, 25).and_hms_milli(9, 10, 11, 500)),
+            precise.round_to(Grain::Millisecond)
+        );
+        assert_eq!(
+            Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)),
+            precise.round_to(Grain::Second)
+        );
+        assert_eq!(
+            Moment(Paris.ymd(2017, 04, 25).and_hms_milli(9, 10, 12, 250)),
+            precise.round_to(Grain::Millisecond) + PeriodComp::milliseconds(750)
+        );
         assert_eq!(
             Moment(Paris.ymd(2017, 04, 01).and_hms(0, 0, 0)),
             now.round_to(Grain::Quarter)
         );
+        assert_eq!(
+            Moment(Paris.ymd(2010, 01, 01).and_hms(0, 0, 0)),
+            now.round_to(Grain::Decade)
+        );
+        assert_eq!(
+            Moment(Paris.ymd(2000, 01, 01).and_hms(0, 0, 0)),
+            now.round_to(Grain::Century)
+        );
     }
 
     #[test]
@@ -761,4 +867,29 @@ mod tests {
         };
         assert_eq!(5 * 86400, interval.seconds());
     }
+
+    #[test]
+    fn wall_clock_in_other_timezone() {
+        let tz = FixedOffset::west(5 * 3600);
+        let moment = Moment(Local.ymd(2017, 04, 25).and_hms(9, 10, 11));
+        let wall_clock = moment.to_wall_clock_in(&tz);
+        assert_eq!(
+            moment.0.with_timezone(&tz).naive_local(),
+            wall_clock.0.naive_local()
+        );
+        assert_eq!(moment, wall_clock.from_wall_clock_in(&tz));
+    }
+
+    #[test]
+    fn interval_wall_clock_in_other_timezone() {
+        let tz = FixedOffset::east(9 * 3600);
+        let interval = Interval {
+            start: Moment(Local.ymd(2017, 04, 25).and_hms(9, 0, 0)),
+            grain: Grain::Hour,
+            end: Some(Moment(Local.ymd(2017, 04, 25).and_hms(11, 0, 0))),
+        };
+        let wall_clock = interval.to_wall_clock_in(&tz);
+        assert_eq!(Grain::Hour, wall_clock.grain);
+        assert_eq!(interval, wall_clock.from_wall_clock_in(&tz));
+    }
 }
@@ -0,0 +1,135 @@
+use crate::interval_constraints::CHRONO_YEAR_RANGE;
+use chrono::{Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+use std::{env, fs};
+
+/// Local timezone of the system. `chrono::Local` asks the operating system for the offsets, from
+/// a `time_t` which only holds the years 1902 to 2038 on 32 bits targets. Out of the years the
+/// `time_t` holds, the offsets are computed by chrono-tz from the timezone named by `TZ`,
+/// `/etc/localtime` or `/etc/timezone`, or when none is known from the same date in a year
+/// from 2000 to 2027 with the same calendar.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Local;
+
+struct System {
+    timezone: Option<Tz>,
+    time_t_years: (i32, i32),
+}
+
+thread_local! {
+    static SYSTEM: System = System {
+        timezone: system_timezone(),
+        time_t_years: time_t_year_range(),
+    };
+}
+
+/// Years from the 1st of January of the first one to the 1st of January of the last one which
+/// `chrono::Local` reads back unchanged
+fn time_t_year_range() -> (i32, i32) {
+    let new_year = |year: i32| NaiveDate::from_ymd(year, 1, 1).and_hms(0, 0, 0);
+    let is_local =
+        |naive: NaiveDateTime| chrono::Local.from_utc_datetime(&naive).naive_utc() == naive;
+    if is_local(new_year(CHRONO_YEAR_RANGE.0)) && is_local(new_year(CHRONO_YEAR_RANGE.1)) {
+        return CHRONO_YEAR_RANGE;
+    }
+    let year_of = |secs: i32| NaiveDateTime::from_timestamp(i64::from(secs), 0).year();
+    (year_of(::std::i32::MIN) + 1, year_of(::std::i32::MAX))
+}
+
+/// Timezone used by the operating system, read from a name like "Europe/Paris" or a path to it
+/// in the zoneinfo directory
+fn system_timezone() -> Option<Tz> {
+    let name = match env::var("TZ") {
+        Ok(name) => name,
+        Err(_) => fs::read_link("/etc/localtime")
+            .ok()
+            .map(|path| path.to_string_lossy().into_owned())
+            .or_else(|| fs::read_to_string("/etc/timezone").ok())?,
+    };
+    let name = name.trim().trim_start_matches(':');
+    let name = name
+        .find("zoneinfo/")
+        .map(|index| &name[index + "zoneinfo/".len()..])
+        .unwrap_or(name);
+    name.parse().ok()
+}
+
+impl Local {
+    pub fn now() -> chrono::DateTime<Local> {
+        chrono::Local::now().with_timezone(&Local)
+    }
+
+    /// Gives the offset from the operating system if `naive` is in one of the years of its
+    /// `time_t`, from chrono-tz otherwise
+    fn offset_at<R, F, G>(&self, naive: &NaiveDateTime, from_time_t: F, from_tz: G) -> R
+    where
+        F: Fn(&NaiveDateTime) -> R,
+        G: Fn(&Tz, &NaiveDateTime) -> R,
+    {
+        SYSTEM.with(|system| {
+            let (first_year, last_year) = system.time_t_years;
+            if naive.year() >= first_year && naive.year() < last_year {
+                return from_time_t(naive);
+            }
+            match system.timezone {
+                Some(ref timezone) => from_tz(timezone, naive),
+                // The calendar repeats every 28 years from 1901 to 2099
+                None => from_time_t(
+                    &naive
+                        .with_year(2000 + (naive.year() - 2000).rem_euclid(28))
+                        .unwrap_or(*naive),
+                ),
+            }
+        })
+    }
+}
+
+impl TimeZone for Local {
+    type Offset = FixedOffset;
+
+    fn from_offset(_offset: &FixedOffset) -> Local {
+        Local
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+        self.offset_from_local_datetime(&local.and_hms(0, 0, 0))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        self.offset_at(
+            local,
+            |naive| chrono::Local.offset_from_local_datetime(naive),
+            |timezone, naive| {
+                timezone
+                    .offset_from_local_datetime(naive)
+                    .map(|offset| offset.fix())
+            },
+        )
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        self.offset_at(
+            utc,
+            |naive| chrono::Local.offset_from_utc_datetime(naive),
+            |timezone, naive| timezone.offset_from_utc_datetime(naive).fix(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_out_of_time_t() {
+        for &year in [1850, 1900, 2100, 2500].iter() {
+            let datetime = Local.ymd(year, 6, 15).and_hms(12, 0, 0);
+            assert_eq!(year, datetime.year());
+            assert_eq!(datetime, Local.from_utc_datetime(&datetime.naive_utc()));
+        }
+    }
+}
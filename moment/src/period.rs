@@ -1,8 +1,10 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops;
 use vec_map::VecMap;
 
 enum_from_primitive! {
-    #[derive(Debug,PartialEq,Copy,Clone,Eq,Ord,PartialOrd, Hash)]
+    #[derive(Debug,PartialEq,Copy,Clone,Eq, Hash)]
     pub enum Grain {
         Year = 0,
         Quarter = 1,
@@ -12,12 +14,32 @@ enum_from_primitive! {
         Hour = 5,
         Minute = 6,
         Second = 7,
+        Decade = 8,
+        Century = 9,
+        Millisecond = 10,
+    }
+}
+
+/// Grains are ordered from the coarsest to the finest. The grains added after `Second` are
+/// numbered after it, keeping the indices of the periods, so the order doesn't follow the
+/// numbers.
+impl Ord for Grain {
+    fn cmp(&self, other: &Grain) -> Ordering {
+        other.coarse_num_millis().cmp(&self.coarse_num_millis())
+    }
+}
+
+impl PartialOrd for Grain {
+    fn partial_cmp(&self, other: &Grain) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl Grain {
     pub fn next(&self) -> Grain {
         match self {
+            &Grain::Century => Grain::Year,
+            &Grain::Decade => Grain::Year,
             &Grain::Year => Grain::Month,
             &Grain::Quarter => Grain::Month,
             &Grain::Month => Grain::Day,
@@ -26,11 +48,14 @@ impl Grain {
             &Grain::Hour => Grain::Minute,
             &Grain::Minute => Grain::Second,
             &Grain::Second => Grain::Second,
+            &Grain::Millisecond => Grain::Millisecond,
         }
     }
 
     pub fn after_shift(&self) -> Grain {
         match self {
+            &Grain::Century => Grain::Year,
+            &Grain::Decade => Grain::Year,
             &Grain::Year => Grain::Month,
             &Grain::Quarter => Grain::Month,
             &Grain::Month => Grain::Day,
@@ -39,11 +64,14 @@ impl Grain {
             &Grain::Hour => Grain::Minute,
             &Grain::Minute => Grain::Second,
             &Grain::Second => Grain::Second,
+            &Grain::Millisecond => Grain::Millisecond,
         }
     }
 
     pub fn half_period(&self) -> Option<PeriodComp> {
         match self {
+            &Grain::Century => Some(PeriodComp::years(50)),
+            &Grain::Decade => Some(PeriodComp::years(5)),
             &Grain::Year => Some(PeriodComp::months(6)),
             &Grain::Quarter => Some(PeriodComp::days(45)),
             &Grain::Month => Some(PeriodComp::days(15)),
@@ -52,11 +80,14 @@ impl Grain {
             &Grain::Hour => Some(PeriodComp::minutes(30)),
             &Grain::Minute => Some(PeriodComp::seconds(30)),
             &Grain::Second => None,
+            &Grain::Millisecond => None,
         }
     }
 
     pub fn quarter_period(&self) -> Option<PeriodComp> {
         match self {
+            &Grain::Century => Some(PeriodComp::years(25)),
+            &Grain::Decade => Some(PeriodComp::months(30)),
             &Grain::Year => Some(PeriodComp::months(3)),
             &Grain::Quarter => Some(PeriodComp::days(22)),
             &Grain::Month => Some(PeriodComp::days(7)),
@@ -65,31 +96,47 @@ impl Grain {
             &Grain::Hour => Some(PeriodComp::minutes(15)),
             &Grain::Minute => Some(PeriodComp::seconds(15)),
             &Grain::Second => None,
+            &Grain::Millisecond => None,
         }
     }
 
+    /// Approximate length of the grain in seconds, 0 for `Millisecond`
     pub fn coarse_num_secs(&self) -> i64 {
+        self.coarse_num_millis() / 1000
+    }
+
+    pub fn coarse_num_millis(&self) -> i64 {
         match self {
-            &Grain::Year => 12 * 30 * 24 * 3600,
-            &Grain::Quarter => 3 * 30 * 24 * 3600,
-            &Grain::Month => 30 * 24 * 3600,
-            &Grain::Week => 7 * 24 * 3600,
-            &Grain::Day => 24 * 3600,
-            &Grain::Hour => 3600,
-            &Grain::Minute => 60,
-            &Grain::Second => 1,
+            &Grain::Century => 100 * 12 * 30 * 24 * 3600 * 1000,
+            &Grain::Decade => 10 * 12 * 30 * 24 * 3600 * 1000,
+            &Grain::Year => 12 * 30 * 24 * 3600 * 1000,
+            &Grain::Quarter => 3 * 30 * 24 * 3600 * 1000,
+            &Grain::Month => 30 * 24 * 3600 * 1000,
+            &Grain::Week => 7 * 24 * 3600 * 1000,
+            &Grain::Day => 24 * 3600 * 1000,
+            &Grain::Hour => 3600 * 1000,
+            &Grain::Minute => 60 * 1000,
+            &Grain::Second => 1000,
+            &Grain::Millisecond => 1,
         }
     }
 
     pub fn is_greater_than_day(&self) -> bool {
         match self {
-            Grain::Week | Grain::Month | Grain::Year | Grain::Quarter => true,
+            Grain::Week
+            | Grain::Month
+            | Grain::Year
+            | Grain::Quarter
+            | Grain::Decade
+            | Grain::Century => true,
             _ => false,
         }
     }
 
     pub fn is_date_grain(&self) -> bool {
         match self {
+            &Grain::Century => true,
+            &Grain::Decade => true,
             &Grain::Year => true,
             &Grain::Quarter => true,
             &Grain::Month => true,
@@ -98,11 +145,14 @@ impl Grain {
             &Grain::Hour => false,
             &Grain::Minute => false,
             &Grain::Second => false,
+            &Grain::Millisecond => false,
         }
     }
 
     pub fn is_time_grain(&self) -> bool {
         match self {
+            &Grain::Century => false,
+            &Grain::Decade => false,
             &Grain::Year => false,
             &Grain::Quarter => false,
             &Grain::Month => false,
@@ -111,6 +161,7 @@ impl Grain {
             &Grain::Hour => true,
             &Grain::Minute => true,
             &Grain::Second => true,
+            &Grain::Millisecond => true,
         }
     }
 }
@@ -118,7 +169,7 @@ impl Grain {
 impl Grain {
     pub fn all() -> Vec<Grain> {
         use enum_primitive::FromPrimitive;
-        (0..8)
+        (0..11)
             .filter_map(|primitive| Grain::from_usize(primitive))
             .collect::<Vec<Grain>>()
     }
@@ -141,26 +192,29 @@ impl PartialEq for Period {
     }
 }
 
+/// Hashes the quantity of every grain, the missing ones being zero as for the equality
+impl Hash for Period {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for grain in Grain::all() {
+            self.0.get(grain as usize).cloned().unwrap_or(0).hash(state);
+        }
+    }
+}
+
 impl Period {
     pub fn finer_grain(&self) -> Option<Grain> {
-        use enum_primitive::FromPrimitive;
-        self.0
-            .iter()
-            .max_by_key(|&(g, _)| g)
-            .and_then(|(g, _)| Grain::from_usize(g))
+        self.comps().into_iter().map(|comp| comp.grain).max()
     }
 
     pub fn coarser_grain(&self) -> Option<Grain> {
-        use enum_primitive::FromPrimitive;
-        self.0
-            .iter()
-            .min_by_key(|&(g, _)| g)
-            .and_then(|(g, _)| Grain::from_usize(g))
+        self.comps().into_iter().map(|comp| comp.grain).min()
     }
 
+    /// Components of the period, from the coarsest grain to the finest
     pub fn comps(&self) -> Vec<PeriodComp> {
         use enum_primitive::FromPrimitive;
-        self.0
+        let mut comps: Vec<PeriodComp> = self
+            .0
             .iter()
             .filter_map(|(g, q)| {
                 if let Some(grain) = Grain::from_usize(g) {
@@ -169,11 +223,17 @@ impl Period {
                     None
                 }
             })
-            .collect()
+            .collect();
+        comps.sort_by_key(|comp| comp.grain);
+        comps
     }
 
     pub fn coarse_num_secs(&self) -> i64 {
-        self.comps().iter().map(|it| it.coarse_num_secs()).sum()
+        self.coarse_num_millis() / 1000
+    }
+
+    pub fn coarse_num_millis(&self) -> i64 {
+        self.comps().iter().map(|it| it.coarse_num_millis()).sum()
     }
 }
 
@@ -215,7 +275,7 @@ impl ops::Add<Period> for Period {
     type Output = Period;
     fn add(self, p: Period) -> Period {
         let mut result = Period::default();
-        for i in 0..8 {
+        for i in 0..11 {
             if !self.0.get(i).is_none() || !p.0.get(i).is_none() {
                 result
                     .0
@@ -230,7 +290,7 @@ impl<'a> ops::Add<&'a Period> for Period {
     type Output = Period;
     fn add(self, p: &'a Period) -> Period {
         let mut result = self;
-        for i in 0..8 {
+        for i in 0..11 {
             if !p.0.get(i).is_none() {
                 *result.0.entry(i).or_insert(0) += *p.0.get(i).unwrap_or(&0);
             }
@@ -243,7 +303,7 @@ impl<'a, 'b> ops::Add<&'a Period> for &'b Period {
     type Output = Period;
     fn add(self, p: &'a Period) -> Period {
         let mut result = Period::default();
-        for i in 0..8 {
+        for i in 0..11 {
             if !self.0.get(i).is_none() || !p.0.get(i).is_none() {
                 result
                     .0
@@ -289,9 +349,25 @@ impl PeriodComp {
     }
 
     pub fn coarse_num_secs(&self) -> i64 {
-        self.grain.coarse_num_secs() * self.quantity
+        self.coarse_num_millis() / 1000
+    }
+
+    pub fn coarse_num_millis(&self) -> i64 {
+        self.grain.coarse_num_millis() * self.quantity
     }
 
+    pub fn centuries(n: i64) -> PeriodComp {
+        PeriodComp {
+            grain: Grain::Century,
+            quantity: n,
+        }
+    }
+    pub fn decades(n: i64) -> PeriodComp {
+        PeriodComp {
+            grain: Grain::Decade,
+            quantity: n,
+        }
+    }
     pub fn years(n: i64) -> PeriodComp {
         PeriodComp {
             grain: Grain::Year,
@@ -340,6 +416,12 @@ impl PeriodComp {
             quantity: n,
         }
     }
+    pub fn milliseconds(n: i64) -> PeriodComp {
+        PeriodComp {
+            grain: Grain::Millisecond,
+            quantity: n,
+        }
+    }
 }
 
 impl ops::Neg for PeriodComp {
@@ -442,4 +524,17 @@ mod tests {
         a.0.insert(Grain::Hour as usize, 4);
         assert_eq!(a.finer_grain(), Some(Grain::Hour));
     }
+
+    #[test]
+    fn grain_order() {
+        // The indices of the periods of the first grains are kept
+        assert_eq!(0, Grain::Year as usize);
+        assert_eq!(7, Grain::Second as usize);
+        assert!(Grain::Century < Grain::Decade && Grain::Decade < Grain::Year);
+        assert!(Grain::Second < Grain::Millisecond);
+        let period = Period::from(PeriodComp::days(2)) + PeriodComp::decades(1);
+        assert_eq!(Some(Grain::Decade), period.coarser_grain());
+        assert_eq!(Some(Grain::Day), period.finer_grain());
+        assert_eq!(Grain::Decade, period.comps()[0].grain);
+    }
 }
@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::DateTime;
+use serde_crate::de::{self, Deserialize, Deserializer, Visitor};
+use serde_crate::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::{Grain, Local, Moment, Period, PeriodComp};
+
+impl Grain {
+    fn name(&self) -> &'static str {
+        match self {
+            &Grain::Century => "century",
+            &Grain::Decade => "decade",
+            &Grain::Year => "year",
+            &Grain::Quarter => "quarter",
+            &Grain::Month => "month",
+            &Grain::Week => "week",
+            &Grain::Day => "day",
+            &Grain::Hour => "hour",
+            &Grain::Minute => "minute",
+            &Grain::Second => "second",
+            &Grain::Millisecond => "millisecond",
+        }
+    }
+}
+
+impl Serialize for Grain {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+struct GrainVisitor;
+
+impl<'de> Visitor<'de> for GrainVisitor {
+    type Value = Grain;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a grain name like \"day\"")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Grain, E> {
+        Grain::all()
+            .into_iter()
+            .find(|grain| grain.name() == value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Grain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Grain, D::Error> {
+        deserializer.deserialize_str(GrainVisitor)
+    }
+}
+
+/// Moments are serialized as RFC 3339 strings, e.g. "2013-02-12T04:30:00+01:00"
+impl Serialize for Moment<Local> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_rfc3339())
+    }
+}
+
+impl<'de> Deserialize<'de> for Moment<Local> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Moment<Local>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&value)
+            .map(|datetime| Moment(datetime.with_timezone(&Local)))
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Periods are serialized as a map from grain to quantity, e.g. {"hour": 1, "minute": 30}
+impl Serialize for Period {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let comps = self.comps();
+        let mut map = serializer.serialize_map(Some(comps.len()))?;
+        for comp in comps {
+            map.serialize_entry(&comp.grain, &comp.quantity)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Period {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Period, D::Error> {
+        let comps = BTreeMap::<Grain, i64>::deserialize(deserializer)?;
+        Ok(comps
+            .into_iter()
+            .fold(Period::default(), |period, (grain, quantity)| {
+                period + PeriodComp::new(grain, quantity)
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_serialize_grain() {
+        assert_eq!("\"quarter\"", serde_json::to_string(&Grain::Quarter).unwrap());
+        assert_eq!(Grain::Quarter, serde_json::from_str("\"quarter\"").unwrap());
+        assert!(serde_json::from_str::<Grain>("\"fortnight\"").is_err());
+    }
+
+    #[test]
+    fn test_serialize_moment() {
+        let moment = Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0));
+        let json = serde_json::to_string(&moment).unwrap();
+        assert_eq!(moment, serde_json::from_str::<Moment<Local>>(&json).unwrap());
+    }
+
+    #[test]
+    fn test_serialize_period() {
+        let period = Period::from(PeriodComp::hours(1)) + PeriodComp::minutes(30);
+        let json = serde_json::to_string(&period).unwrap();
+        assert_eq!("{\"hour\":1,\"minute\":30}", json);
+        assert_eq!(period, serde_json::from_str::<Period>(&json).unwrap());
+    }
+}
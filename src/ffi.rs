@@ -0,0 +1,137 @@
+//! C bindings, enabled by the `ffi` feature.
+//!
+//! A resolver is created for a reference unix timestamp and freed with
+//! `rustling_ontology_resolver_free`. Sentences are parsed and resolved with
+//! `rustling_ontology_resolve`, which returns the outputs as a JSON array, to be freed with
+//! `rustling_ontology_string_free`. All strings are null-terminated UTF-8. A resolver must not be
+//! used by several threads at the same time. A panic while parsing or resolving doesn't unwind
+//! into the caller, the function returns null instead.
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::str::FromStr;
+
+use rustling_ontology_moment::Grain;
+
+use crate::{build_parser, Interval, Lang, Local, Moment, Output, OutputKind, Parser, ResolverContext, TimeZone};
+
+/// Opaque resolver handed to C, the parsers are built the first time a language is used
+pub struct RustlingResolver {
+    context: ResolverContext,
+    parsers: Vec<(Lang, Parser)>,
+}
+
+impl RustlingResolver {
+    fn parser(&mut self, lang: Lang) -> Option<&Parser> {
+        if !self.parsers.iter().any(|&(parser_lang, _)| parser_lang == lang) {
+            self.parsers.push((lang, build_parser(lang).ok()?));
+        }
+        self.parsers
+            .iter()
+            .find(|&&(parser_lang, _)| parser_lang == lang)
+            .map(|&(_, ref parser)| parser)
+    }
+
+    fn resolve(&mut self, lang: Lang, kind: Option<OutputKind>, sentence: &str) -> Option<String> {
+        let context = self.context.clone();
+        let parser = self.parser(lang)?;
+        let matches = match kind {
+            Some(kind) => parser.parse_with_kind_order(sentence, &context, &[kind]),
+            None => parser.parse(sentence, &context),
+        }
+        .ok()?;
+        let outputs: Vec<Output> = matches.into_iter().map(|m| m.value).collect();
+        serde_json::to_string(&outputs).ok()
+    }
+}
+
+unsafe fn to_str<'a>(string: *const c_char) -> Option<&'a str> {
+    if string.is_null() {
+        return None;
+    }
+    CStr::from_ptr(string).to_str().ok()
+}
+
+/// Creates a resolver for the given unix timestamp, in seconds. Returns null if the timestamp is
+/// out of range.
+#[no_mangle]
+pub extern "C" fn rustling_ontology_resolver_new(timestamp: i64) -> *mut RustlingResolver {
+    let new = || match Local.timestamp_opt(timestamp, 0).single() {
+        Some(reference) => Box::into_raw(Box::new(RustlingResolver {
+            context: ResolverContext::for_reference(Interval::starting_at(Moment(reference), Grain::Second)),
+            parsers: vec![],
+        })),
+        None => ptr::null_mut(),
+    };
+    panic::catch_unwind(new).unwrap_or(ptr::null_mut())
+}
+
+/// Frees a resolver created by `rustling_ontology_resolver_new`
+#[no_mangle]
+pub unsafe extern "C" fn rustling_ontology_resolver_free(resolver: *mut RustlingResolver) {
+    if !resolver.is_null() {
+        drop(Box::from_raw(resolver));
+    }
+}
+
+/// Parses and resolves a sentence in the given language, e.g. "en", keeping only the outputs of
+/// the given kind, e.g. "Datetime", or all of them if `kind` is null. Returns the outputs as a
+/// JSON array, or null if an argument is invalid or the parsing failed or panicked.
+#[no_mangle]
+pub unsafe extern "C" fn rustling_ontology_resolve(
+    resolver: *mut RustlingResolver,
+    lang: *const c_char,
+    kind: *const c_char,
+    sentence: *const c_char,
+) -> *mut c_char {
+    let resolve = || {
+        let resolver = resolver.as_mut()?;
+        let lang = Lang::from_str(to_str(lang)?).ok()?;
+        let kind = if kind.is_null() {
+            None
+        } else {
+            Some(OutputKind::from_str(to_str(kind)?).ok()?)
+        };
+        let json = resolver.resolve(lang, kind, to_str(sentence)?)?;
+        CString::new(json).ok()
+    };
+    // A panic leaves the resolver usable, a parser being only added once it is built
+    panic::catch_unwind(AssertUnwindSafe(resolve))
+        .ok()
+        .and_then(|json| json)
+        .map(CString::into_raw)
+        .unwrap_or(ptr::null_mut())
+}
+
+/// Frees a string returned by `rustling_ontology_resolve`
+#[no_mangle]
+pub unsafe extern "C" fn rustling_ontology_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        unsafe {
+            let resolver = rustling_ontology_resolver_new(1360639800);
+            assert!(!resolver.is_null());
+            let lang = CString::new("en").unwrap();
+            let kind = CString::new("Number").unwrap();
+            let sentence = CString::new("twenty-one").unwrap();
+            let json = rustling_ontology_resolve(resolver, lang.as_ptr(), kind.as_ptr(), sentence.as_ptr());
+            assert!(!json.is_null());
+            let outputs: Vec<Output> = serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+            assert_eq!(vec![Output::Integer(crate::output::IntegerOutput(21))], outputs);
+            rustling_ontology_string_free(json);
+            let unknown = CString::new("xx").unwrap();
+            assert!(rustling_ontology_resolve(resolver, unknown.as_ptr(), kind.as_ptr(), sentence.as_ptr()).is_null());
+            rustling_ontology_resolver_free(resolver);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Names of the grains and of the units in the languages of the ontology, e.g. to display
+//! "3 jours" for a duration of three days.
+//!
+//! English, French, Spanish and German are supported, the other languages get the English
+//! names. Currencies are given by their symbol, see `Currency::symbol`, which doesn't depend on
+//! the language.
+use crate::output::TemperatureUnit;
+use crate::{Grain, Lang};
+
+/// Name of a grain or a unit in the given language, e.g. "jour" for `Grain::Day` in French
+pub trait Label {
+    /// Name of one of them, e.g. "day"
+    fn label(&self, lang: Lang) -> &'static str {
+        self.labels(lang).0
+    }
+
+    /// Name of several of them, e.g. "days"
+    fn plural_label(&self, lang: Lang) -> &'static str {
+        self.labels(lang).1
+    }
+
+    /// Singular and plural names
+    fn labels(&self, lang: Lang) -> (&'static str, &'static str);
+}
+
+impl Label for Grain {
+    fn labels(&self, lang: Lang) -> (&'static str, &'static str) {
+        match lang {
+            Lang::FR => match self {
+                Grain::Century => ("siècle", "siècles"),
+                Grain::Decade => ("décennie", "décennies"),
+                Grain::Year => ("année", "années"),
+                Grain::Quarter => ("trimestre", "trimestres"),
+                Grain::Month => ("mois", "mois"),
+                Grain::Week => ("semaine", "semaines"),
+                Grain::Day => ("jour", "jours"),
+                Grain::Hour => ("heure", "heures"),
+                Grain::Minute => ("minute", "minutes"),
+                Grain::Second => ("seconde", "secondes"),
+                Grain::Millisecond => ("milliseconde", "millisecondes"),
+            },
+            Lang::ES => match self {
+                Grain::Century => ("siglo", "siglos"),
+                Grain::Decade => ("década", "décadas"),
+                Grain::Year => ("año", "años"),
+                Grain::Quarter => ("trimestre", "trimestres"),
+                Grain::Month => ("mes", "meses"),
+                Grain::Week => ("semana", "semanas"),
+                Grain::Day => ("día", "días"),
+                Grain::Hour => ("hora", "horas"),
+                Grain::Minute => ("minuto", "minutos"),
+                Grain::Second => ("segundo", "segundos"),
+                Grain::Millisecond => ("milisegundo", "milisegundos"),
+            },
+            Lang::DE => match self {
+                Grain::Century => ("Jahrhundert", "Jahrhunderte"),
+                Grain::Decade => ("Jahrzehnt", "Jahrzehnte"),
+                Grain::Year => ("Jahr", "Jahre"),
+                Grain::Quarter => ("Quartal", "Quartale"),
+                Grain::Month => ("Monat", "Monate"),
+                Grain::Week => ("Woche", "Wochen"),
+                Grain::Day => ("Tag", "Tage"),
+                Grain::Hour => ("Stunde", "Stunden"),
+                Grain::Minute => ("Minute", "Minuten"),
+                Grain::Second => ("Sekunde", "Sekunden"),
+                Grain::Millisecond => ("Millisekunde", "Millisekunden"),
+            },
+            _ => match self {
+                Grain::Century => ("century", "centuries"),
+                Grain::Decade => ("decade", "decades"),
+                Grain::Year => ("year", "years"),
+                Grain::Quarter => ("quarter", "quarters"),
+                Grain::Month => ("month", "months"),
+                Grain::Week => ("week", "weeks"),
+                Grain::Day => ("day", "days"),
+                Grain::Hour => ("hour", "hours"),
+                Grain::Minute => ("minute", "minutes"),
+                Grain::Second => ("second", "seconds"),
+                Grain::Millisecond => ("millisecond", "milliseconds"),
+            },
+        }
+    }
+}
+
+impl Label for TemperatureUnit {
+    fn labels(&self, lang: Lang) -> (&'static str, &'static str) {
+        match (lang, self) {
+            (Lang::FR, TemperatureUnit::Celsius) => ("degré Celsius", "degrés Celsius"),
+            (Lang::FR, TemperatureUnit::Fahrenheit) => ("degré Fahrenheit", "degrés Fahrenheit"),
+            (Lang::FR, TemperatureUnit::Kelvin) => ("kelvin", "kelvins"),
+            (Lang::ES, TemperatureUnit::Celsius) => ("grado Celsius", "grados Celsius"),
+            (Lang::ES, TemperatureUnit::Fahrenheit) => ("grado Fahrenheit", "grados Fahrenheit"),
+            (Lang::ES, TemperatureUnit::Kelvin) => ("kelvin", "kelvins"),
+            // German units are invariable after a number, e.g. "20 Grad Celsius"
+            (Lang::DE, TemperatureUnit::Celsius) => ("Grad Celsius", "Grad Celsius"),
+            (Lang::DE, TemperatureUnit::Fahrenheit) => ("Grad Fahrenheit", "Grad Fahrenheit"),
+            (Lang::DE, TemperatureUnit::Kelvin) => ("Kelvin", "Kelvin"),
+            (_, TemperatureUnit::Celsius) => ("degree Celsius", "degrees Celsius"),
+            (_, TemperatureUnit::Fahrenheit) => ("degree Fahrenheit", "degrees Fahrenheit"),
+            (_, TemperatureUnit::Kelvin) => ("kelvin", "kelvins"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grain_labels() {
+        assert_eq!("jour", Grain::Day.label(Lang::FR));
+        assert_eq!("jours", Grain::Day.plural_label(Lang::FR));
+        assert_eq!("mois", Grain::Month.plural_label(Lang::FR));
+        assert_eq!("day", Grain::Day.label(Lang::EN));
+        assert_eq!("meses", Grain::Month.plural_label(Lang::ES));
+        assert_eq!("Stunde", Grain::Hour.label(Lang::DE));
+        assert_eq!("centuries", Grain::Century.plural_label(Lang::EN));
+        assert_eq!("week", Grain::Week.label(Lang::JA));
+    }
+
+    #[test]
+    fn test_temperature_unit_labels() {
+        assert_eq!("degré Celsius", TemperatureUnit::Celsius.label(Lang::FR));
+        assert_eq!("degrees Fahrenheit", TemperatureUnit::Fahrenheit.plural_label(Lang::EN));
+        assert_eq!("Grad Celsius", TemperatureUnit::Celsius.plural_label(Lang::DE));
+        assert_eq!("kelvin", TemperatureUnit::Kelvin.label(Lang::ES));
+    }
+}
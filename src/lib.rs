@@ -25,20 +25,30 @@ extern crate rustling_ontology_moment;
 extern crate rustling_ontology_values;
 extern crate serde;
 
-pub use grammar::{dims, Lang};
+pub use grammar::{dims, supported_dimensions, supported_languages, Lang};
 pub use rustling::RustlingResult;
 pub use rustling::{AttemptInto, ParsedNode, ParserMatch, ParsingAnalysis, Range, Sym, Value};
 pub use rustling_ontology_moment::Grain;
-pub use rustling_ontology_moment::{Interval, Local, Moment, TimeZone};
+pub use rustling_ontology_moment::{Interval, Local, Moment, TimeZone, Tz};
 pub use rustling_ontology_values::dimension;
+pub use rustling_ontology_values::dimension::{DateOrder, Season};
+#[cfg(feature = "duckling")]
+pub use rustling_ontology_values::duckling;
+pub use rustling_ontology_values::holidays;
 pub use rustling_ontology_values::output;
 pub use rustling_ontology_values::output::{Output, OutputKind};
-pub use rustling_ontology_values::{IdentityContext, ParsingContext, ResolverContext};
+pub use rustling_ontology_values::{CustomResolver, IdentityContext, ParsingContext, ResolveError, ResolveOptions, ResolverContext, WeekdayPolicy, FuzzyReferencePolicy, TieBreak, PartialDatePolicy, OutOfRangePolicy, AmbiguousHourPolicy, TwoDigitYearPivot, SeasonConvention, Hemisphere};
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod labels;
 mod mapper;
 mod parser;
+mod spoken;
 mod tagger;
 
+pub use labels::Label;
+pub use spoken::ToSpoken;
 pub use tagger::CandidateTagger;
 
 // Rustling raw parser. Don't use directly
@@ -91,6 +101,98 @@ impl Parser {
         self.parse_with_kind_order(input, context, &all_output)
     }
 
+    /// Parses the input like `parse`, pairing each output with the range of characters of the
+    /// input it was resolved from, e.g. to highlight the entities. The range counts characters,
+    /// not bytes, so it can't slice an input which isn't ASCII: the byte ranges are given by
+    /// `ParserMatch::byte_range`.
+    ///
+    /// ```
+    /// use rustling_ontology::*;
+    ///
+    /// let ctx = ResolverContext::default();
+    /// let parser = build_parser(Lang::EN).unwrap();
+    /// let input = "café at 3pm on Friday";
+    /// let spans = parser.resolve_with_range(input, &ctx).unwrap();
+    /// let (ref range, ref output) = spans[0];
+    /// assert!(output.datetime_kind().is_some());
+    /// let byte_range = parser.parse(input, &ctx).unwrap()[0].byte_range;
+    /// assert!(input[byte_range.0..byte_range.1].contains("3pm on Friday"));
+    /// // "é" is one character but two bytes
+    /// assert_eq!(byte_range.0 - 1, range.start);
+    /// ```
+    pub fn resolve_with_range(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+    ) -> RustlingResult<Vec<(std::ops::Range<usize>, Output)>> {
+        Ok(self
+            .parse(input, context)?
+            .into_iter()
+            .map(|m| (m.char_range.0..m.char_range.1, m.value))
+            .collect())
+    }
+
+    /// Parses the input like `parse`, pairing each output with the text of the input it was
+    /// resolved from, e.g. "fifty" or "50" for `IntegerOutput(50)`, to keep it in audit logs. The
+    /// text is only copied if the context captures it, see `ResolverContext::with_capture_text`.
+    pub fn resolve_with_text(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+    ) -> RustlingResult<Vec<(Output, Option<String>)>> {
+        let capture_text = context.captures_text();
+        Ok(self
+            .parse(input, context)?
+            .into_iter()
+            .map(|m| {
+                let text = if capture_text {
+                    Some(input[m.byte_range.0..m.byte_range.1].to_string())
+                } else {
+                    None
+                };
+                (m.value, text)
+            })
+            .collect())
+    }
+
+    /// Parses and resolves the input like `resolve_with_range`, but keeps all the resolved
+    /// matches, including the ones overlapping a better match, each with its score. The matches
+    /// are sorted by decreasing score, then by position in the input.
+    ///
+    /// The score is between 0 and 1. Matches which are not latent score above 0.5 and the latent
+    /// ones, e.g. "three" as a time of day, below it. Within each half the score grows with the
+    /// probability given by the training of the parser to the parsing tree of the match.
+    pub fn resolve_scored(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+    ) -> RustlingResult<Vec<(std::ops::Range<usize>, Output, f32)>> {
+        let all_output = OutputKind::all();
+        let tagger = CandidateTagger {
+            output_kind_filter: &all_output,
+            context,
+            resolve_all_candidates: true,
+        };
+        let mut scored = self
+            .0
+            .candidates(input, &tagger)?
+            .into_iter()
+            .filter_map(|candidate| {
+                let m = candidate.match_;
+                let score = score(m.probalog, m.latent);
+                m.value
+                    .map(|output| (m.char_range.0..m.char_range.1, output, score))
+            })
+            .collect::<Vec<_>>();
+        scored.sort_by(|a, b| {
+            b.2.partial_cmp(&a.2)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.start.cmp(&b.0.start))
+                .then_with(|| b.0.end.cmp(&a.0.end))
+        });
+        Ok(scored)
+    }
+
     pub fn analyse_with_kind_order(
         &self,
         examples: Vec<&str>,
@@ -123,6 +225,16 @@ impl Parser {
     }
 }
 
+/// Score of a match between 0 and 1 from its log-probability, see `Parser::resolve_scored`
+fn score(probalog: f32, latent: bool) -> f32 {
+    let probability = probalog.exp().min(1.0);
+    if latent {
+        probability / 2.0
+    } else {
+        0.5 + probability / 2.0
+    }
+}
+
 /// Obtain a parser for a given language.
 pub fn build_parser(lang: Lang) -> RustlingResult<Parser> {
     build_raw_parser(lang).map(crate::Parser)
@@ -182,6 +294,84 @@ pub fn train_parser(lang: Lang) -> RustlingResult<Parser> {
 mod tests {
     use super::*;
 
+    fn now() -> Interval<Local> {
+        Interval::starting_at(
+            Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        )
+    }
+
+    #[test]
+    fn test_resolve_in_timezone_en() {
+        let ctx = ResolverContext::for_reference_in_tz(now(), Tz::America__New_York);
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse("tomorrow at 9am", &ctx).unwrap();
+        let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Some(Tz::America__New_York), datetime.timezone);
+        assert_eq!(
+            rustling_ontology_moment::FixedOffset::west(5 * 3600),
+            datetime.utc_offset()
+        );
+        assert_eq!(
+            "09:00",
+            datetime
+                .moment
+                .0
+                .with_timezone(&Tz::America__New_York)
+                .format("%H:%M")
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_resolve_scored() {
+        let parser = build_parser(Lang::EN).unwrap();
+        let ctx = ResolverContext::default();
+        let scored = parser.resolve_scored("three", &ctx).unwrap();
+        let integer = scored
+            .iter()
+            .position(|&(_, ref output, _)| output == &Output::Integer(output::IntegerOutput(3)))
+            .unwrap();
+        // "three" is also a latent time of day
+        let time = scored
+            .iter()
+            .position(|&(_, ref output, _)| output.datetime_kind().is_some())
+            .unwrap();
+        assert!(integer < time);
+        assert_eq!(scored[integer].0, scored[time].0);
+        assert!(scored[integer].2 > 0.5 && scored[integer].2 <= 1.0);
+        assert!(scored[time].2 >= 0.0 && scored[time].2 < 0.5);
+    }
+
+    #[test]
+    fn test_supported_dimensions() {
+        use crate::dimension::DimensionKind;
+        assert_eq!(Lang::all(), supported_languages().to_vec());
+        let en = supported_dimensions(Lang::EN);
+        for kind in vec![DimensionKind::Datetime, DimensionKind::Number, DimensionKind::Duration, DimensionKind::AmountOfMoney] {
+            assert!(en.contains(&kind), "{:?}", kind);
+        }
+        for lang in supported_languages() {
+            assert!(!supported_dimensions(*lang).is_empty(), "{:?}", lang);
+            assert_eq!(dims(*lang), supported_dimensions(*lang).to_vec());
+        }
+    }
+
+    #[test]
+    fn test_resolve_with_text() {
+        let parser = build_parser(Lang::EN).unwrap();
+        let ctx = ResolverContext::default().with_capture_text(true);
+        let resolve = |ctx: &ResolverContext, sentence: &str| {
+            let mut resolved = parser.resolve_with_text(sentence, ctx).unwrap();
+            assert_eq!(1, resolved.len(), "{}", sentence);
+            resolved.remove(0)
+        };
+        let fifty = Output::Integer(output::IntegerOutput(50));
+        assert_eq!((fifty.clone(), Some("fifty".to_string())), resolve(&ctx, "fifty"));
+        assert_eq!((fifty.clone(), Some("50".to_string())), resolve(&ctx, "give me 50"));
+        assert_eq!((fifty, None), resolve(&ResolverContext::default(), "fifty"));
+    }
+
     #[test]
     fn test_long_number_en() {
         let ctx = ResolverContext::default();
@@ -0,0 +1,314 @@
+//! Spelling out of the numeric outputs, e.g. to speak the numbers given as digits.
+//!
+//! English and French cardinals and ordinals are supported, the other languages get the digits
+//! back. English numbers are written as in the United States, e.g. "one hundred twenty-one", and
+//! French ones with the traditional hyphens, e.g. "vingt et un" and "cent trente-deux". Decimals
+//! are read digit by digit after the point, e.g. "three point one four".
+use crate::output::{FloatOutput, IntegerOutput, OrdinalOutput};
+use crate::Lang;
+
+/// Spells out an output in the given language, e.g. "forty-two" for `IntegerOutput(42)` or
+/// "troisième" for `OrdinalOutput(3)` in French
+pub trait ToSpoken {
+    fn to_spoken(&self, lang: Lang) -> String;
+}
+
+impl ToSpoken for IntegerOutput {
+    fn to_spoken(&self, lang: Lang) -> String {
+        match lang {
+            Lang::EN | Lang::FR => signed(self.0, lang, cardinal),
+            _ => self.0.to_string(),
+        }
+    }
+}
+
+impl ToSpoken for OrdinalOutput {
+    fn to_spoken(&self, lang: Lang) -> String {
+        match lang {
+            Lang::EN | Lang::FR => signed(self.0, lang, ordinal),
+            _ => self.0.to_string(),
+        }
+    }
+}
+
+impl ToSpoken for FloatOutput {
+    fn to_spoken(&self, lang: Lang) -> String {
+        let digits = self.0.abs().to_string();
+        let (integer, decimals) = match digits.find('.') {
+            Some(point) => (&digits[..point], &digits[point + 1..]),
+            None => (&digits[..], ""),
+        };
+        let integer = match (lang, integer.parse::<u64>()) {
+            (Lang::EN, Ok(integer)) | (Lang::FR, Ok(integer)) => integer,
+            _ => return self.0.to_string(),
+        };
+        let mut words = cardinal(integer, lang);
+        if !decimals.is_empty() {
+            words.push_str(if lang == Lang::EN { " point" } else { " virgule" });
+            for digit in decimals.chars().filter_map(|digit| digit.to_digit(10)) {
+                words.push(' ');
+                words.push_str(&cardinal(digit as u64, lang));
+            }
+        }
+        if self.0 < 0.0 && words != cardinal(0, lang) {
+            format!("{} {}", minus(lang), words)
+        } else {
+            words
+        }
+    }
+}
+
+fn minus(lang: Lang) -> &'static str {
+    if lang == Lang::EN {
+        "minus"
+    } else {
+        "moins"
+    }
+}
+
+fn signed(n: i64, lang: Lang, spell: fn(u64, Lang) -> String) -> String {
+    let words = spell((n as i128).abs() as u64, lang);
+    if n < 0 {
+        format!("{} {}", minus(lang), words)
+    } else {
+        words
+    }
+}
+
+fn cardinal(n: u64, lang: Lang) -> String {
+    if lang == Lang::EN {
+        english_cardinal(n)
+    } else {
+        french_cardinal(n)
+    }
+}
+
+fn ordinal(n: u64, lang: Lang) -> String {
+    if lang == Lang::EN {
+        english_ordinal(n)
+    } else {
+        french_ordinal(n)
+    }
+}
+
+const ENGLISH_UNITS: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen",
+    "nineteen",
+];
+const ENGLISH_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const ENGLISH_SCALES: [&str; 7] = [
+    "", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion",
+];
+
+/// Groups of three digits, the lowest first
+fn thousands_groups(mut n: u64) -> Vec<u64> {
+    let mut groups = vec![];
+    while n > 0 {
+        groups.push(n % 1000);
+        n /= 1000;
+    }
+    groups
+}
+
+fn english_below_thousand(n: u64) -> String {
+    let mut words = vec![];
+    if n >= 100 {
+        words.push(format!("{} hundred", ENGLISH_UNITS[(n / 100) as usize]));
+    }
+    let rest = n % 100;
+    if rest >= 20 {
+        let tens = ENGLISH_TENS[(rest / 10) as usize];
+        if rest % 10 == 0 {
+            words.push(tens.to_string());
+        } else {
+            words.push(format!("{}-{}", tens, ENGLISH_UNITS[(rest % 10) as usize]));
+        }
+    } else if rest > 0 {
+        words.push(ENGLISH_UNITS[rest as usize].to_string());
+    }
+    words.join(" ")
+}
+
+fn english_cardinal(n: u64) -> String {
+    if n == 0 {
+        return ENGLISH_UNITS[0].to_string();
+    }
+    let mut words = vec![];
+    for (scale, group) in thousands_groups(n).into_iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        let group = english_below_thousand(group);
+        if scale == 0 {
+            words.push(group);
+        } else {
+            words.push(format!("{} {}", group, ENGLISH_SCALES[scale]));
+        }
+    }
+    words.join(" ")
+}
+
+fn english_ordinal(n: u64) -> String {
+    let cardinal = english_cardinal(n);
+    let split = cardinal.rfind(|c| c == ' ' || c == '-').map(|i| i + 1).unwrap_or(0);
+    let (head, last) = cardinal.split_at(split);
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        word if word.ends_with('y') => format!("{}ieth", &word[..word.len() - 1]),
+        word => format!("{}th", word),
+    };
+    format!("{}{}", head, last)
+}
+
+const FRENCH_UNITS: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+const FRENCH_TENS: [&str; 7] = ["", "dix", "vingt", "trente", "quarante", "cinquante", "soixante"];
+const FRENCH_SCALES: [&str; 7] = [
+    "", "mille", "million", "milliard", "billion", "billiard", "trillion",
+];
+
+fn french_below_hundred(n: u64) -> String {
+    match n {
+        0..=16 => FRENCH_UNITS[n as usize].to_string(),
+        17..=19 => format!("dix-{}", FRENCH_UNITS[(n - 10) as usize]),
+        20..=69 => {
+            let tens = FRENCH_TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => tens.to_string(),
+                1 => format!("{} et un", tens),
+                units => format!("{}-{}", tens, FRENCH_UNITS[units as usize]),
+            }
+        }
+        70 => "soixante-dix".to_string(),
+        71 => "soixante et onze".to_string(),
+        72..=79 => format!("soixante-{}", french_below_hundred(n - 60)),
+        80 => "quatre-vingts".to_string(),
+        _ => format!("quatre-vingt-{}", french_below_hundred(n - 80)),
+    }
+}
+
+/// Below a thousand, `cents` and `quatre-vingts` losing their final "s" before "mille"
+fn french_below_thousand(n: u64, before_mille: bool) -> String {
+    let hundreds = n / 100;
+    let rest = n % 100;
+    let mut words = vec![];
+    match hundreds {
+        0 => {}
+        1 => words.push("cent".to_string()),
+        _ if rest == 0 && !before_mille => words.push(format!("{} cents", FRENCH_UNITS[hundreds as usize])),
+        _ => words.push(format!("{} cent", FRENCH_UNITS[hundreds as usize])),
+    }
+    if rest == 80 && before_mille {
+        words.push("quatre-vingt".to_string());
+    } else if rest > 0 {
+        words.push(french_below_hundred(rest));
+    }
+    words.join(" ")
+}
+
+fn french_cardinal(n: u64) -> String {
+    if n == 0 {
+        return FRENCH_UNITS[0].to_string();
+    }
+    let mut words = vec![];
+    for (scale, group) in thousands_groups(n).into_iter().enumerate().rev() {
+        match (scale, group) {
+            (_, 0) => {}
+            (0, group) => words.push(french_below_thousand(group, false)),
+            // "mille" is invariable and never preceded by "un"
+            (1, 1) => words.push("mille".to_string()),
+            (1, group) => words.push(format!("{} mille", french_below_thousand(group, true))),
+            (scale, 1) => words.push(format!("un {}", FRENCH_SCALES[scale])),
+            (scale, group) => words.push(format!(
+                "{} {}s",
+                french_below_thousand(group, false),
+                FRENCH_SCALES[scale]
+            )),
+        }
+    }
+    words.join(" ")
+}
+
+fn french_ordinal(n: u64) -> String {
+    if n == 1 {
+        return "premier".to_string();
+    }
+    let cardinal = french_cardinal(n);
+    let split = cardinal.rfind(|c| c == ' ' || c == '-').map(|i| i + 1).unwrap_or(0);
+    let (head, last) = cardinal.split_at(split);
+    let stem = if last.ends_with("cents")
+        || last.ends_with("vingts")
+        || last.ends_with("ions")
+        || last.ends_with("ards")
+    {
+        &last[..last.len() - 1]
+    } else {
+        last
+    };
+    let stem = match stem {
+        "cinq" => "cinqu".to_string(),
+        "neuf" => "neuv".to_string(),
+        word if word.ends_with('e') => word[..word.len() - 1].to_string(),
+        word => word.to_string(),
+    };
+    format!("{}{}ième", head, stem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_spoken_en() {
+        let spoken = |n| IntegerOutput(n).to_spoken(Lang::EN);
+        assert_eq!("zero", spoken(0));
+        assert_eq!("twenty-one", spoken(21));
+        assert_eq!("one hundred", spoken(100));
+        assert_eq!("one million", spoken(1000000));
+        assert_eq!("minus forty-two", spoken(-42));
+        assert_eq!("two thousand three hundred fifteen", spoken(2315));
+        assert_eq!("minus three point five", FloatOutput(-3.5).to_spoken(Lang::EN));
+        assert_eq!("twelve", FloatOutput(12.0).to_spoken(Lang::EN));
+        assert_eq!("third", OrdinalOutput(3).to_spoken(Lang::EN));
+        assert_eq!("twenty-first", OrdinalOutput(21).to_spoken(Lang::EN));
+        assert_eq!("one hundredth", OrdinalOutput(100).to_spoken(Lang::EN));
+        assert_eq!("fortieth", OrdinalOutput(40).to_spoken(Lang::EN));
+    }
+
+    #[test]
+    fn test_to_spoken_fr() {
+        let spoken = |n| IntegerOutput(n).to_spoken(Lang::FR);
+        assert_eq!("zéro", spoken(0));
+        assert_eq!("vingt et un", spoken(21));
+        assert_eq!("cent", spoken(100));
+        assert_eq!("un million", spoken(1000000));
+        assert_eq!("soixante et onze", spoken(71));
+        assert_eq!("quatre-vingt-dix-sept", spoken(97));
+        assert_eq!("deux cents", spoken(200));
+        assert_eq!("quatre-vingt mille", spoken(80000));
+        assert_eq!("deux millions trois cent mille", spoken(2300000));
+        assert_eq!("moins trois virgule cinq", FloatOutput(-3.5).to_spoken(Lang::FR));
+        assert_eq!("premier", OrdinalOutput(1).to_spoken(Lang::FR));
+        assert_eq!("troisième", OrdinalOutput(3).to_spoken(Lang::FR));
+        assert_eq!("vingt et unième", OrdinalOutput(21).to_spoken(Lang::FR));
+        assert_eq!("centième", OrdinalOutput(100).to_spoken(Lang::FR));
+        assert_eq!("neuvième", OrdinalOutput(9).to_spoken(Lang::FR));
+    }
+
+    #[test]
+    fn test_to_spoken_other_languages() {
+        assert_eq!("21", IntegerOutput(21).to_spoken(Lang::DE));
+        assert_eq!("-3.5", FloatOutput(-3.5).to_spoken(Lang::DE));
+    }
+}
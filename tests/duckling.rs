@@ -0,0 +1,32 @@
+#![cfg(feature = "duckling")]
+extern crate rustling_ontology;
+extern crate serde_json;
+
+use std::str::FromStr;
+
+use rustling_ontology::duckling::DucklingCompat;
+use rustling_ontology::{build_parser, Interval, Lang, Local, Moment, OutputKind, ResolverContext, TimeZone, Tz};
+use serde_json::Value;
+
+/// Outputs of duckling for its test reference, 2013-02-12T04:30:00-02:00 in Sao Paulo
+#[test]
+fn test_duckling_golden_file() {
+    let golden: Vec<Value> = serde_json::from_str(include_str!("duckling.json")).unwrap();
+    let reference = Tz::America__Sao_Paulo.ymd(2013, 2, 12).and_hms(4, 30, 0).with_timezone(&Local);
+    let ctx = ResolverContext::for_reference_in_tz(
+        Interval::starting_at(Moment(reference), rustling_ontology::Grain::Second),
+        Tz::America__Sao_Paulo,
+    );
+    let parser = build_parser(Lang::EN).unwrap();
+    for entry in golden {
+        let phrase = entry["phrase"].as_str().unwrap();
+        let kind = OutputKind::from_str(entry["kind"].as_str().unwrap()).unwrap();
+        let matches = parser.parse_with_kind_order(phrase, &ctx, &[kind]).unwrap();
+        let found = &matches[0];
+        let body = &phrase[found.byte_range.0..found.byte_range.1];
+        let json = DucklingCompat::new(&found.value)
+            .with_span(body, found.char_range.0, found.char_range.1)
+            .to_json();
+        assert_eq!(entry["duckling"], json, "{}", phrase);
+    }
+}
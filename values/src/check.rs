@@ -38,6 +38,72 @@ pub fn check_ordinal(v: i64) -> CheckOrdinal {
     CheckOrdinal { value: v }
 }
 
+#[derive(Debug)]
+pub struct CheckOrdinalRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Check<Dimension> for CheckOrdinalRange {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        OrdinalRangeValue::attempt_from(pn.value.clone())
+            .map(|v| v.start == self.start && v.end == self.end)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_ordinal_range(start: i64, end: i64) -> CheckOrdinalRange {
+    CheckOrdinalRange { start, end }
+}
+
+#[derive(Debug)]
+pub struct CheckNumberRange {
+    pub low: f64,
+    pub high: f64,
+    pub low_inclusive: bool,
+    pub high_inclusive: bool,
+}
+
+impl Check<Dimension> for CheckNumberRange {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        NumberRangeValue::attempt_from(pn.value.clone())
+            .map(|v| {
+                v.low == self.low
+                    && v.high == self.high
+                    && v.low_inclusive == self.low_inclusive
+                    && v.high_inclusive == self.high_inclusive
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_number_range(low: f64, high: f64, low_inclusive: bool, high_inclusive: bool) -> CheckNumberRange {
+    CheckNumberRange {
+        low,
+        high,
+        low_inclusive,
+        high_inclusive,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckNumberComparison {
+    pub op: CompareOp,
+    pub value: f64,
+}
+
+impl Check<Dimension> for CheckNumberComparison {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        NumberComparisonValue::attempt_from(pn.value.clone())
+            .map(|v| v.op == self.op && v.value == self.value)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_number_comparison(op: CompareOp, value: f64) -> CheckNumberComparison {
+    CheckNumberComparison { op, value }
+}
+
 #[derive(Debug)]
 pub struct CheckFloat {
     pub value: f64,
@@ -100,7 +166,7 @@ impl Check<Dimension> for CheckMoment {
                 .resolve(&pn.value)
                 .and_then(|v| DatetimeIntervalOutput::attempt_from(v))
                 .map(|v| {
-                    if let DatetimeIntervalKind::After(m) = v.interval_kind {
+                    if let DatetimeIntervalKind::After { datetime: m, .. } = v.interval_kind {
                         let check_value =
                             m.moment == self.interval.start && m.grain == self.interval.grain;
                         let check_precision = m.precision == self.precision;
@@ -115,7 +181,7 @@ impl Check<Dimension> for CheckMoment {
                 .resolve(&pn.value)
                 .and_then(|v| DatetimeIntervalOutput::attempt_from(v))
                 .map(|v| {
-                    if let DatetimeIntervalKind::Before(m) = v.interval_kind {
+                    if let DatetimeIntervalKind::Before { datetime: m, .. } = v.interval_kind {
                         let check_value =
                             m.moment == self.interval.start && m.grain == self.interval.grain;
                         let check_precision = m.precision == self.precision;
@@ -144,6 +210,37 @@ pub fn check_moment(
     }
 }
 
+#[derive(Debug)]
+pub struct CheckTwelveHour {
+    pub moment: Moment<Local>,
+    pub was_12_hour: bool,
+    pub context: ResolverContext,
+}
+
+impl Check<Dimension> for CheckTwelveHour {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        self.context
+            .resolve(&pn.value)
+            .and_then(|v| DatetimeOutput::attempt_from(v))
+            .map(|v| v.moment == self.moment && v.was_12_hour == self.was_12_hour)
+            .unwrap_or(false)
+    }
+}
+
+/// Checks the moment and whether it was given with the 12-hour clock, see
+/// `DatetimeOutput::was_12_hour`
+pub fn check_12_hour(
+    context: ResolverContext,
+    moment: Moment<Local>,
+    was_12_hour: bool,
+) -> CheckTwelveHour {
+    CheckTwelveHour {
+        moment,
+        was_12_hour,
+        context,
+    }
+}
+
 #[derive(Debug)]
 pub struct CheckMomentSpan {
     pub interval: Interval<Local>,
@@ -233,16 +330,153 @@ pub fn check_percentage(value: f64) -> CheckPercentage {
 pub struct CheckTemperature {
     pub value: f64,
     pub unit: Option<&'static str>,
+    pub qualifier: Option<TemperatureQualifier>,
 }
 
 impl Check<Dimension> for CheckTemperature {
     fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
         TemperatureValue::attempt_from(pn.value.clone())
-            .map(|v| v.value == self.value && v.unit == self.unit)
+            .map(|v| v.value == self.value && v.unit == self.unit && v.qualifier == self.qualifier)
             .unwrap_or(false)
     }
 }
 
 pub fn check_temperature(value: f64, unit: Option<&'static str>) -> CheckTemperature {
-    CheckTemperature { value, unit }
+    CheckTemperature {
+        value,
+        unit,
+        qualifier: None,
+    }
+}
+
+pub fn check_temperature_with_qualifier(
+    value: f64,
+    unit: Option<&'static str>,
+    qualifier: TemperatureQualifier,
+) -> CheckTemperature {
+    CheckTemperature {
+        value,
+        unit,
+        qualifier: Some(qualifier),
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckTemperatureRange {
+    pub low: f64,
+    pub high: f64,
+    pub unit: Option<&'static str>,
+}
+
+impl Check<Dimension> for CheckTemperatureRange {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        TemperatureRangeValue::attempt_from(pn.value.clone())
+            .map(|v| v.low.value == self.low && v.high.value == self.high && v.unit() == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_temperature_range(low: f64, high: f64, unit: Option<&'static str>) -> CheckTemperatureRange {
+    CheckTemperatureRange { low, high, unit }
+}
+
+#[derive(Debug)]
+pub struct CheckQuantity {
+    pub value: f64,
+    pub unit: Option<&'static str>,
+    pub product: Option<&'static str>,
+}
+
+impl Check<Dimension> for CheckQuantity {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        QuantityValue::attempt_from(pn.value.clone())
+            .map(|v| {
+                v.value == self.value
+                    && v.unit.as_ref().map(|it| it.as_str()) == self.unit
+                    && v.product.as_ref().map(|it| it.as_str()) == self.product
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_quantity(
+    value: f64,
+    unit: Option<&'static str>,
+    product: Option<&'static str>,
+) -> CheckQuantity {
+    CheckQuantity {
+        value,
+        unit,
+        product,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckPhoneNumber {
+    pub value: &'static str,
+}
+
+impl Check<Dimension> for CheckPhoneNumber {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        PhoneNumberValue::attempt_from(pn.value.clone())
+            .map(|v| v.0 == self.value)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_phone_number(value: &'static str) -> CheckPhoneNumber {
+    CheckPhoneNumber { value }
+}
+
+#[derive(Debug)]
+pub struct CheckAge {
+    pub value: f64,
+    pub unit: Grain,
+}
+
+impl Check<Dimension> for CheckAge {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        AgeValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.unit == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_age(value: f64, unit: Grain) -> CheckAge {
+    CheckAge { value, unit }
+}
+
+#[derive(Debug)]
+pub struct CheckRecurring {
+    pub first: Interval<Local>,
+    /// Period from one occurrence to the next, see `DatetimeRecurringOutput::stride`
+    pub period: Period,
+    pub context: ResolverContext,
+}
+
+impl Check<Dimension> for CheckRecurring {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        self.context
+            .resolve(&pn.value)
+            .and_then(|v| DatetimeRecurringOutput::attempt_from(v))
+            .map(|v| {
+                v.first.moment == self.first.start
+                    && v.first.grain == self.first.grain
+                    && v.stride() == self.period
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_recurring(
+    context: ResolverContext,
+    moment: Moment<Local>,
+    grain: Grain,
+    period: Period,
+) -> CheckRecurring {
+    CheckRecurring {
+        first: Interval::starting_at(moment, grain),
+        period,
+        context,
+    }
 }
@@ -1,174 +1,3082 @@
 use crate::dimension::*;
+use crate::holidays::{weekday, HolidayProvider, WorkingDays};
+use crate::numbers::{NumericContext, NumericOutput};
 use crate::output::*;
+pub use crate::parsing::{IdentityContext, ParsingContext};
 use log::warn;
 use moment::*;
 use rustling::Value;
+use std::cmp;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::{Arc, Mutex};
 
-pub trait ParsingContext<V: Value> {
-    type O;
-    fn resolve(&self, value: &V) -> Option<Self::O>;
+/// How a day of the week is resolved when the reference is on this day, see
+/// `ResolverContext::with_weekday_policy`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WeekdayPolicy {
+    /// "friday" and "this friday" can be the reference day itself
+    IncludeToday,
+    /// "friday" and "this friday" are the next ones after the reference day, the default
+    ExcludeToday,
+    /// As `ExcludeToday`, with "next friday" always in the week after the one of the reference,
+    /// e.g. in ten days on a tuesday
+    NextStrict,
 }
 
-pub struct IdentityContext<V: Value + Clone> {
-    _phantom: ::std::marker::PhantomData<V>,
+impl Default for WeekdayPolicy {
+    fn default() -> WeekdayPolicy {
+        WeekdayPolicy::ExcludeToday
+    }
+}
+
+/// How a datetime computed from the reference is resolved when it is finer than the reference,
+/// e.g. "in 2 hours" when only the day of the reference is known, see
+/// `ResolverContext::with_fuzzy_reference`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FuzzyReferencePolicy {
+    /// The datetime is computed from the start of the reference interval, the default
+    Snap,
+    /// The datetime is not resolved
+    Reject,
+}
+
+impl Default for FuzzyReferencePolicy {
+    fn default() -> FuzzyReferencePolicy {
+        FuzzyReferencePolicy::Snap
+    }
+}
+
+/// Which interval is chosen when the walker finds a datetime both after and before the
+/// reference, e.g. "wednesday" on a wednesday, see `ResolverContext::with_tie_break`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TieBreak {
+    /// The interval after the reference, the past one only when there is none, the default
+    PreferFuture,
+    /// The interval before the reference, the future one only when there is none
+    PreferPast,
+    /// The interval nearest to the reference, the future one when both are as near
+    PreferClosest,
+}
+
+impl Default for TieBreak {
+    fn default() -> TieBreak {
+        TieBreak::PreferFuture
+    }
+}
+
+/// How a date only known to the month or the year is resolved, e.g. "march" or "2024", see
+/// `ResolverContext::with_partial_date_policy`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PartialDatePolicy {
+    /// A datetime at the first moment of the period, with the grain of the period, the default
+    Start,
+    /// An interval spanning the whole period, e.g. from the 1st of march to the 1st of april
+    Interval,
+}
+
+impl Default for PartialDatePolicy {
+    fn default() -> PartialDatePolicy {
+        PartialDatePolicy::Start
+    }
 }
 
-impl<V: Value + Clone> IdentityContext<V> {
-    pub fn new() -> IdentityContext<V> {
-        IdentityContext {
-            _phantom: ::std::marker::PhantomData,
+/// How an hour of the 12-hour clock given without am or pm is resolved, e.g. "at 5", see
+/// `ResolverContext::with_ambiguous_hour_policy`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AmbiguousHourPolicy {
+    /// The next occurrence of the hour, in the morning or in the afternoon, the default
+    PreferNext,
+    /// The next occurrence of the hour in the morning, e.g. 5am
+    PreferAm,
+    /// The next occurrence of the hour in the afternoon or the evening, e.g. 5pm
+    PreferPm,
+}
+
+impl Default for AmbiguousHourPolicy {
+    fn default() -> AmbiguousHourPolicy {
+        AmbiguousHourPolicy::PreferNext
+    }
+}
+
+/// First year of the century in which the years given with two digits are read, e.g. with the
+/// default pivot of 1950 "'49" is 2049 and "'50" is 1950, see
+/// `ResolverContext::with_two_digit_year_pivot`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TwoDigitYearPivot(pub u16);
+
+impl Default for TwoDigitYearPivot {
+    fn default() -> TwoDigitYearPivot {
+        TwoDigitYearPivot(1950)
+    }
+}
+
+/// Bounds of the seasons, see `ResolverContext::with_season_convention`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SeasonConvention {
+    /// Seasons of three whole months, e.g. the summer from june 1 to august 31 in the northern
+    /// hemisphere, the default
+    Meteorological,
+    /// Seasons between the equinoxes and the solstices, e.g. the summer from june 21 to
+    /// september 23 in the northern hemisphere
+    Astronomical,
+}
+
+impl Default for SeasonConvention {
+    fn default() -> SeasonConvention {
+        SeasonConvention::Meteorological
+    }
+}
+
+/// Hemisphere in which the seasons are resolved, see `ResolverContext::with_hemisphere`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Hemisphere {
+    /// The summer is in june, july and august, the default
+    Northern,
+    /// The summer is in december, january and february
+    Southern,
+}
+
+impl Default for Hemisphere {
+    fn default() -> Hemisphere {
+        Hemisphere::Northern
+    }
+}
+
+/// Start and end of the office hours, as hours and minutes, see
+/// `ResolverContext::with_office_hours`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct OfficeHoursWindow {
+    start: (u32, u32),
+    end: (u32, u32),
+}
+
+impl Default for OfficeHoursWindow {
+    fn default() -> OfficeHoursWindow {
+        OfficeHoursWindow {
+            start: (9, 0),
+            end: (17, 0),
         }
     }
 }
 
-impl<V: Value + Clone> ParsingContext<V> for IdentityContext<V> {
-    type O = V;
-    fn resolve(&self, value: &V) -> Option<V> {
-        Some(value.clone())
+/// How a datetime resolved out of the min and max intervals of the context is handled, see
+/// `ResolverContext::with_out_of_range_policy`. The walker stops around these intervals, but not
+/// exactly at them, e.g. "in 100 years" is found 30 years after the default max interval.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OutOfRangePolicy {
+    /// The datetime isn't resolved, `try_resolve` giving `ResolveError::DatetimeOutOfRange`, the
+    /// default
+    Reject,
+    /// The datetime is resolved as the min or max interval, whichever it escapes
+    Clamp,
+}
+
+impl Default for OutOfRangePolicy {
+    fn default() -> OutOfRangePolicy {
+        OutOfRangePolicy::Reject
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+/// Resolver of the dimensions of a kind, registered with `ResolverContext::with_custom_resolver`
+/// to extend the resolution, e.g. giving the integers of 8 digits as product SKUs:
+///
+/// ```
+/// # use rustling_ontology_values::{CustomResolver, ResolverContext};
+/// # use rustling_ontology_values::dimension::{Dimension, DimensionKind, NumberValue};
+/// # use rustling_ontology_values::output::{CustomOutput, Output};
+/// #[derive(Debug)]
+/// struct SkuResolver;
+///
+/// impl CustomResolver for SkuResolver {
+///     fn dimension_kind(&self) -> DimensionKind {
+///         DimensionKind::Number
+///     }
+///
+///     fn resolve(&self, dim: &Dimension) -> Option<Output> {
+///         match dim {
+///             &Dimension::Number(NumberValue::Integer(ref integer))
+///                 if integer.value >= 10_000_000 && integer.value <= 99_999_999 =>
+///             {
+///                 Some(Output::Custom(CustomOutput {
+///                     tag: "sku".to_string(),
+///                     value: format!("SKU-{}", integer.value),
+///                 }))
+///             }
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let context = ResolverContext::default().with_custom_resolver(SkuResolver);
+/// ```
+pub trait CustomResolver: fmt::Debug + Send + Sync {
+    /// Kind of the dimensions given to `resolve`
+    fn dimension_kind(&self) -> DimensionKind;
+
+    /// Resolves the dimension, `None` to leave it to the next custom resolvers and then to the
+    /// context
+    fn resolve(&self, dim: &Dimension) -> Option<Output>;
+}
+
+/// Options of `ResolverContext::resolve_with_options`
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct ResolveOptions {
+    /// Don't resolve latent dimensions, i.e. low-confidence temperatures and datetimes. Numbers
+    /// are never latent and are always resolved.
+    pub drop_latent: bool,
+}
+
+/// Reference and options with which the dimensions are resolved, see `ResolverContext::resolve`.
+///
+/// Resolving only borrows the context, which is `Send` and `Sync`: a web server can share one
+/// context, e.g. in an `Arc`, between its worker threads resolving at the same time, the cache of
+/// `with_cache` being locked while it is read or filled. A value is resolved the same way from
+/// every thread. The dimensions aren't `Send`, their constraints being reference-counted, so each
+/// thread parses its own sentences.
+#[derive(Debug, Clone)]
 pub struct ResolverContext {
     ctx: Context<Local>,
+    tz: Option<Tz>,
+    /// Offset of the reference given to `from_datetime`, used without a timezone
+    fixed_offset: Option<FixedOffset>,
+    holidays: Option<Arc<dyn HolidayProvider>>,
+    working_days: WorkingDays,
+    weekday_policy: WeekdayPolicy,
+    fuzzy_reference: FuzzyReferencePolicy,
+    tie_break: TieBreak,
+    partial_date_policy: PartialDatePolicy,
+    out_of_range_policy: OutOfRangePolicy,
+    ambiguous_hour_policy: AmbiguousHourPolicy,
+    fiscal_year_start: u32,
+    two_digit_year_pivot: TwoDigitYearPivot,
+    prefer_year_range: Option<RangeInclusive<i32>>,
+    date_order: Option<DateOrder>,
+    season_convention: SeasonConvention,
+    hemisphere: Hemisphere,
+    office_hours: OfficeHoursWindow,
+    capture_text: bool,
+    float_precision: Option<u32>,
+    custom_resolvers: Vec<Arc<dyn CustomResolver>>,
+    cache: Option<Arc<IntervalCache>>,
+}
+
+impl Default for ResolverContext {
+    fn default() -> ResolverContext {
+        ResolverContext::from_context(Context::default())
+    }
 }
 
 impl ResolverContext {
-    pub fn from_secs(secs: i64) -> ResolverContext {
-        let anchor = Interval::starting_at(Moment(Local.timestamp(secs, 0)), Grain::Second);
-        ResolverContext::for_reference(anchor)
+    /// Returns a ResolverContext for the given timestamp, `None` if it is out of the range of
+    /// years which can be represented in the local timezone, see `local_year_range`.
+    pub fn from_secs(secs: i64) -> Option<ResolverContext> {
+        Moment::from_timestamp(secs).map(|moment| ResolverContext::from_datetime(moment.0))
+    }
+
+    /// Returns a ResolverContext for the given datetime, in any timezone, truncated to the
+    /// second. Datetimes are resolved as seen from the wall clock of its offset when it isn't the
+    /// local one, e.g. "tomorrow" is the day after the date of the datetime in its own offset.
+    /// Unlike `from_secs` the range of years is not checked.
+    pub fn from_datetime<Z: TimeZone>(datetime: DateTime<Z>) -> ResolverContext {
+        let offset = datetime.offset().fix();
+        let moment = Moment(datetime.with_timezone(&Local)).round_to(Grain::Second);
+        let fixed_offset = if moment.0.offset().fix() == offset {
+            None
+        } else {
+            Some(offset)
+        };
+        ResolverContext {
+            fixed_offset,
+            ..ResolverContext::for_reference(Interval::starting_at(moment, Grain::Second))
+        }
     }
 
-    /// Returns a ResolverContext for the given interval. This API is working for 32bits and 64bits 
-    /// operating system by supporting dates only between 1970 and 2038
+    /// Returns a ResolverContext for the given interval, with min and max dates 70 years around
+    /// it. Dates are also restricted to `local_year_range`.
     pub fn for_reference(now: Interval<Local>) -> ResolverContext {
+        ResolverContext::from_context(Context::for_reference(now))
+    }
+
+    /// Returns a ResolverContext for the given interval, resolving datetimes as seen from the
+    /// wall clock of `tz` instead of the local timezone. The same dates restrictions as
+    /// `for_reference` apply.
+    pub fn for_reference_in_tz(now: Interval<Local>, tz: Tz) -> ResolverContext {
         ResolverContext {
-            ctx: Context::for_reference(now),
+            tz: Some(tz),
+            ..ResolverContext::for_reference(now)
         }
     }
 
-    /// Returns a ResolverContext with the given intervals. No restrictions is applied. 
-    pub fn new(now: Interval<Local>, min: Interval<Local>, max: Interval<Local>) -> ResolverContext {
+    /// Returns a ResolverContext with the given intervals. No restrictions is applied.
+    pub fn new(
+        now: Interval<Local>,
+        min: Interval<Local>,
+        max: Interval<Local>,
+    ) -> ResolverContext {
+        ResolverContext::from_context(Context::new(now, min, max))
+    }
+
+    /// Returns a ResolverContext walking the constraints with the given context, with the
+    /// default options
+    fn from_context(ctx: Context<Local>) -> ResolverContext {
         ResolverContext {
-            ctx: Context::new(now, min, max)
+            ctx,
+            tz: None,
+            fixed_offset: None,
+            holidays: None,
+            working_days: WorkingDays::default(),
+            weekday_policy: WeekdayPolicy::default(),
+            fuzzy_reference: FuzzyReferencePolicy::default(),
+            tie_break: TieBreak::default(),
+            partial_date_policy: PartialDatePolicy::default(),
+            out_of_range_policy: OutOfRangePolicy::default(),
+            ambiguous_hour_policy: AmbiguousHourPolicy::default(),
+            fiscal_year_start: 1,
+            two_digit_year_pivot: TwoDigitYearPivot::default(),
+            prefer_year_range: None,
+            date_order: None,
+            season_convention: SeasonConvention::default(),
+            hemisphere: Hemisphere::default(),
+            office_hours: OfficeHoursWindow::default(),
+            capture_text: false,
+            float_precision: None,
+            custom_resolvers: vec![],
+            cache: None,
         }
     }
-}
 
-impl ParsingContext<Dimension> for ResolverContext {
-    type O = Output;
+    /// Returns a builder to set the reference, min and max intervals one by one
+    pub fn builder() -> ResolverContextBuilder {
+        ResolverContextBuilder::default()
+    }
 
-    fn resolve(&self, dim: &Dimension) -> Option<Output> {
+    /// Returns the same context, resolving the datetimes built from a holiday with the given
+    /// calendar, e.g. `holidays::DefaultHolidayProvider`. Holidays unknown to the calendar are
+    /// resolved as without it.
+    pub fn with_holidays<P: HolidayProvider + 'static>(self, provider: P) -> ResolverContext {
+        ResolverContext {
+            holidays: Some(Arc::new(provider)),
+            ..self
+        }
+    }
+
+    /// Returns the same context, resolving the dimensions of the kind of the resolver with it
+    /// first. The resolvers are consulted in the order they were added, the context resolving
+    /// the dimensions they all leave. Dimensions the context can't resolve, e.g. cycles, are then
+    /// resolved too, and selected by the parser with `OutputKind::Custom`.
+    pub fn with_custom_resolver<R: CustomResolver + 'static>(
+        mut self,
+        resolver: R,
+    ) -> ResolverContext {
+        self.custom_resolvers.push(Arc::new(resolver));
+        self
+    }
+
+    /// Returns the same context, counting "in 3 business days" with the given weekmask, from
+    /// monday to sunday, and holidays. By default working days are from monday to friday.
+    pub fn with_working_days(
+        self,
+        weekmask: [bool; 7],
+        holidays: Vec<Interval<Local>>,
+    ) -> ResolverContext {
+        ResolverContext {
+            working_days: WorkingDays::new(weekmask, holidays),
+            ..self
+        }
+    }
+
+    /// Returns the same context, resolving the days of the week with the given policy
+    pub fn with_weekday_policy(self, weekday_policy: WeekdayPolicy) -> ResolverContext {
+        ResolverContext {
+            weekday_policy,
+            ..self
+        }
+    }
+
+    /// Returns the same context, resolving the datetimes computed from the reference at a finer
+    /// grain than the one of the reference with the given policy. With a reference at the day
+    /// grain, "tomorrow" is resolved either way, while "now" and "in an hour" are either
+    /// resolved from the start of the day or not resolved.
+    pub fn with_fuzzy_reference(self, fuzzy_reference: FuzzyReferencePolicy) -> ResolverContext {
+        ResolverContext {
+            fuzzy_reference,
+            ..self
+        }
+    }
+
+    /// Returns the same context, choosing between the intervals found after and before the
+    /// reference with the given policy
+    pub fn with_tie_break(self, tie_break: TieBreak) -> ResolverContext {
+        ResolverContext { tie_break, ..self }
+    }
+
+    /// Returns the same context, with weeks starting on the given day, e.g. sunday in the United
+    /// States. "this week" on a sunday is then the week starting on this sunday instead of the one
+    /// ending on it. By default weeks start on monday, for all the languages.
+    pub fn with_week_start(self, week_start: Weekday) -> ResolverContext {
+        ResolverContext {
+            ctx: self.ctx.with_week_start(week_start),
+            ..self
+        }
+    }
+
+    /// Returns the same context, resolving the dates only known to the month or the year with the
+    /// given policy
+    pub fn with_partial_date_policy(
+        self,
+        partial_date_policy: PartialDatePolicy,
+    ) -> ResolverContext {
+        ResolverContext {
+            partial_date_policy,
+            ..self
+        }
+    }
+
+    /// Returns the same context, handling the datetimes resolved out of the min and max intervals
+    /// with the given policy
+    pub fn with_out_of_range_policy(
+        self,
+        out_of_range_policy: OutOfRangePolicy,
+    ) -> ResolverContext {
+        ResolverContext {
+            out_of_range_policy,
+            ..self
+        }
+    }
+
+    /// Returns the same context, resolving the hours of the 12-hour clock given without am or pm
+    /// with the given policy. Hours given with am or pm, or on the 24-hour clock, are resolved as
+    /// without it.
+    pub fn with_ambiguous_hour_policy(
+        self,
+        ambiguous_hour_policy: AmbiguousHourPolicy,
+    ) -> ResolverContext {
+        ResolverContext {
+            ambiguous_hour_policy,
+            ..self
+        }
+    }
+
+    /// Returns the same context, with fiscal years starting on the given month, from 1 for
+    /// january to 12. The quarters identified by their number are then the ones of the fiscal
+    /// year, which is named after the calendar year it starts in: with fiscal years starting in
+    /// april, "Q1 2025" is from april to june 2025 and "Q4 2025" from january to march 2026. The
+    /// quarters relative to the reference, e.g. "next quarter", stay calendar quarters. By default
+    /// fiscal years start in january.
+    ///
+    /// Panics if the month is not between 1 and 12.
+    pub fn with_fiscal_year_start(self, month: u32) -> ResolverContext {
+        assert!(
+            1 <= month && month <= 12,
+            "Invalid fiscal year start: {}",
+            month
+        );
+        ResolverContext {
+            fiscal_year_start: month,
+            ..self
+        }
+    }
+
+    /// Returns the same context, reading the years given with two digits in the century starting
+    /// at the pivot, e.g. "3/4/21" is in 1921 with a pivot of 1900. Years given with more digits
+    /// are resolved as without it.
+    pub fn with_two_digit_year_pivot(
+        self,
+        two_digit_year_pivot: TwoDigitYearPivot,
+    ) -> ResolverContext {
+        ResolverContext {
+            two_digit_year_pivot,
+            ..self
+        }
+    }
+
+    /// Returns the same context, resolving a lone number as a year only in the given range, e.g.
+    /// "1960" is then a count with `1990..=2100` while "in 1960" is still a year. Without it the
+    /// grammar decides, e.g. from 1000 to 2100 in english.
+    pub fn prefer_year_range(self, range: RangeInclusive<i32>) -> ResolverContext {
+        ResolverContext {
+            prefer_year_range: Some(range),
+            ..self
+        }
+    }
+
+    /// Returns the same context, reading the numeric dates whose day and month can be swapped in
+    /// the given order, e.g. "03/04/2024" is april 3 in `DayMonthYear` order even in english. By
+    /// default they are read in the order of the language, and the dates which can only be read
+    /// one way, e.g. "13/04/2024", are always read that way. The `YearMonthDay` order only
+    /// applies to the dates starting with the year, which are always read in that order.
+    pub fn with_date_order(self, date_order: DateOrder) -> ResolverContext {
+        ResolverContext {
+            date_order: Some(date_order),
+            ..self
+        }
+    }
+
+    /// Returns the same context, resolving the seasons between the bounds of the given
+    /// convention, e.g. "this summer" from june 21 to september 23 with
+    /// `SeasonConvention::Astronomical`. By default the seasons are meteorological.
+    pub fn with_season_convention(self, season_convention: SeasonConvention) -> ResolverContext {
+        ResolverContext {
+            season_convention,
+            ..self
+        }
+    }
+
+    /// Returns the same context, resolving the seasons in the given hemisphere, e.g. "next
+    /// summer" in december in the southern one. By default the seasons are the ones of the
+    /// northern hemisphere.
+    pub fn with_hemisphere(self, hemisphere: Hemisphere) -> ResolverContext {
+        ResolverContext { hemisphere, ..self }
+    }
+
+    /// Returns the same context, with office hours from `start` to `end`, given as hours and
+    /// minutes, e.g. `(8, 30)` for 8:30. "working hours today" is then resolved from `start` to
+    /// `end` today, and "after hours" as from `end` on. By default the office hours are from 9:00
+    /// to 17:00.
+    ///
+    /// Panics if one of the times is invalid or if `start` is not before `end`.
+    pub fn with_office_hours(self, start: (u32, u32), end: (u32, u32)) -> ResolverContext {
+        let valid = |(hour, minute): (u32, u32)| hour < 24 && minute < 60;
+        assert!(
+            valid(start) && valid(end) && start < end,
+            "Invalid office hours: {:?} to {:?}",
+            start,
+            end
+        );
+        ResolverContext {
+            office_hours: OfficeHoursWindow { start, end },
+            ..self
+        }
+    }
+
+    /// Returns the same context, keeping the intervals found by the walker for up to `capacity`
+    /// datetimes, the least recently used ones being dropped first. The datetimes are identified
+    /// by the structure of their constraint and the reference, so that resolving the same phrase
+    /// again doesn't walk through time. The cache is shared by the clones of the context and can
+    /// be used from several threads. A capacity of 0 disables it.
+    pub fn with_cache(self, capacity: usize) -> ResolverContext {
+        ResolverContext {
+            cache: if capacity > 0 {
+                Some(Arc::new(IntervalCache::new(capacity)))
+            } else {
+                None
+            },
+            ..self
+        }
+    }
+
+    /// Returns the same context, keeping the text of the input each output was resolved from,
+    /// e.g. "fifty" or "50", see `Parser::resolve_with_text` of the ontology. The text isn't
+    /// copied by default.
+    pub fn with_capture_text(self, capture_text: bool) -> ResolverContext {
+        ResolverContext {
+            capture_text,
+            ..self
+        }
+    }
+
+    /// Returns the same context, rounding the resolved floats to the given number of decimals
+    /// with `FloatOutput::round`, e.g. 3.14 for "3.14159265" with 2 decimals. Percentages and the
+    /// values with a unit are kept as they are.
+    pub fn with_float_precision(self, decimals: u32) -> ResolverContext {
+        ResolverContext {
+            float_precision: Some(decimals),
+            ..self
+        }
+    }
+
+    pub fn timezone(&self) -> Option<Tz> {
+        self.tz
+    }
+
+    /// Whether the text the outputs were resolved from is kept, see `with_capture_text`
+    pub fn captures_text(&self) -> bool {
+        self.capture_text
+    }
+
+    /// Context to walk the constraints with, moved to the wall clock of the timezone or of the
+    /// fixed offset if any
+    fn walking_context(&self) -> Context<Local> {
+        fn in_wall_clock<Z: TimeZone>(ctx: &Context<Local>, tz: &Z) -> Context<Local> {
+            Context::new(
+                ctx.reference.to_wall_clock_in(tz),
+                ctx.min.to_wall_clock_in(tz),
+                ctx.max.to_wall_clock_in(tz),
+            )
+            .with_week_start(ctx.week_start)
+        }
+        match (self.tz, self.fixed_offset) {
+            (Some(ref tz), _) => in_wall_clock(&self.ctx, tz),
+            (None, Some(ref offset)) => in_wall_clock(&self.ctx, offset),
+            (None, None) => self.ctx,
+        }
+    }
+
+    /// Moves back an interval found with the walking context to the local timezone
+    fn from_walking_interval(&self, interval: Interval<Local>) -> Interval<Local> {
+        match (self.tz, self.fixed_offset) {
+            (Some(ref tz), _) => interval.from_wall_clock_in(tz),
+            (None, Some(ref offset)) => interval.from_wall_clock_in(offset),
+            (None, None) => interval,
+        }
+    }
+
+    /// Moves a quarter identified by its number to the same quarter of the fiscal year
+    fn fiscal_quarter_interval(
+        &self,
+        fiscal_quarter: bool,
+        interval: Interval<Local>,
+    ) -> Interval<Local> {
+        if !fiscal_quarter || self.fiscal_year_start == 1 {
+            return interval;
+        }
+        let shift = PeriodComp::months(self.fiscal_year_start as i64 - 1);
+        Interval {
+            start: interval.start + shift,
+            end: interval.end.map(|end| end + shift),
+            grain: interval.grain,
+        }
+    }
+
+    /// Moves a datetime with a year given with two digits, read by the grammars in the century
+    /// starting in 1950, to the century of the two-digit year pivot
+    fn two_digit_year_interval(
+        &self,
+        two_digit_year: bool,
+        interval: Interval<Local>,
+    ) -> Interval<Local> {
+        if !two_digit_year {
+            return interval;
+        }
+        let year = interval.start.year();
+        let pivot = self.two_digit_year_pivot.0 as i32;
+        let shift = PeriodComp::years((pivot + (year - pivot).rem_euclid(100) - year) as i64);
+        if shift.quantity == 0 {
+            return interval;
+        }
+        Interval {
+            start: interval.start + shift,
+            end: interval.end.map(|end| end + shift),
+            grain: interval.grain,
+        }
+    }
+
+    /// Replaces the interval of a holiday by the one of the holiday calendar for the same year
+    fn holiday_interval(
+        &self,
+        holiday: Option<&str>,
+        interval: Interval<Local>,
+    ) -> Interval<Local> {
+        match (&self.holidays, holiday) {
+            (Some(provider), Some(name)) => provider
+                .resolve(name, interval.start.year())
+                .unwrap_or(interval),
+            _ => interval,
+        }
+    }
+
+    /// Moves a season found between its astronomical bounds to the three months starting in the
+    /// same month with the meteorological convention, e.g. from june 1 to september 1 for the
+    /// summer
+    fn season_interval(
+        &self,
+        season: Option<Season>,
+        interval: Interval<Local>,
+    ) -> Interval<Local> {
+        let season = match season {
+            Some(season) if self.season_convention == SeasonConvention::Meteorological => season,
+            _ => return interval,
+        };
+        let season = match self.hemisphere {
+            Hemisphere::Northern => season,
+            Hemisphere::Southern => season.opposite(),
+        };
+        let month = match season {
+            Season::Spring => 3,
+            Season::Summer => 6,
+            Season::Fall => 9,
+            Season::Winter => 12,
+        };
+        let start = Moment::ymd(interval.start.year(), month, 1);
+        Interval {
+            start,
+            end: Some(start + PeriodComp::months(3)),
+            grain: interval.grain,
+        }
+    }
+
+    /// Office hours of the day starting the interval, or the rest of this day after them. When
+    /// the interval is finer than a day, e.g. for "after hours tonight", it is narrowed to them,
+    /// and kept as it is if they don't overlap.
+    fn office_hours_interval(
+        &self,
+        office_hours: Option<OfficeHours>,
+        interval: Interval<Local>,
+    ) -> Interval<Local> {
+        let office_hours = match office_hours {
+            Some(office_hours) => office_hours,
+            None => return interval,
+        };
+        let day = interval.start.round_to(Grain::Day);
+        let at = |(hour, minute): (u32, u32)| {
+            day + PeriodComp::hours(hour as i64) + PeriodComp::minutes(minute as i64)
+        };
+        let (start, end) = match office_hours {
+            OfficeHours::During => (at(self.office_hours.start), at(self.office_hours.end)),
+            OfficeHours::After => (at(self.office_hours.end), day + PeriodComp::days(1)),
+        };
+        let window = Interval {
+            start,
+            end: Some(end),
+            grain: Grain::Minute,
+        };
+        if interval.grain <= Grain::Day {
+            window
+        } else {
+            window.intersect(interval).unwrap_or(interval)
+        }
+    }
+
+    /// Whether the datetime is computed from the reference at a finer grain than the one of the
+    /// reference, and must not be resolved with `FuzzyReferencePolicy::Reject`
+    fn rejects_fuzzy_reference(&self, datetime_value: &DatetimeValue) -> bool {
+        self.fuzzy_reference == FuzzyReferencePolicy::Reject
+            && datetime_value
+                .relative_grain
+                .map(|grain| grain > self.ctx.reference.grain)
+                .unwrap_or(false)
+    }
+
+    /// Whether a year given by a lone number is out of the preferred range of years, see
+    /// `ResolverContext::prefer_year_range`
+    fn rejects_lone_year(&self, datetime_value: &DatetimeValue) -> bool {
+        match (&self.prefer_year_range, &datetime_value.form) {
+            (&Some(ref range), &Form::Year(year)) => {
+                datetime_value.lone_year && !range.contains(&year)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the interval intersecting the reference must be skipped, i.e. "friday" on a friday
+    fn not_immediate(&self, datetime_value: &DatetimeValue) -> bool {
+        match datetime_value.form {
+            Form::DayOfWeek { not_immediate, .. } => {
+                not_immediate && self.weekday_policy != WeekdayPolicy::IncludeToday
+            }
+            ref form => form.not_immediate().unwrap_or(false),
+        }
+    }
+
+    /// Moves "next <day-of-week>" to the week after the one of the reference with
+    /// `WeekdayPolicy::NextStrict`
+    fn next_weekday_interval(
+        &self,
+        next_weekday: bool,
+        interval: Interval<Local>,
+    ) -> Interval<Local> {
+        if !next_weekday || self.weekday_policy != WeekdayPolicy::NextStrict {
+            return interval;
+        }
+        let reference = self.ctx.reference.start;
+        let days_from_monday = weekday(reference.year(), reference.month(), reference.day()) as i64;
+        let next_week = Moment::ymd(reference.year(), reference.month(), reference.day())
+            + PeriodComp::days(7 - days_from_monday);
+        if interval.start < next_week {
+            interval + PeriodComp::days(7)
+        } else {
+            interval
+        }
+    }
+
+    /// Extends a month or a year to the whole period with `PartialDatePolicy::Interval`, unless
+    /// the datetime is bounded or recurring
+    fn partial_date_interval(
+        &self,
+        bounded_or_recurring: bool,
+        interval: Interval<Local>,
+    ) -> Interval<Local> {
+        if self.partial_date_policy == PartialDatePolicy::Interval
+            && !bounded_or_recurring
+            && interval.end.is_none()
+            && (interval.grain == Grain::Month || interval.grain == Grain::Year)
+        {
+            Interval {
+                end: Some(interval.end_moment()),
+                ..interval
+            }
+        } else {
+            interval
+        }
+    }
+
+    /// Moves the day of a datetime counted in working days, which was walked as calendar days
+    fn business_day_interval(
+        &self,
+        business_days: Option<i64>,
+        interval: Interval<Local>,
+    ) -> Interval<Local> {
+        let n = match business_days {
+            Some(n) => n,
+            None => return interval,
+        };
+        let reference = self.walking_context().reference.start;
+        let today = Moment::ymd(reference.year(), reference.month(), reference.day());
+        match self.working_days.add_working_days(today, n) {
+            Some(working_day) => {
+                let calendar_day = today + PeriodComp::days(n);
+                // Rounded to absorb daylight saving time changes
+                let offset = (working_day.timestamp() - calendar_day.timestamp() + 12 * 3600)
+                    .div_euclid(24 * 3600);
+                interval + PeriodComp::days(offset)
+            }
+            None => interval,
+        }
+    }
+
+    /// Whether an interval ends before the min interval or starts after the max one
+    fn is_out_of_range(&self, interval: &Interval<Local>) -> bool {
+        interval.end_moment() <= self.ctx.min.start || self.ctx.max.start < interval.start
+    }
+
+    /// Applies the out of range policy to an interval, `None` if it is rejected
+    fn in_range_interval(&self, interval: Interval<Local>) -> Option<Interval<Local>> {
+        if !self.is_out_of_range(&interval) {
+            return Some(interval);
+        }
+        match self.out_of_range_policy {
+            OutOfRangePolicy::Reject => None,
+            OutOfRangePolicy::Clamp if interval.start < self.ctx.min.start => Some(self.ctx.min),
+            OutOfRangePolicy::Clamp => Some(self.ctx.max),
+        }
+    }
+
+    /// Returns up to `max` resolutions of the given dimension, the best one first. For datetimes
+    /// the next occurrences found by the walker follow, e.g. "friday" gives this coming friday
+    /// then the following ones.
+    pub fn resolve_candidates(&self, dim: &Dimension, max: usize) -> Vec<Output> {
+        if max == 0 {
+            return vec![];
+        }
+        if let Some(output) = self.custom_output(dim) {
+            return vec![output];
+        }
+        if let &Dimension::Datetime(ref datetime_value) = dim {
+            if let Some(swapped) = self.in_date_order(datetime_value) {
+                return self.resolve_candidates(&Dimension::Datetime(swapped), max);
+            }
+            if let Some(southern) = self.in_hemisphere(datetime_value) {
+                return self.resolve_candidates(&Dimension::Datetime(southern), max);
+            }
+        }
         match dim {
+            // The timezone named in the sentence overrides the one of the context
+            &Dimension::Datetime(ref datetime_value)
+                if datetime_value.timezone.is_some() && datetime_value.timezone != self.tz =>
+            {
+                self.in_timezone_of(datetime_value)
+                    .resolve_candidates(dim, max)
+            }
             &Dimension::Datetime(ref datetime_value) => {
-                let mut walker = datetime_value
-                    .constraint
-                    .to_walker(&self.ctx.reference, &self.ctx);
-                walker
-                    .forward
-                    .next()
-                    .and_then(|h| {
-                        if datetime_value.form.not_immediate().unwrap_or(false)
-                            && h.intersect(self.ctx.reference).is_some()
-                        {
-                            walker.forward.next()
-                        } else {
-                            Some(h)
-                        }
-                    })
-                    .or_else(|| walker.backward.next())
-                    .map(|interval| {
-                        if let Some(bounded_direction) = datetime_value.direction {
-                            let anchor = match bounded_direction.bound {
-                                Bound::Start => interval.start,
-                                Bound::End { only_interval } if only_interval => {
-                                    interval.end.unwrap_or(interval.start)
-                                }
-                                Bound::End { .. } => interval.end_moment(),
-                            };
-                            let datetime_output_value = DatetimeOutput {
-                                moment: anchor,
-                                grain: interval.grain,
-                                precision: datetime_value.precision,
-                                latent: datetime_value.latent,
-                                datetime_kind: datetime_value.datetime_kind,
-                            };
-                            match bounded_direction.direction {
-                                Direction::After => {
-                                    let datetime_interval_output_value = DatetimeIntervalOutput {
-                                        interval_kind: DatetimeIntervalKind::After(
-                                            datetime_output_value,
-                                        ),
-                                        datetime_kind: datetime_output_value.datetime_kind,
-                                    };
-                                    Output::DatetimeInterval(datetime_interval_output_value)
-                                }
-                                Direction::Before => {
-                                    let datetime_interval_output_value = DatetimeIntervalOutput {
-                                        interval_kind: DatetimeIntervalKind::Before(
-                                            datetime_output_value,
-                                        ),
-                                        datetime_kind: datetime_output_value.datetime_kind,
-                                    };
-                                    Output::DatetimeInterval(datetime_interval_output_value)
-                                }
-                            }
-                        } else if let Some(end) = interval.end {
-                            if datetime_value.datetime_kind == DatetimeKind::Date
-                                || datetime_value.datetime_kind == DatetimeKind::Time
-                            {
-                                warn!(
-                                    "{:?} kind with an interval - {:?}",
-                                    datetime_value.datetime_kind, interval
-                                );
-                            }
-                            let datetime_interval_output_value = DatetimeIntervalOutput {
-                                interval_kind: DatetimeIntervalKind::Between {
-                                    start: interval.start,
-                                    end: end,
-                                    precision: datetime_value.precision,
-                                    latent: datetime_value.latent,
-                                },
-                                datetime_kind: datetime_value.datetime_kind,
-                            };
-                            Output::DatetimeInterval(datetime_interval_output_value)
-                        } else {
-                            let datetime_output_value = DatetimeOutput {
-                                moment: interval.start,
-                                grain: interval.grain,
-                                precision: datetime_value.precision,
-                                latent: datetime_value.latent,
-                                datetime_kind: datetime_value.datetime_kind,
-                            };
-                            Output::Datetime(datetime_output_value)
-                        }
+                let mut intervals = self
+                    .datetime_candidate_intervals(datetime_value, max)
+                    .into_iter()
+                    .filter_map(|interval| {
+                        self.in_range_interval(self.adjust_interval(datetime_value, interval))
                     })
+                    .collect::<Vec<_>>();
+                // Several candidates can be clamped to the same interval
+                intervals.dedup();
+                intervals
+                    .into_iter()
+                    .map(|interval| self.datetime_output(datetime_value, interval))
+                    .collect()
+            }
+            _ => self.resolve_value(dim).into_iter().collect(),
+        }
+    }
+
+    /// Resolves the given dimension as `resolve` does, telling why it couldn't be resolved. A
+    /// datetime with an interval resolved for a date or a time kind is given with
+    /// `ResolveError::KindIntervalMismatch`, `resolve` keeps it.
+    pub fn try_resolve(&self, dim: &Dimension) -> Result<Output, ResolveError> {
+        if let Some(output) = self.custom_output(dim) {
+            return Ok(output);
+        }
+        let datetime_value = match dim {
+            &Dimension::Datetime(ref datetime_value) => datetime_value,
+            _ => {
+                return self
+                    .resolve_value(dim)
+                    .ok_or_else(|| ResolveError::Unsupported(dim.kind()))
+            }
+        };
+        if let Some(swapped) = self.in_date_order(datetime_value) {
+            return self.try_resolve(&Dimension::Datetime(swapped));
+        }
+        if let Some(southern) = self.in_hemisphere(datetime_value) {
+            return self.try_resolve(&Dimension::Datetime(southern));
+        }
+        if datetime_value.timezone.is_some() && datetime_value.timezone != self.tz {
+            return self.in_timezone_of(datetime_value).try_resolve(dim);
+        }
+        if self.rejects_fuzzy_reference(datetime_value) {
+            return Err(ResolveError::FuzzyReference(self.ctx.reference.grain));
+        }
+        if self.rejects_lone_year(datetime_value) {
+            return Err(ResolveError::ImplausibleYear);
+        }
+        let interval = match self
+            .datetime_candidate_intervals(datetime_value, 1)
+            .into_iter()
+            .next()
+        {
+            Some(interval) => self.adjust_interval(datetime_value, interval),
+            None if self.ctx.reference.start < self.ctx.min.start
+                || self.ctx.max.start < self.ctx.reference.start =>
+            {
+                return Err(ResolveError::OutOfRange {
+                    reference: self.ctx.reference.start,
+                    min: self.ctx.min.start,
+                    max: self.ctx.max.start,
+                })
+            }
+            None => return Err(ResolveError::NoMatchInRange),
+        };
+        let interval = match self.in_range_interval(interval) {
+            Some(interval) => interval,
+            None => {
+                return Err(ResolveError::DatetimeOutOfRange {
+                    start: interval.start,
+                    min: self.ctx.min.start,
+                    max: self.ctx.max.start,
+                })
+            }
+        };
+        let output = self.datetime_output(datetime_value, interval);
+        if is_kind_interval_mismatch(datetime_value, &interval) {
+            return Err(ResolveError::KindIntervalMismatch {
+                kind: datetime_value.datetime_kind,
+                output,
+            });
+        }
+        Ok(output)
+    }
+
+    /// Output of the first custom resolver of the kind of the dimension which resolves it
+    fn custom_output(&self, dim: &Dimension) -> Option<Output> {
+        self.custom_resolvers
+            .iter()
+            .filter(|resolver| resolver.dimension_kind() == dim.kind())
+            .filter_map(|resolver| resolver.resolve(dim))
+            .next()
+    }
+
+    /// Same datetime with its day and month swapped, if the grammar read it in the other of the
+    /// month first and day first orders than the one of the context
+    fn in_date_order(&self, datetime_value: &DatetimeValue) -> Option<DatetimeValue> {
+        match (self.date_order, &datetime_value.swapped_date) {
+            (Some(DateOrder::MonthDayYear), &Some((DateOrder::DayMonthYear, ref swapped)))
+            | (Some(DateOrder::DayMonthYear), &Some((DateOrder::MonthDayYear, ref swapped))) => {
+                Some(DatetimeValue {
+                    constraint: swapped.clone(),
+                    swapped_date: None,
+                    ..datetime_value.clone()
+                })
             }
-            &Dimension::Number(ref number) => match number {
-                &NumberValue::Integer(ref v) => Some(Output::Integer(IntegerOutput(v.value))),
-                &NumberValue::Float(ref v) => Some(Output::Float(FloatOutput(v.value))),
+            _ => None,
+        }
+    }
+
+    /// Same season with the constraint of the southern hemisphere, if it is the one of the
+    /// context
+    fn in_hemisphere(&self, datetime_value: &DatetimeValue) -> Option<DatetimeValue> {
+        match (self.hemisphere, &datetime_value.southern_season) {
+            (Hemisphere::Southern, &Some(ref southern)) => Some(DatetimeValue {
+                constraint: southern.clone(),
+                southern_season: None,
+                ..datetime_value.clone()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Same context, resolving in the timezone named by the datetime if any
+    fn in_timezone_of(&self, datetime_value: &DatetimeValue) -> ResolverContext {
+        ResolverContext {
+            tz: datetime_value.timezone.or(self.tz),
+            ..self.clone()
+        }
+    }
+
+    /// Moves an interval found by the walker as given by the holidays, the working days and the
+    /// weekday policy
+    fn adjust_interval(
+        &self,
+        datetime_value: &DatetimeValue,
+        interval: Interval<Local>,
+    ) -> Interval<Local> {
+        let interval = self.two_digit_year_interval(datetime_value.two_digit_year, interval);
+        let interval = self.fiscal_quarter_interval(datetime_value.fiscal_quarter, interval);
+        let interval = self.holiday_interval(datetime_value.holiday, interval);
+        let interval = holiday_offset_interval(datetime_value.holiday_offset, interval);
+        let interval = self.season_interval(datetime_value.season, interval);
+        let interval = self.office_hours_interval(datetime_value.office_hours, interval);
+        let interval = part_of_period_interval(datetime_value.part_of_period, interval);
+        let bounded_or_recurring =
+            datetime_value.direction.is_some() || datetime_value.recurrence.is_some();
+        let interval = self.partial_date_interval(bounded_or_recurring, interval);
+        let interval = self.business_day_interval(datetime_value.business_days, interval);
+        let interval = self.next_weekday_interval(datetime_value.next_weekday, interval);
+        offset_interval(datetime_value.offset.as_ref(), interval)
+    }
+
+    /// Resolves the given dimension like `resolve`, returning `None` for the dimensions filtered
+    /// out by the options.
+    pub fn resolve_with_options(&self, dim: &Dimension, options: ResolveOptions) -> Option<Output> {
+        if options.drop_latent && dim.latent() {
+            return None;
+        }
+        self.resolve(dim)
+    }
+
+    /// Resolves each of the given dimensions, grouping the outputs by type. The dimensions which
+    /// can't be resolved are left out.
+    pub fn resolve_batch(&self, dims: &[Dimension]) -> ResolvedSet {
+        dims.iter().filter_map(|dim| self.resolve(dim)).collect()
+    }
+
+    /// Resolves the given dimension like `resolve`, giving the datetimes in UTC, e.g. to store
+    /// them. See `Output::to_utc`.
+    pub fn resolve_utc(&self, dim: &Dimension) -> Option<Output> {
+        self.resolve(dim).map(|output| output.to_utc())
+    }
+
+    /// Resolves the given dimension as if the reference was `reference`, keeping the min and max
+    /// intervals, the timezone and the holidays of this context.
+    pub fn resolve_at(&self, dim: &Dimension, reference: Interval<Local>) -> Option<Output> {
+        let context = ResolverContext {
+            ctx: Context::new(reference, self.ctx.min, self.ctx.max)
+                .with_week_start(self.ctx.week_start),
+            ..self.clone()
+        };
+        context.resolve(dim)
+    }
+
+    /// Walks the datetime constraint to find up to `max` intervals, forward or backward as given
+    /// by the tie-break policy. When the datetime must not be immediate, the interval
+    /// intersecting the reference is kept as the second candidate.
+    fn datetime_candidate_intervals(
+        &self,
+        datetime_value: &DatetimeValue,
+        max: usize,
+    ) -> Vec<Interval<Local>> {
+        if self.rejects_fuzzy_reference(datetime_value) || self.rejects_lone_year(datetime_value) {
+            return vec![];
+        }
+        let ctx = self.walking_context();
+        let not_immediate = self.not_immediate(datetime_value);
+        // Both the morning and the afternoon hours are needed to choose between them
+        let ambiguous_hour = self.ambiguous_hour_policy != AmbiguousHourPolicy::PreferNext
+            && datetime_value.form.is_12_clock();
+        let walked = if ambiguous_hour {
+            cmp::max(max, 2)
+        } else {
+            max
+        };
+        let key = match (&self.cache, datetime_value.constraint.structure_hash()) {
+            (Some(_), Some(constraint)) => Some(CacheKey {
+                constraint,
+                not_immediate,
+                upcoming: datetime_value.upcoming,
+                tie_break: self.tie_break,
+                max: walked,
+                reference: interval_key(&ctx.reference),
+                min: interval_key(&ctx.min),
+                max_interval: interval_key(&ctx.max),
+                week_start: ctx.week_start.num_days_from_monday(),
+            }),
+            _ => None,
+        };
+        let cached = match (&self.cache, &key) {
+            (Some(cache), Some(key)) => cache.get(key),
+            _ => None,
+        };
+        let intervals = match cached {
+            Some(intervals) => intervals,
+            None => {
+                let intervals =
+                    self.walk_candidate_intervals(datetime_value, &ctx, not_immediate, walked);
+                if let (Some(cache), Some(key)) = (&self.cache, key) {
+                    cache.insert(key, intervals.clone());
+                }
+                intervals
+            }
+        };
+        let intervals = if ambiguous_hour {
+            self.prefer_half_of_day(intervals, max)
+        } else {
+            intervals
+        };
+        intervals
+            .into_iter()
+            .map(|interval| self.from_walking_interval(interval))
+            .collect()
+    }
+
+    /// Puts first the intervals on the wall clock in the half of the day preferred by the
+    /// ambiguous hour policy, keeping up to `max` of them
+    fn prefer_half_of_day(
+        &self,
+        intervals: Vec<Interval<Local>>,
+        max: usize,
+    ) -> Vec<Interval<Local>> {
+        let pm = self.ambiguous_hour_policy == AmbiguousHourPolicy::PreferPm;
+        let (mut preferred, others): (Vec<_>, Vec<_>) = intervals
+            .into_iter()
+            .partition(|interval| (interval.start.hour() >= 12) == pm);
+        preferred.extend(others);
+        preferred.truncate(max);
+        preferred
+    }
+
+    /// Intervals of `datetime_candidate_intervals`, on the wall clock of the walking context
+    fn walk_candidate_intervals(
+        &self,
+        datetime_value: &DatetimeValue,
+        ctx: &Context<Local>,
+        not_immediate: bool,
+        max: usize,
+    ) -> Vec<Interval<Local>> {
+        let mut walker = datetime_value.constraint.to_walker(&ctx.reference, ctx);
+        let mut forward = Vec::with_capacity(max);
+        let mut immediate = None;
+        while forward.len() < max {
+            match walker.forward.next() {
+                Some(interval) => {
+                    let skipped = (not_immediate && interval.intersect(ctx.reference).is_some())
+                        || (datetime_value.upcoming && interval.start < ctx.reference.start);
+                    if skipped && forward.is_empty() && immediate.is_none() {
+                        immediate = Some(interval);
+                    } else {
+                        forward.push(interval);
+                    }
+                }
+                None => break,
+            }
+        }
+        let backward = if self.tie_break == TieBreak::PreferFuture && !forward.is_empty() {
+            vec![]
+        } else {
+            walker.backward.take(max).collect::<Vec<_>>()
+        };
+        let prefer_past = match (forward.first(), backward.first()) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(future), Some(past)) => match self.tie_break {
+                TieBreak::PreferFuture => false,
+                TieBreak::PreferPast => true,
+                TieBreak::PreferClosest => {
+                    let reference = ctx.reference.start;
+                    reference.timestamp() - past.end_moment().timestamp()
+                        < future.start.timestamp() - reference.timestamp()
+                }
             },
-            &Dimension::Ordinal(ref ordinal) => Some(Output::Ordinal(OrdinalOutput(ordinal.value))),
+        };
+        let mut intervals = if prefer_past { backward } else { forward };
+        if let Some(immediate) = immediate {
+            // The immediate interval is never the best answer
+            if !intervals.is_empty() {
+                intervals.insert(1, immediate);
+                intervals.truncate(max);
+            }
+        }
+        intervals
+    }
+
+    /// Returns the forward intervals matching the datetime lazily, without building outputs.
+    /// With `apply_not_immediate`, the interval intersecting the reference is skipped if the
+    /// datetime form must not be immediate, as it is done by `resolve`.
+    pub fn datetime_intervals(
+        &self,
+        datetime_value: &DatetimeValue,
+        apply_not_immediate: bool,
+    ) -> impl Iterator<Item = Interval<Local>> {
+        let swapped = self.in_date_order(datetime_value);
+        let datetime_value = swapped.as_ref().unwrap_or(datetime_value);
+        let southern = self.in_hemisphere(datetime_value);
+        let datetime_value = southern.as_ref().unwrap_or(datetime_value);
+        let resolver = self.in_timezone_of(datetime_value);
+        let ctx = resolver.walking_context();
+        let skip_immediate = apply_not_immediate && self.not_immediate(datetime_value);
+        let upcoming = apply_not_immediate && datetime_value.upcoming;
+        let rejected =
+            self.rejects_fuzzy_reference(datetime_value) || self.rejects_lone_year(datetime_value);
+        let owned_value = datetime_value.clone();
+        datetime_value
+            .constraint
+            .to_walker(&ctx.reference, &ctx)
+            .forward
+            .into_iter()
+            .take_while(move |_| !rejected)
+            .enumerate()
+            .filter(move |&(index, interval)| {
+                index > 0
+                    || !((skip_immediate && interval.intersect(ctx.reference).is_some())
+                        || (upcoming && interval.start < ctx.reference.start))
+            })
+            .map(move |(_, interval)| {
+                resolver.adjust_interval(&owned_value, resolver.from_walking_interval(interval))
+            })
+    }
+
+    fn datetime_output(&self, datetime_value: &DatetimeValue, interval: Interval<Local>) -> Output {
+        if let (Some(ref period), None) = (&datetime_value.recurrence, datetime_value.direction) {
+            // Recurrences are given by the start of their first occurrence
+            let first = DatetimeOutput {
+                moment: interval.start,
+                grain: interval.grain,
+                precision: datetime_value.precision,
+                latent: datetime_value.latent,
+                datetime_kind: datetime_value.datetime_kind,
+                timezone: self.tz,
+                part_of_period: datetime_value.part_of_period,
+                was_12_hour: datetime_value.was_12_hour,
+            };
+            Output::DatetimeRecurring(DatetimeRecurringOutput {
+                first,
+                period: period.clone(),
+                interval: datetime_value.recurrence_interval,
+            })
+        } else if let Some(bounded_direction) = datetime_value.direction {
+            // The end of an instant is the instant itself, excluded by "after 5pm" and included
+            // by "until 5pm". The other anchors are the first moment of the datetime, or the
+            // first one after it, which are included after them and excluded before them.
+            let instant_end = match bounded_direction.bound {
+                Bound::End { only_interval } => only_interval && interval.end.is_none(),
+                Bound::Start => false,
+            };
+            let anchor = match bounded_direction.bound {
+                Bound::Start => interval.start,
+                Bound::End { only_interval } if only_interval => {
+                    interval.end.unwrap_or(interval.start)
+                }
+                Bound::End { .. } => interval.end_moment(),
+            };
+            let datetime_output_value = DatetimeOutput {
+                moment: anchor,
+                grain: interval.grain,
+                precision: datetime_value.precision,
+                latent: datetime_value.latent,
+                datetime_kind: datetime_value.datetime_kind,
+                timezone: self.tz,
+                part_of_period: datetime_value.part_of_period,
+                was_12_hour: datetime_value.was_12_hour,
+            };
+            match bounded_direction.direction {
+                Direction::After => {
+                    let datetime_interval_output_value = DatetimeIntervalOutput {
+                        interval_kind: DatetimeIntervalKind::After {
+                            datetime: datetime_output_value,
+                            inclusive: !instant_end,
+                        },
+                        datetime_kind: datetime_output_value.datetime_kind,
+                        timezone: self.tz,
+                        part_of_period: datetime_value.part_of_period,
+                    };
+                    Output::DatetimeInterval(datetime_interval_output_value)
+                }
+                Direction::Before => {
+                    let datetime_interval_output_value = DatetimeIntervalOutput {
+                        interval_kind: DatetimeIntervalKind::Before {
+                            datetime: datetime_output_value,
+                            inclusive: instant_end,
+                        },
+                        datetime_kind: datetime_output_value.datetime_kind,
+                        timezone: self.tz,
+                        part_of_period: datetime_value.part_of_period,
+                    };
+                    Output::DatetimeInterval(datetime_interval_output_value)
+                }
+            }
+        } else if let Some(end) = interval.end {
+            if is_kind_interval_mismatch(datetime_value, &interval) {
+                warn!(
+                    "{:?} kind with an interval - {:?}",
+                    datetime_value.datetime_kind, interval
+                );
+            }
+            let end = wrap_midnight(interval, end);
+            let datetime_interval_output_value = DatetimeIntervalOutput {
+                interval_kind: DatetimeIntervalKind::Between {
+                    start: interval.start,
+                    end: end,
+                    grain: interval.grain,
+                    start_grain: cmp::min(datetime_value.constraint.grain_left(), interval.grain),
+                    end_grain: cmp::min(datetime_value.constraint.grain_right(), interval.grain),
+                    precision: datetime_value.precision,
+                    latent: datetime_value.latent,
+                },
+                datetime_kind: datetime_value.datetime_kind,
+                timezone: self.tz,
+                part_of_period: datetime_value.part_of_period,
+            };
+            Output::DatetimeInterval(datetime_interval_output_value)
+        } else {
+            let datetime_output_value = DatetimeOutput {
+                moment: interval.start,
+                grain: interval.grain,
+                precision: datetime_value.precision,
+                latent: datetime_value.latent,
+                datetime_kind: datetime_value.datetime_kind,
+                timezone: self.tz,
+                part_of_period: datetime_value.part_of_period,
+                was_12_hour: datetime_value.was_12_hour,
+            };
+            Output::Datetime(datetime_output_value)
+        }
+    }
+
+    /// Resolves the dimensions which don't need to walk through time
+    fn resolve_value(&self, dim: &Dimension) -> Option<Output> {
+        match dim {
+            &Dimension::Number(ref number) => match NumericContext.resolve_number(number) {
+                NumericOutput::Float(float) => Some(Output::Float(match self.float_precision {
+                    Some(decimals) => float.round(decimals),
+                    None => float,
+                })),
+                output => Some(output.into()),
+            },
+            &Dimension::Ordinal(ref ordinal) => {
+                Some(Output::Ordinal(NumericContext.resolve_ordinal(ordinal)))
+            }
+            &Dimension::OrdinalRange(ref range) => Some(Output::OrdinalRange(OrdinalRangeOutput {
+                start: range.start,
+                end: range.end,
+            })),
             &Dimension::AmountOfMoney(ref aom) => {
                 Some(Output::AmountOfMoney(AmountOfMoneyOutput {
                     value: aom.value,
                     precision: aom.precision,
                     unit: aom.unit,
+                    currency: aom.unit.and_then(Currency::from_unit),
                 }))
             }
             &Dimension::Temperature(ref temp) => Some(Output::Temperature(TemperatureOutput {
                 value: temp.value,
                 unit: temp.unit,
                 latent: temp.latent,
+                qualifier: temp.qualifier,
             })),
+            &Dimension::TemperatureRange(ref range) => {
+                let bound = |temp: &TemperatureValue| TemperatureOutput {
+                    value: temp.value,
+                    unit: temp.unit,
+                    latent: false,
+                    qualifier: None,
+                };
+                // A scale given with one bound is preferred to plain degrees given with the other
+                let unit = [range.low.unit, range.high.unit]
+                    .iter()
+                    .filter_map(|unit| *unit)
+                    .find(|unit| TemperatureUnit::from_name(unit).is_some())
+                    .or_else(|| range.unit());
+                let (low, high) = match unit.and_then(TemperatureUnit::from_name) {
+                    Some(scale) => (
+                        bound(&range.low).to_unit(scale),
+                        bound(&range.high).to_unit(scale),
+                    ),
+                    None => (bound(&range.low), bound(&range.high)),
+                };
+                Some(Output::TemperatureRange(TemperatureRangeOutput {
+                    low: low.value,
+                    high: high.value,
+                    unit,
+                }))
+            }
             &Dimension::Duration(ref duration) => Some(Output::Duration(DurationOutput {
                 period: duration.period.clone(),
                 precision: duration.precision,
             })),
-            &Dimension::Percentage(ref percentage) => {
-                Some(Output::Percentage(PercentageOutput(percentage.0)))
+            &Dimension::Percentage(ref percentage) => Some(Output::Percentage(
+                NumericContext.resolve_percentage(percentage),
+            )),
+            &Dimension::Quantity(ref quantity) => Some(Output::Quantity(QuantityOutput {
+                value: quantity.value,
+                unit: quantity.unit.clone(),
+                product: quantity.product.clone(),
+            })),
+            &Dimension::PhoneNumber(ref phone_number) => Some(Output::PhoneNumber(
+                PhoneNumberOutput(phone_number.0.clone()),
+            )),
+            &Dimension::Age(ref age) => Some(Output::Age(AgeOutput {
+                value: age.value,
+                unit: age.unit,
+            })),
+            &Dimension::NumberRange(ref range) => Some(Output::NumberRange(NumberRangeOutput {
+                low: range.low,
+                high: range.high,
+                low_inclusive: range.low_inclusive,
+                high_inclusive: range.high_inclusive,
+            })),
+            &Dimension::NumberComparison(ref comparison) => {
+                Some(Output::NumberComparison(NumberComparisonOutput {
+                    op: comparison.op,
+                    value: comparison.value,
+                }))
             }
             _ => None,
         }
     }
 }
+
+/// Reason why a dimension couldn't be resolved, see `ResolverContext::try_resolve`
+#[derive(Debug, Fail)]
+pub enum ResolveError {
+    /// The dimension is only used while parsing, e.g. a cycle or a unit of duration
+    #[fail(display = "{:?} dimensions can't be resolved", _0)]
+    Unsupported(DimensionKind),
+    /// No datetime was found by walking between the min and max intervals of the context
+    #[fail(display = "No match between the min and max intervals")]
+    NoMatchInRange,
+    /// The reference is out of the min and max intervals of the context
+    #[fail(display = "Reference {} is not between {} and {}", reference, min, max)]
+    OutOfRange {
+        reference: Moment<Local>,
+        min: Moment<Local>,
+        max: Moment<Local>,
+    },
+    /// The datetime was found out of the min and max intervals of the context, which is rejected
+    /// by `OutOfRangePolicy::Reject`
+    #[fail(display = "Datetime {} is not between {} and {}", start, min, max)]
+    DatetimeOutOfRange {
+        start: Moment<Local>,
+        min: Moment<Local>,
+        max: Moment<Local>,
+    },
+    /// The datetime is computed from the reference at a finer grain than the one of the reference,
+    /// which is rejected by `FuzzyReferencePolicy::Reject`
+    #[fail(display = "The reference is only known at the {:?} grain", _0)]
+    FuzzyReference(Grain),
+    /// The datetime is a year given by a lone number out of the range of
+    /// `ResolverContext::prefer_year_range`, the number being rather a count
+    #[fail(display = "A lone number out of the preferred years is not a year")]
+    ImplausibleYear,
+    /// A date or a time was resolved as an interval, the output is still given
+    #[fail(display = "{:?} kind resolved as an interval - {:?}", kind, output)]
+    KindIntervalMismatch { kind: DatetimeKind, output: Output },
+}
+
+/// Error returned by `ResolverContextBuilder::build`
+#[derive(Debug, Fail)]
+pub enum ResolverContextError {
+    #[fail(display = "Reference {} is not between {} and {}", reference, min, max)]
+    ReferenceOutOfRange {
+        reference: Moment<Local>,
+        min: Moment<Local>,
+        max: Moment<Local>,
+    },
+}
+
+/// Builder of `ResolverContext`, see `ResolverContext::builder`. The reference defaults to now,
+/// and the min and max intervals to the 1st of January `window` years around it, 70 by default.
+/// As with `ResolverContext::for_reference`, the default min and max are restricted to
+/// `local_year_range`.
+#[derive(Debug, Clone)]
+pub struct ResolverContextBuilder {
+    reference: Option<Interval<Local>>,
+    min: Option<Interval<Local>>,
+    max: Option<Interval<Local>>,
+    tz: Option<Tz>,
+    window: i32,
+}
+
+impl Default for ResolverContextBuilder {
+    fn default() -> ResolverContextBuilder {
+        ResolverContextBuilder {
+            reference: None,
+            min: None,
+            max: None,
+            tz: None,
+            window: 70,
+        }
+    }
+}
+
+impl ResolverContextBuilder {
+    pub fn reference(self, reference: Interval<Local>) -> ResolverContextBuilder {
+        ResolverContextBuilder {
+            reference: Some(reference),
+            ..self
+        }
+    }
+
+    pub fn min(self, min: Interval<Local>) -> ResolverContextBuilder {
+        ResolverContextBuilder {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    pub fn max(self, max: Interval<Local>) -> ResolverContextBuilder {
+        ResolverContextBuilder {
+            max: Some(max),
+            ..self
+        }
+    }
+
+    pub fn timezone(self, tz: Tz) -> ResolverContextBuilder {
+        ResolverContextBuilder {
+            tz: Some(tz),
+            ..self
+        }
+    }
+
+    /// Number of years around the reference used for the min and max intervals which are not set
+    pub fn window(self, years: i32) -> ResolverContextBuilder {
+        ResolverContextBuilder {
+            window: years,
+            ..self
+        }
+    }
+
+    /// Builds the context, checking that the reference is between the min and max intervals
+    pub fn build(self) -> Result<ResolverContext, ResolverContextError> {
+        let reference = self
+            .reference
+            .unwrap_or_else(|| Interval::starting_at(Moment::now(), Grain::Second));
+        let new_year = |year: i32| {
+            Interval::starting_at(
+                Moment(Local.ymd(year, 1, 1).and_hms(0, 0, 0)),
+                Grain::Second,
+            )
+        };
+        let window = self.window;
+        let (first_year, last_year) = local_year_range();
+        let min = self.min.unwrap_or_else(|| {
+            new_year(::std::cmp::max(reference.start.year() - window, first_year))
+        });
+        let max = self.max.unwrap_or_else(|| {
+            new_year(::std::cmp::min(
+                reference.end_moment().year() + window,
+                last_year,
+            ))
+        });
+        if min.start > reference.start || reference.start > max.start {
+            return Err(ResolverContextError::ReferenceOutOfRange {
+                reference: reference.start,
+                min: min.start,
+                max: max.start,
+            });
+        }
+        Ok(ResolverContext {
+            tz: self.tz,
+            ..ResolverContext::new(reference, min, max)
+        })
+    }
+}
+
+/// End of a span between two times of day which doesn't come after its start, moved to the
+/// following day, e.g. to 2am of the next day for "from 10pm to 2am", or 24 hours later for
+/// "from 10pm to 10pm"
+fn wrap_midnight(interval: Interval<Local>, end: Moment<Local>) -> Moment<Local> {
+    let mut end = end;
+    while end <= interval.start && !interval.grain.is_date_grain() {
+        end = end + PeriodComp::days(1);
+    }
+    end
+}
+
+/// Moves the interval of a holiday to the day relative to it, counted from its last day after it
+/// and from its first day before it, e.g. to the 29th for "the friday after thanksgiving" on
+/// thursday the 28th
+fn holiday_offset_interval(
+    offset: Option<HolidayOffset>,
+    interval: Interval<Local>,
+) -> Interval<Local> {
+    let offset = match offset {
+        Some(offset) => offset,
+        None => return interval,
+    };
+    let first_day = interval.start.round_to(Grain::Day);
+    let last_day = interval
+        .end
+        .map(|end| (end + PeriodComp::days(-1)).round_to(Grain::Day))
+        .filter(|last_day| *last_day > first_day)
+        .unwrap_or(first_day);
+    let day = match offset {
+        HolidayOffset::Days(n) if n >= 0 => last_day + PeriodComp::days(n),
+        HolidayOffset::Days(n) => first_day + PeriodComp::days(n),
+        HolidayOffset::Weekday {
+            weekday: target,
+            after: true,
+        } => {
+            let from = weekday(last_day.year(), last_day.month(), last_day.day()) as i64;
+            let to = target.num_days_from_monday() as i64;
+            last_day + PeriodComp::days((to - from - 1).rem_euclid(7) + 1)
+        }
+        HolidayOffset::Weekday {
+            weekday: target,
+            after: false,
+        } => {
+            let from = weekday(first_day.year(), first_day.month(), first_day.day()) as i64;
+            let to = target.num_days_from_monday() as i64;
+            first_day + PeriodComp::days(-((from - to - 1).rem_euclid(7) + 1))
+        }
+    };
+    Interval::starting_at(day, Grain::Day)
+}
+
+/// Moves the interval by the offset of the datetime, from its end for a positive offset and from
+/// its start for a negative one, e.g. to 16:00 for "2 hours after lunch" or to 11:30 for
+/// "30 minutes before noon". The moved interval is an instant at the finer of the two grains.
+fn offset_interval(offset: Option<&Period>, interval: Interval<Local>) -> Interval<Local> {
+    let offset = match offset {
+        Some(offset) => offset,
+        None => return interval,
+    };
+    let anchor = if offset.coarse_num_millis() < 0 {
+        interval.start
+    } else {
+        interval.end.unwrap_or(interval.start)
+    };
+    let grain = cmp::max(
+        interval.grain,
+        offset.finer_grain().unwrap_or(interval.grain),
+    );
+    Interval::starting_at(anchor + offset, grain)
+}
+
+/// Narrows the interval of a period to the given part of it, see `PartOfPeriod`
+fn part_of_period_interval(
+    part_of_period: Option<PartOfPeriod>,
+    interval: Interval<Local>,
+) -> Interval<Local> {
+    let part_of_period = match part_of_period {
+        Some(part_of_period) => part_of_period,
+        None => return interval,
+    };
+    let unit = match interval.grain {
+        Grain::Century | Grain::Decade => Grain::Year,
+        Grain::Year | Grain::Quarter => Grain::Month,
+        Grain::Month | Grain::Week => Grain::Day,
+        Grain::Day => Grain::Hour,
+        Grain::Hour => Grain::Minute,
+        Grain::Minute => Grain::Second,
+        Grain::Second | Grain::Millisecond => return interval,
+    };
+    let start = interval.start.round_to(unit);
+    let end = interval.end_moment();
+    let units = (1..)
+        .take_while(|&n| start + PeriodComp::new(unit, n) < end)
+        .count() as i64
+        + 1;
+    let tenth = cmp::max(1, (units + 5) / 10);
+    let (from, to) = match (part_of_period, interval.grain) {
+        (PartOfPeriod::Early, Grain::Week) => (0, 2),
+        (PartOfPeriod::Middle, Grain::Week) => (2, 4),
+        (PartOfPeriod::Late, Grain::Week) => (4, units),
+        (PartOfPeriod::Start, _) => (0, tenth),
+        (PartOfPeriod::Early, _) => (0, (units + 1) / 3),
+        (PartOfPeriod::Middle, _) => ((units + 1) / 3, (2 * units + 1) / 3),
+        (PartOfPeriod::Late, _) => ((2 * units + 1) / 3, units),
+        (PartOfPeriod::End, _) => (units - tenth, units),
+    };
+    // In a period of less than three units, the middle is the unit after the start
+    let to = cmp::max(to, from + 1);
+    let from_moment = start + PeriodComp::new(unit, from);
+    if to - from == 1 {
+        Interval::starting_at(from_moment, unit)
+    } else {
+        Interval {
+            start: from_moment,
+            end: Some(start + PeriodComp::new(unit, to)),
+            grain: unit,
+        }
+    }
+}
+
+/// Whether a datetime of the date or time kind was resolved as an interval, which is neither
+/// recurring nor bounded by a direction
+fn is_kind_interval_mismatch(datetime_value: &DatetimeValue, interval: &Interval<Local>) -> bool {
+    // A whole month or year is a legitimate interval for a date, see `PartialDatePolicy`
+    let partial_date = (interval.grain == Grain::Month || interval.grain == Grain::Year)
+        && interval.end == Some(Interval::starting_at(interval.start, interval.grain).end_moment());
+    interval.end.is_some()
+        && !partial_date
+        && datetime_value.recurrence.is_none()
+        && datetime_value.direction.is_none()
+        && datetime_value.part_of_period.is_none()
+        && (datetime_value.datetime_kind == DatetimeKind::Date
+            || datetime_value.datetime_kind == DatetimeKind::Time)
+}
+
+impl ParsingContext<Dimension> for ResolverContext {
+    type O = Output;
+
+    fn resolve(&self, dim: &Dimension) -> Option<Output> {
+        match self.try_resolve(dim) {
+            Ok(output) | Err(ResolveError::KindIntervalMismatch { output, .. }) => Some(output),
+            Err(_) => None,
+        }
+    }
+}
+
+/// Identifies the intervals found by the walker for a datetime constraint in a walking context
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    constraint: u64,
+    not_immediate: bool,
+    upcoming: bool,
+    tie_break: TieBreak,
+    max: usize,
+    reference: IntervalKey,
+    min: IntervalKey,
+    max_interval: IntervalKey,
+    week_start: u32,
+}
+
+/// Start in seconds and nanoseconds, grain and end of an interval
+type IntervalKey = (i64, u32, Grain, Option<(i64, u32)>);
+
+fn interval_key(interval: &Interval<Local>) -> IntervalKey {
+    (
+        interval.start.timestamp(),
+        interval.start.timestamp_subsec_nanos(),
+        interval.grain,
+        interval
+            .end
+            .map(|end| (end.timestamp(), end.timestamp_subsec_nanos())),
+    )
+}
+
+/// Least recently used intervals found by the walker, see `ResolverContext::with_cache`. The
+/// oldest entry is looked for on eviction, which is linear in the capacity.
+struct IntervalCache {
+    capacity: usize,
+    entries: Mutex<CacheEntries>,
+}
+
+#[derive(Default)]
+struct CacheEntries {
+    uses: u64,
+    intervals: HashMap<CacheKey, (u64, Vec<Interval<Local>>)>,
+}
+
+impl IntervalCache {
+    fn new(capacity: usize) -> IntervalCache {
+        IntervalCache {
+            capacity,
+            entries: Mutex::new(CacheEntries::default()),
+        }
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<Vec<Interval<Local>>> {
+        let mut entries = self.entries.lock().ok()?;
+        entries.uses += 1;
+        let uses = entries.uses;
+        entries.intervals.get_mut(key).map(|entry| {
+            entry.0 = uses;
+            entry.1.clone()
+        })
+    }
+
+    fn insert(&self, key: CacheKey, intervals: Vec<Interval<Local>>) {
+        let mut entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        if entries.intervals.len() >= self.capacity && !entries.intervals.contains_key(&key) {
+            let least_recent = entries
+                .intervals
+                .iter()
+                .min_by_key(|&(_, &(uses, _))| uses)
+                .map(|(key, _)| *key);
+            if let Some(least_recent) = least_recent {
+                entries.intervals.remove(&least_recent);
+            }
+        }
+        entries.uses += 1;
+        let uses = entries.uses;
+        entries.intervals.insert(key, (uses, intervals));
+    }
+
+    fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .map(|entries| entries.intervals.len())
+            .unwrap_or(0)
+    }
+}
+
+impl fmt::Debug for IntervalCache {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "IntervalCache(capacity={}, len={})",
+            self.capacity,
+            self.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers;
+    use rustling::AttemptInto;
+
+    fn context() -> ResolverContext {
+        ResolverContext::for_reference(Interval::starting_at(
+            Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ))
+    }
+
+    #[test]
+    fn test_resolve_candidates() {
+        let friday = Dimension::Datetime(helpers::day_of_week(Weekday::Fri).unwrap());
+        let candidates = context()
+            .resolve_candidates(&friday, 3)
+            .into_iter()
+            .map(|output| {
+                let output: DatetimeOutput = output.attempt_into().unwrap();
+                output.moment
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Moment(Local.ymd(2013, 2, 15).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 2, 22).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 3, 1).and_hms(0, 0, 0)),
+            ],
+            candidates
+        );
+        assert_eq!(
+            context().resolve(&friday),
+            context().resolve_candidates(&friday, 1).into_iter().next()
+        );
+        assert!(context().resolve_candidates(&friday, 0).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_all() {
+        let values = vec![
+            Dimension::Datetime(helpers::day_of_week(Weekday::Fri).unwrap()),
+            Dimension::Number(NumberValue::Integer(IntegerValue::new(42).unwrap())),
+            Dimension::Cycle(CycleValue::new(Grain::Day).unwrap()),
+        ];
+        let resolved = context().resolve_all(&values);
+        assert_eq!(3, resolved.len());
+        for (value, output) in values.iter().zip(resolved.iter()) {
+            assert_eq!(&context().resolve(value), output);
+        }
+        assert_eq!(None, resolved[2]);
+        // Datetime values can't be compared
+        let identity = IdentityContext::new().resolve_all(&values[1..]);
+        assert_eq!(
+            values[1..].iter().cloned().map(Some).collect::<Vec<_>>(),
+            identity
+        );
+    }
+
+    #[test]
+    fn test_resolve_negative_numbers() {
+        let minus_five = Dimension::Number(NumberValue::Integer(IntegerValue::new(-5).unwrap()));
+        assert_eq!(
+            Some(Output::Integer(IntegerOutput(-5))),
+            context().resolve(&minus_five)
+        );
+        let minus_three_point_two =
+            Dimension::Number(NumberValue::Float(FloatValue::new(-3.2).unwrap()));
+        assert_eq!(
+            Some(Output::Float(FloatOutput(-3.2))),
+            context().resolve(&minus_three_point_two)
+        );
+    }
+
+    #[test]
+    fn test_float_precision() {
+        let pi = Dimension::Number(NumberValue::Float(FloatValue::new(3.14159).unwrap()));
+        assert_eq!(
+            Some(Output::Float(FloatOutput(3.14159))),
+            context().resolve(&pi)
+        );
+        let rounded = context().with_float_precision(2);
+        assert_eq!(Some(Output::Float(FloatOutput(3.14))), rounded.resolve(&pi));
+        let percentage = Dimension::Percentage(PercentageValue(3.14159));
+        assert_eq!(
+            Some(Output::Percentage(PercentageOutput(3.14159))),
+            rounded.resolve(&percentage)
+        );
+    }
+
+    #[test]
+    fn test_datetime_intervals() {
+        let tuesday = helpers::day_of_week(Weekday::Tue).unwrap();
+        let starts = |apply_not_immediate| {
+            context()
+                .datetime_intervals(&tuesday, apply_not_immediate)
+                .take(2)
+                .map(|interval| interval.start)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![
+                Moment(Local.ymd(2013, 2, 12).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 2, 19).and_hms(0, 0, 0)),
+            ],
+            starts(false)
+        );
+        assert_eq!(
+            vec![
+                Moment(Local.ymd(2013, 2, 19).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 2, 26).and_hms(0, 0, 0)),
+            ],
+            starts(true)
+        );
+    }
+
+    #[test]
+    fn test_resolve_recurring() {
+        let every_friday = Dimension::Datetime(
+            helpers::day_of_week(Weekday::Fri)
+                .unwrap()
+                .recurring(PeriodComp::weeks(1).into()),
+        );
+        let output: DatetimeRecurringOutput = context()
+            .resolve(&every_friday)
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        assert_eq!(
+            Moment(Local.ymd(2013, 2, 15).and_hms(0, 0, 0)),
+            output.first.moment
+        );
+        assert_eq!(Grain::Day, output.first.grain);
+        assert_eq!(Period::from(PeriodComp::weeks(1)), output.period);
+    }
+
+    #[test]
+    fn test_resolve_with_options() {
+        let drop_latent = ResolveOptions { drop_latent: true };
+        // "it's 20"
+        let latent = Dimension::Temperature(TemperatureValue {
+            value: 20.0,
+            unit: None,
+            latent: true,
+            qualifier: None,
+        });
+        // "20 degrees"
+        let explicit = Dimension::Temperature(TemperatureValue {
+            value: 20.0,
+            unit: Some("degree"),
+            latent: false,
+            qualifier: None,
+        });
+        assert!(context()
+            .resolve_with_options(&latent, ResolveOptions::default())
+            .is_some());
+        assert_eq!(None, context().resolve_with_options(&latent, drop_latent));
+        assert_eq!(
+            context().resolve(&explicit),
+            context().resolve_with_options(&explicit, drop_latent)
+        );
+        let latent_datetime = Dimension::Datetime(helpers::hour(9, true).unwrap().latent());
+        assert_eq!(
+            None,
+            context().resolve_with_options(&latent_datetime, drop_latent)
+        );
+        // "20" alone is kept
+        let number = Dimension::Number(NumberValue::Integer(IntegerValue::new(20).unwrap()));
+        assert_eq!(
+            Some(Output::Integer(IntegerOutput(20))),
+            context().resolve_with_options(&number, drop_latent)
+        );
+    }
+
+    #[test]
+    fn test_resolve_business_days() {
+        let friday = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Local.ymd(2013, 2, 15).and_hms(10, 0, 0)),
+            Grain::Second,
+        ));
+        let in_business_days = |n: i64| {
+            Dimension::Datetime(
+                DurationValue::new(PeriodComp::days(n).into())
+                    .business_days()
+                    .in_present()
+                    .unwrap(),
+            )
+        };
+        let day = |context: &ResolverContext, n: i64| {
+            let output: DatetimeOutput = context
+                .resolve(&in_business_days(n))
+                .unwrap()
+                .attempt_into()
+                .unwrap();
+            output.moment
+        };
+        assert_eq!(
+            Moment(Local.ymd(2013, 2, 19).and_hms(0, 0, 0)),
+            day(&friday, 2)
+        );
+        assert_eq!(
+            Moment(Local.ymd(2013, 2, 18).and_hms(0, 0, 0)),
+            day(&friday, 1)
+        );
+        // saturdays are worked, the monday is off
+        let calendar = friday.with_working_days(
+            [true, true, true, true, true, true, false],
+            vec![Interval::ymd(2013, 2, 18)],
+        );
+        assert_eq!(
+            Moment(Local.ymd(2013, 2, 19).and_hms(0, 0, 0)),
+            day(&calendar, 2)
+        );
+        assert_eq!(
+            Moment(Local.ymd(2013, 2, 20).and_hms(0, 0, 0)),
+            day(&calendar, 3)
+        );
+        // 12pm in UTC on thursday is already friday in Kiritimati
+        let kiritimati = ResolverContext::for_reference_in_tz(
+            Interval::starting_at(
+                Moment(Utc.ymd(2013, 2, 14).and_hms(12, 0, 0).with_timezone(&Local)),
+                Grain::Second,
+            ),
+            Tz::Pacific__Kiritimati,
+        );
+        assert_eq!(
+            Tz::Pacific__Kiritimati.ymd(2013, 2, 18).and_hms(0, 0, 0),
+            day(&kiritimati, 1)
+                .0
+                .with_timezone(&Tz::Pacific__Kiritimati)
+        );
+    }
+
+    #[test]
+    fn test_weekday_policy() {
+        let at = |day: u32| {
+            ResolverContext::for_reference(Interval::starting_at(
+                Moment(Local.ymd(2013, 2, day).and_hms(10, 0, 0)),
+                Grain::Second,
+            ))
+        };
+        let friday = helpers::day_of_week(Weekday::Fri).unwrap();
+        let next_friday = friday.the_nth_not_immediate(0).unwrap().mark_next_weekday();
+        let day = |context: &ResolverContext, value: &DatetimeValue| {
+            let output: DatetimeOutput = context
+                .resolve(&Dimension::Datetime(value.clone()))
+                .unwrap()
+                .attempt_into()
+                .unwrap();
+            output.moment
+        };
+        let friday_15 = Moment(Local.ymd(2013, 2, 15).and_hms(0, 0, 0));
+        let friday_22 = Moment(Local.ymd(2013, 2, 22).and_hms(0, 0, 0));
+        assert_eq!(WeekdayPolicy::ExcludeToday, WeekdayPolicy::default());
+        assert_eq!(friday_22, day(&at(15), &friday));
+        assert_eq!(friday_22, day(&at(15), &next_friday));
+        let include_today = at(15).with_weekday_policy(WeekdayPolicy::IncludeToday);
+        assert_eq!(friday_15, day(&include_today, &friday));
+        assert_eq!(friday_22, day(&include_today, &next_friday));
+        assert_eq!(friday_15, day(&at(12), &next_friday));
+        let next_strict = at(12).with_weekday_policy(WeekdayPolicy::NextStrict);
+        assert_eq!(friday_15, day(&next_strict, &friday));
+        assert_eq!(friday_22, day(&next_strict, &next_friday));
+    }
+
+    #[test]
+    fn test_fuzzy_reference() {
+        let day = ResolverContext::for_reference(Interval::ymd(2013, 2, 12));
+        let now = Dimension::Datetime(helpers::cycle_nth(Grain::Second, 0).unwrap());
+        let in_an_hour = Dimension::Datetime(
+            DurationValue::new(PeriodComp::hours(1).into())
+                .in_present()
+                .unwrap(),
+        );
+        let tomorrow = Dimension::Datetime(helpers::cycle_nth(Grain::Day, 1).unwrap());
+        let moment = |context: &ResolverContext, dim: &Dimension| {
+            context.resolve(dim).map(|output| {
+                let output: DatetimeOutput = output.attempt_into().unwrap();
+                output.moment
+            })
+        };
+        assert_eq!(FuzzyReferencePolicy::Snap, FuzzyReferencePolicy::default());
+        assert_eq!(Some(Moment::ymd(2013, 2, 12)), moment(&day, &now));
+        assert_eq!(
+            Some(Moment(Local.ymd(2013, 2, 12).and_hms(1, 0, 0))),
+            moment(&day, &in_an_hour)
+        );
+        assert_eq!(Some(Moment::ymd(2013, 2, 13)), moment(&day, &tomorrow));
+        let reject = day.with_fuzzy_reference(FuzzyReferencePolicy::Reject);
+        assert_eq!(None, moment(&reject, &now));
+        assert_eq!(None, moment(&reject, &in_an_hour));
+        assert_eq!(Some(Moment::ymd(2013, 2, 13)), moment(&reject, &tomorrow));
+        // a precise reference is not affected
+        let precise = context().with_fuzzy_reference(FuzzyReferencePolicy::Reject);
+        assert_eq!(
+            Some(Moment(Local.ymd(2013, 2, 12).and_hms(5, 30, 0))),
+            moment(&precise, &in_an_hour)
+        );
+    }
+
+    #[test]
+    fn test_tie_break() {
+        let wednesday = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Local.ymd(2013, 2, 13).and_hms(10, 0, 0)),
+            Grain::Second,
+        ));
+        let day = |tie_break: TieBreak, weekday: Weekday| {
+            let value = Dimension::Datetime(helpers::day_of_week(weekday).unwrap());
+            let context = wednesday.clone().with_tie_break(tie_break);
+            let output: DatetimeOutput = context.resolve(&value).unwrap().attempt_into().unwrap();
+            output.moment.day()
+        };
+        assert_eq!(TieBreak::PreferFuture, TieBreak::default());
+        assert_eq!(20, day(TieBreak::PreferFuture, Weekday::Wed));
+        assert_eq!(6, day(TieBreak::PreferPast, Weekday::Wed));
+        assert_eq!(6, day(TieBreak::PreferClosest, Weekday::Wed));
+        assert_eq!(11, day(TieBreak::PreferClosest, Weekday::Mon));
+        assert_eq!(15, day(TieBreak::PreferClosest, Weekday::Fri));
+        // Datetimes found in a single direction are kept
+        let tomorrow = Dimension::Datetime(helpers::cycle_nth(Grain::Day, 1).unwrap());
+        let output: DatetimeOutput = wednesday
+            .clone()
+            .with_tie_break(TieBreak::PreferPast)
+            .resolve(&tomorrow)
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        assert_eq!(14, output.moment.day());
+    }
+
+    #[test]
+    fn test_part_of_period() {
+        let beginning_of_2025 = helpers::year(2025)
+            .unwrap()
+            .part_of_period(PartOfPeriod::Start);
+        let output: DatetimeOutput = context()
+            .resolve(&Dimension::Datetime(beginning_of_2025))
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        assert_eq!(
+            Moment(Local.ymd(2025, 1, 1).and_hms(0, 0, 0)),
+            output.moment
+        );
+        assert_eq!(Grain::Month, output.grain);
+        assert_eq!(Some(PartOfPeriod::Start), output.part_of_period);
+        let bounds = |part_of_period, month| {
+            let datetime = helpers::month(month)
+                .unwrap()
+                .part_of_period(part_of_period);
+            let output: DatetimeIntervalOutput = context()
+                .resolve(&Dimension::Datetime(datetime))
+                .unwrap()
+                .attempt_into()
+                .unwrap();
+            match output.interval_kind {
+                DatetimeIntervalKind::Between {
+                    start, end, grain, ..
+                } => {
+                    assert_eq!(Grain::Day, grain);
+                    (start, end)
+                }
+                other => panic!("{:?}", other),
+            }
+        };
+        assert_eq!(
+            (
+                Moment(Local.ymd(2013, 3, 29).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 4, 1).and_hms(0, 0, 0))
+            ),
+            bounds(PartOfPeriod::End, 3)
+        );
+        assert_eq!(
+            (
+                Moment(Local.ymd(2013, 7, 11).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 7, 22).and_hms(0, 0, 0))
+            ),
+            bounds(PartOfPeriod::Middle, 7)
+        );
+        assert_eq!(
+            (
+                Moment(Local.ymd(2013, 3, 1).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 3, 11).and_hms(0, 0, 0))
+            ),
+            bounds(PartOfPeriod::Early, 3)
+        );
+        assert_eq!(
+            (
+                Moment(Local.ymd(2013, 4, 21).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 5, 1).and_hms(0, 0, 0))
+            ),
+            bounds(PartOfPeriod::Late, 4)
+        );
+    }
+
+    #[test]
+    fn test_partial_date_policy() {
+        let whole_periods = context().with_partial_date_policy(PartialDatePolicy::Interval);
+        for (datetime, start, end, expected_grain) in vec![
+            (
+                helpers::month(3).unwrap(),
+                Local.ymd(2013, 3, 1),
+                Local.ymd(2013, 4, 1),
+                Grain::Month,
+            ),
+            (
+                helpers::year(2024).unwrap(),
+                Local.ymd(2024, 1, 1),
+                Local.ymd(2025, 1, 1),
+                Grain::Year,
+            ),
+        ] {
+            let dim = Dimension::Datetime(datetime.datetime_kind(DatetimeKind::Date));
+            let output: DatetimeIntervalOutput = whole_periods
+                .try_resolve(&dim)
+                .unwrap()
+                .attempt_into()
+                .unwrap();
+            match output.interval_kind {
+                DatetimeIntervalKind::Between {
+                    start: found_start,
+                    end: found_end,
+                    grain,
+                    ..
+                } => {
+                    assert_eq!(Moment(start.and_hms(0, 0, 0)), found_start);
+                    assert_eq!(Moment(end.and_hms(0, 0, 0)), found_end);
+                    assert_eq!(expected_grain, grain);
+                }
+                other => panic!("{:?}", other),
+            }
+            let start_only: DatetimeOutput =
+                context().resolve(&dim).unwrap().attempt_into().unwrap();
+            assert_eq!(Moment(start.and_hms(0, 0, 0)), start_only.moment);
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_policy() {
+        let narrow = ResolverContext::new(
+            Interval::starting_at(
+                Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+                Grain::Second,
+            ),
+            Interval::ymd(2013, 2, 1),
+            Interval::ymd(2013, 2, 20),
+        );
+        let next_month = Dimension::Datetime(helpers::cycle_nth(Grain::Month, 1).unwrap());
+        let last_month = Dimension::Datetime(helpers::cycle_nth(Grain::Month, -1).unwrap());
+        let this_month = Dimension::Datetime(helpers::cycle_nth(Grain::Month, 0).unwrap());
+        assert_eq!(None, narrow.resolve(&next_month));
+        assert!(narrow.resolve_candidates(&next_month, 3).is_empty());
+        match narrow.try_resolve(&last_month) {
+            Err(ResolveError::DatetimeOutOfRange { start, .. }) => {
+                assert_eq!(Moment::ymd(2013, 1, 1), start)
+            }
+            other => panic!("{:?}", other),
+        }
+        assert!(narrow.resolve(&this_month).is_some());
+        let clamped = narrow.with_out_of_range_policy(OutOfRangePolicy::Clamp);
+        for (dim, expected) in vec![
+            (next_month, Moment::ymd(2013, 2, 20)),
+            (last_month, Moment::ymd(2013, 2, 1)),
+        ] {
+            let output: DatetimeOutput = clamped.resolve(&dim).unwrap().attempt_into().unwrap();
+            assert_eq!(expected, output.moment);
+            assert_eq!(Grain::Day, output.grain);
+            assert_eq!(1, clamped.resolve_candidates(&dim, 3).len());
+        }
+    }
+
+    #[test]
+    fn test_ambiguous_hour_policy() {
+        let afternoon = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Local.ymd(2013, 2, 12).and_hms(14, 0, 0)),
+            Grain::Second,
+        ));
+        let five = Dimension::Datetime(helpers::hour(5, true).unwrap());
+        let five_pm = Dimension::Datetime(helpers::hour(17, false).unwrap());
+        for (policy, expected) in vec![
+            (
+                AmbiguousHourPolicy::PreferNext,
+                Moment(Local.ymd(2013, 2, 12).and_hms(17, 0, 0)),
+            ),
+            (
+                AmbiguousHourPolicy::PreferAm,
+                Moment(Local.ymd(2013, 2, 13).and_hms(5, 0, 0)),
+            ),
+            (
+                AmbiguousHourPolicy::PreferPm,
+                Moment(Local.ymd(2013, 2, 12).and_hms(17, 0, 0)),
+            ),
+        ] {
+            let resolver = afternoon.clone().with_ambiguous_hour_policy(policy);
+            let output: DatetimeOutput = resolver.resolve(&five).unwrap().attempt_into().unwrap();
+            assert_eq!(expected, output.moment);
+            assert_eq!(Grain::Hour, output.grain);
+            // The 24-hour clock is never ambiguous
+            let output: DatetimeOutput =
+                resolver.resolve(&five_pm).unwrap().attempt_into().unwrap();
+            assert_eq!(
+                Moment(Local.ymd(2013, 2, 12).and_hms(17, 0, 0)),
+                output.moment
+            );
+        }
+    }
+
+    #[test]
+    fn test_fiscal_year_start() {
+        let quarter = |n: i64| {
+            let quarter =
+                helpers::cycle_nth_after(Grain::Quarter, n, &helpers::year(2025).unwrap()).unwrap();
+            Dimension::Datetime(quarter.mark_fiscal_quarter())
+        };
+        let april = context().with_fiscal_year_start(4);
+        for (resolver, n, expected) in vec![
+            (context(), 0, Moment::ymd(2025, 1, 1)),
+            (april.clone(), 0, Moment::ymd(2025, 4, 1)),
+            (april.clone(), 3, Moment::ymd(2026, 1, 1)),
+        ] {
+            let output: DatetimeOutput = resolver
+                .resolve(&quarter(n))
+                .unwrap()
+                .attempt_into()
+                .unwrap();
+            assert_eq!(expected, output.moment);
+            assert_eq!(Grain::Quarter, output.grain);
+        }
+        // The quarters relative to the reference are calendar quarters
+        let next_quarter = Dimension::Datetime(helpers::cycle_nth(Grain::Quarter, 1).unwrap());
+        assert_eq!(
+            context().resolve(&next_quarter),
+            april.resolve(&next_quarter)
+        );
+    }
+
+    #[derive(Debug)]
+    struct SkuResolver;
+
+    impl CustomResolver for SkuResolver {
+        fn dimension_kind(&self) -> DimensionKind {
+            DimensionKind::Number
+        }
+
+        fn resolve(&self, dim: &Dimension) -> Option<Output> {
+            match dim {
+                &Dimension::Number(NumberValue::Integer(ref integer))
+                    if integer.value >= 10_000_000 =>
+                {
+                    Some(Output::Custom(CustomOutput {
+                        tag: "sku".to_string(),
+                        value: format!("SKU-{}", integer.value),
+                    }))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct CycleResolver;
+
+    impl CustomResolver for CycleResolver {
+        fn dimension_kind(&self) -> DimensionKind {
+            DimensionKind::Cycle
+        }
+
+        fn resolve(&self, dim: &Dimension) -> Option<Output> {
+            match dim {
+                &Dimension::Cycle(ref cycle) => Some(Output::Custom(CustomOutput {
+                    tag: "cycle".to_string(),
+                    value: format!("{:?}", cycle.grain),
+                })),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_resolver() {
+        let resolver = context()
+            .with_custom_resolver(SkuResolver)
+            .with_custom_resolver(CycleResolver);
+        let sku = Dimension::Number(NumberValue::Integer(IntegerValue::new(12345678).unwrap()));
+        let sku_output = Output::Custom(CustomOutput {
+            tag: "sku".to_string(),
+            value: "SKU-12345678".to_string(),
+        });
+        assert_eq!(Some(sku_output.clone()), resolver.resolve(&sku));
+        assert_eq!(vec![sku_output], resolver.resolve_candidates(&sku, 3));
+        assert_eq!(
+            Some(Output::Integer(IntegerOutput(12345678))),
+            context().resolve(&sku)
+        );
+        let integer = Dimension::Number(NumberValue::Integer(IntegerValue::new(42).unwrap()));
+        assert_eq!(
+            Some(Output::Integer(IntegerOutput(42))),
+            resolver.resolve(&integer)
+        );
+        let cycle = Dimension::Cycle(CycleValue::new(Grain::Week).unwrap());
+        assert!(OutputKind::Custom.match_dim(&cycle));
+        assert!(!OutputKind::Custom.match_dim(&integer));
+        assert_eq!(
+            Some(Output::Custom(CustomOutput {
+                tag: "cycle".to_string(),
+                value: "Week".to_string(),
+            })),
+            resolver.resolve(&cycle)
+        );
+        assert_eq!(None, context().resolve(&cycle));
+    }
+
+    #[test]
+    fn test_two_digit_year_pivot() {
+        let year = |y| Dimension::Datetime(helpers::year(y).unwrap());
+        let resolved_year = |resolver: &ResolverContext, y| {
+            let output: DatetimeOutput =
+                resolver.resolve(&year(y)).unwrap().attempt_into().unwrap();
+            output.moment.year()
+        };
+        assert_eq!(1999, resolved_year(&context(), 99));
+        assert_eq!(2021, resolved_year(&context(), 21));
+        let nineteen_hundred = context().with_two_digit_year_pivot(TwoDigitYearPivot(1900));
+        assert_eq!(1921, resolved_year(&nineteen_hundred, 21));
+        assert_eq!(1999, resolved_year(&nineteen_hundred, 99));
+        let two_thousand = context().with_two_digit_year_pivot(TwoDigitYearPivot(2000));
+        assert_eq!(2099, resolved_year(&two_thousand, 99));
+        // Years given with four digits aren't moved
+        assert_eq!(1999, resolved_year(&two_thousand, 1999));
+        let date = Dimension::Datetime(helpers::year_month_day(21, 3, 4).unwrap());
+        let output: DatetimeOutput = nineteen_hundred
+            .resolve(&date)
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        assert_eq!(Moment::ymd(1921, 3, 4), output.moment);
+    }
+
+    #[test]
+    fn test_prefer_year_range() {
+        let lone_year = |y| Dimension::Datetime(helpers::year(y).unwrap().lone_year());
+        let recent = context().prefer_year_range(1990..=2100);
+        match recent.try_resolve(&lone_year(1960)) {
+            Err(ResolveError::ImplausibleYear) => {}
+            other => panic!("{:?}", other),
+        }
+        assert!(recent.resolve(&lone_year(2024)).is_some());
+        // Any year found by the grammar without the range
+        assert!(context().resolve(&lone_year(1960)).is_some());
+        // The year is confirmed by its context, e.g. "in 1960"
+        let confirmed = Dimension::Datetime(helpers::year(1960).unwrap().lone_year().not_latent());
+        assert!(recent.resolve(&confirmed).is_some());
+    }
+
+    #[derive(Debug)]
+    struct FirstOfAprilEaster;
+
+    impl HolidayProvider for FirstOfAprilEaster {
+        fn resolve(&self, name: &str, year: i32) -> Option<Interval<Local>> {
+            if name == "easter" {
+                Some(Interval::ymd(year, 4, 1))
+            } else {
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_holidays() {
+        let easter = Dimension::Datetime(
+            helpers::easter()
+                .unwrap()
+                .holiday("easter")
+                .intersect(&helpers::year(2025).unwrap())
+                .unwrap(),
+        );
+        for (resolver, expected) in vec![
+            (context(), Moment::ymd(2025, 4, 20)),
+            (
+                context().with_holidays(crate::holidays::DefaultHolidayProvider),
+                Moment::ymd(2025, 4, 20),
+            ),
+            (
+                context().with_holidays(FirstOfAprilEaster),
+                Moment::ymd(2025, 4, 1),
+            ),
+        ] {
+            let output: DatetimeOutput = resolver.resolve(&easter).unwrap().attempt_into().unwrap();
+            assert_eq!(expected, output.moment);
+        }
+    }
+
+    #[test]
+    fn test_was_12_hour() {
+        let was_12_hour = |datetime: DatetimeValue| {
+            let output: DatetimeOutput = context()
+                .resolve(&Dimension::Datetime(datetime))
+                .unwrap()
+                .attempt_into()
+                .unwrap();
+            output.was_12_hour
+        };
+        let at_5pm = helpers::hour(17, false).unwrap().mark_12_hour();
+        assert!(!was_12_hour(helpers::hour(17, false).unwrap()));
+        assert!(was_12_hour(at_5pm.clone()));
+        // Kept by the intersection with a date
+        let tomorrow = helpers::cycle_nth(Grain::Day, 1).unwrap();
+        assert!(was_12_hour(tomorrow.intersect(&at_5pm).unwrap()));
+    }
+
+    #[test]
+    fn test_resolve_from_threads() {
+        fn resolve_all(ctx: &ResolverContext) -> Vec<Option<Output>> {
+            vec![
+                ctx.resolve(&Dimension::Datetime(
+                    helpers::day_of_week(Weekday::Fri).unwrap(),
+                )),
+                ctx.resolve(&Dimension::Datetime(
+                    helpers::month_day(12, 25).unwrap().holiday("christmas"),
+                )),
+                ctx.resolve(&Dimension::Datetime(helpers::hour(15, false).unwrap())),
+            ]
+        }
+        let shared = Arc::new(
+            context()
+                .with_cache(16)
+                .with_holidays(crate::holidays::DefaultHolidayProvider),
+        );
+        let expected = resolve_all(&shared);
+        assert!(expected.iter().all(Option::is_some));
+        let threads = (0..8)
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                ::std::thread::spawn(move || {
+                    (0..10).map(|_| resolve_all(&shared)).collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            for outputs in thread.join().unwrap() {
+                assert_eq!(expected, outputs);
+            }
+        }
+    }
+
+    #[test]
+    fn test_resolve_utc() {
+        let now = Moment(Utc.ymd(2013, 2, 12).and_hms(10, 0, 0).with_timezone(&Local));
+        let in_paris = ResolverContext::for_reference_in_tz(
+            Interval::starting_at(now, Grain::Second),
+            Tz::Europe__Paris,
+        );
+        let three_pm = Dimension::Datetime(helpers::hour(15, false).unwrap());
+        let output: DatetimeOutput = in_paris
+            .resolve_utc(&three_pm)
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        // 3pm in Paris is 2pm in UTC in winter
+        assert_eq!(
+            Utc.ymd(2013, 2, 12).and_hms(14, 0, 0),
+            output.moment.0.with_timezone(&Utc)
+        );
+        assert_eq!(Grain::Hour, output.grain);
+        assert_eq!(Some(Tz::UTC), output.timezone);
+        assert_eq!("2013-02-12T14:00:00+00:00", output.to_rfc3339());
+        // Outputs of the local timezone are converted too
+        let local: DatetimeOutput = context()
+            .resolve(&three_pm)
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        let utc: DatetimeOutput = context()
+            .resolve_utc(&three_pm)
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        assert_eq!(local.moment, utc.moment);
+        assert_eq!(Some(Tz::UTC), utc.timezone);
+    }
+
+    #[test]
+    fn test_seasons() {
+        let july = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Local.ymd(2013, 7, 15).and_hms(9, 0, 0)),
+            Grain::Second,
+        ));
+        let bounds = |resolver: &ResolverContext, datetime: DatetimeValue| {
+            let output: DatetimeIntervalOutput = resolver
+                .resolve(&Dimension::Datetime(datetime))
+                .unwrap()
+                .attempt_into()
+                .unwrap();
+            match output.interval_kind {
+                DatetimeIntervalKind::Between { start, end, .. } => (start, end),
+                other => panic!("{:?}", other),
+            }
+        };
+        // As built by "next <season>"
+        let next = |season| {
+            helpers::season(season)
+                .unwrap()
+                .the_nth(0)
+                .unwrap()
+                .form(Form::Season)
+        };
+        let between = |start: (i32, u32, u32), end: (i32, u32, u32)| {
+            (
+                Moment::ymd(start.0, start.1, start.2),
+                Moment::ymd(end.0, end.1, end.2),
+            )
+        };
+        assert_eq!(
+            between((2013, 12, 1), (2014, 3, 1)),
+            bounds(&july, next(Season::Winter))
+        );
+        assert_eq!(
+            between((2013, 9, 1), (2013, 12, 1)),
+            bounds(&july, next(Season::Fall))
+        );
+        let astronomical = july
+            .clone()
+            .with_season_convention(SeasonConvention::Astronomical);
+        assert_eq!(
+            between((2013, 12, 21), (2014, 3, 21)),
+            bounds(&astronomical, next(Season::Winter))
+        );
+        // The seasons are inverted in the southern hemisphere
+        let southern = july.with_hemisphere(Hemisphere::Southern);
+        assert_eq!(
+            between((2013, 6, 1), (2013, 9, 1)),
+            bounds(&southern, next(Season::Winter))
+        );
+        assert_eq!(
+            between((2013, 12, 1), (2014, 3, 1)),
+            bounds(&southern, next(Season::Summer))
+        );
+        assert_eq!(
+            between((2013, 9, 1), (2013, 12, 1)),
+            bounds(&southern, next(Season::Spring))
+        );
+        let winter_2014 = helpers::season_of_year(Season::Winter, 2014).unwrap();
+        assert_eq!(
+            between((2014, 6, 1), (2014, 9, 1)),
+            bounds(&southern, winter_2014)
+        );
+        let southern_astronomical = southern.with_season_convention(SeasonConvention::Astronomical);
+        assert_eq!(
+            between((2013, 12, 21), (2014, 3, 21)),
+            bounds(&southern_astronomical, next(Season::Summer))
+        );
+    }
+
+    #[test]
+    fn test_week_start() {
+        let sunday = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Local.ymd(2013, 2, 17).and_hms(10, 0, 0)),
+            Grain::Second,
+        ));
+        let this_week = Dimension::Datetime(helpers::cycle_nth(Grain::Week, 0).unwrap());
+        let start = |context: &ResolverContext| {
+            let output: DatetimeOutput =
+                context.resolve(&this_week).unwrap().attempt_into().unwrap();
+            output.moment
+        };
+        assert_eq!(
+            Moment(Local.ymd(2013, 2, 11).and_hms(0, 0, 0)),
+            start(&sunday)
+        );
+        let sunday_start = sunday.with_week_start(Weekday::Sun);
+        assert_eq!(
+            Moment(Local.ymd(2013, 2, 17).and_hms(0, 0, 0)),
+            start(&sunday_start)
+        );
+    }
+
+    #[test]
+    fn test_cache() {
+        let context = context().with_cache(1);
+        let tomorrow = Dimension::Datetime(helpers::cycle_nth(Grain::Day, 1).unwrap());
+        let friday = Dimension::Datetime(helpers::day_of_week(Weekday::Fri).unwrap());
+        let uncached = context().resolve(&tomorrow);
+        assert_eq!(uncached, context.resolve(&tomorrow));
+        assert_eq!(uncached, context.clone().resolve(&tomorrow));
+        let cache = context.cache.clone().unwrap();
+        assert_eq!(1, cache.len());
+        assert_eq!(context().resolve(&friday), context.resolve(&friday));
+        assert_eq!(1, cache.len());
+        // Resolved at another reference, the same datetime walks again
+        let wednesday = Interval::starting_at(
+            Moment(Local.ymd(2013, 2, 13).and_hms(0, 0, 0)),
+            Grain::Second,
+        );
+        assert_eq!(
+            context().resolve_at(&tomorrow, wednesday),
+            context.resolve_at(&tomorrow, wednesday)
+        );
+        assert_eq!(None, context().with_cache(0).cache.map(|cache| cache.len()));
+    }
+
+    #[test]
+    fn test_try_resolve() {
+        let cycle = Dimension::Cycle(CycleValue::new(Grain::Day).unwrap());
+        match context().try_resolve(&cycle) {
+            Err(ResolveError::Unsupported(DimensionKind::Cycle)) => {}
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(None, context().resolve(&cycle));
+        let year = Dimension::Datetime(helpers::year(1900).unwrap());
+        match context().try_resolve(&year) {
+            Err(ResolveError::NoMatchInRange) => {}
+            other => panic!("{:?}", other),
+        }
+        let year = Dimension::Datetime(helpers::year(2013).unwrap());
+        let later = ResolverContext::new(
+            Interval::starting_at(
+                Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+                Grain::Second,
+            ),
+            Interval::ymd(2014, 1, 1),
+            Interval::ymd(2020, 1, 1),
+        );
+        match later.try_resolve(&year) {
+            Err(ResolveError::OutOfRange { .. }) => {}
+            other => panic!("{:?}", other),
+        }
+        let days = helpers::cycle_nth(Grain::Day, 0)
+            .unwrap()
+            .span_to(&helpers::cycle_nth(Grain::Day, 2).unwrap(), false)
+            .unwrap()
+            .datetime_kind(DatetimeKind::Date);
+        let days = Dimension::Datetime(days);
+        match context().try_resolve(&days) {
+            Err(ResolveError::KindIntervalMismatch {
+                kind: DatetimeKind::Date,
+                ..
+            }) => {}
+            other => panic!("{:?}", other),
+        }
+        assert!(context().resolve(&days).is_some());
+        let friday = Dimension::Datetime(helpers::day_of_week(Weekday::Fri).unwrap());
+        assert_eq!(
+            context().resolve(&friday),
+            context().try_resolve(&friday).ok()
+        );
+    }
+
+    #[test]
+    fn test_resolve_percentage() {
+        for value in vec![0.5, 100.0, 150.0] {
+            assert_eq!(
+                Some(Output::Percentage(PercentageOutput(value))),
+                context().resolve(&Dimension::Percentage(PercentageValue(value)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_temperature_range() {
+        let temperature = |value, unit| TemperatureValue {
+            value,
+            unit: Some(unit),
+            latent: false,
+            qualifier: None,
+        };
+        // "between 20°C and 77°F", the bounds being given in the same unit
+        let mixed = TemperatureRangeValue::new(
+            temperature(20.0, "celsius"),
+            temperature(77.0, "fahrenheit"),
+        )
+        .unwrap();
+        let output: TemperatureRangeOutput = context()
+            .resolve(&Dimension::TemperatureRange(mixed))
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        assert_eq!(Some("celsius"), output.unit);
+        assert_eq!(20.0, output.low);
+        assert!((output.high - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_resolve_batch() {
+        let dims = vec![
+            Dimension::Number(NumberValue::Integer(IntegerValue::new(3).unwrap())),
+            Dimension::Datetime(helpers::cycle_nth(Grain::Day, 1).unwrap()),
+            Dimension::Number(NumberValue::Float(FloatValue::new(1.5).unwrap())),
+        ];
+        let set = context().resolve_batch(&dims);
+        assert_eq!(3, set.len());
+        assert_eq!(&[IntegerOutput(3)], set.integers());
+        assert_eq!(&[FloatOutput(1.5)], set.floats());
+        assert_eq!(
+            Moment(Local.ymd(2013, 2, 13).and_hms(0, 0, 0)),
+            set.datetimes()[0].moment
+        );
+    }
+
+    #[test]
+    fn test_resolve_at() {
+        let tomorrow = Dimension::Datetime(helpers::cycle_nth(Grain::Day, 1).unwrap());
+        let reference = Interval::starting_at(
+            Moment(Local.ymd(2014, 6, 1).and_hms(10, 0, 0)),
+            Grain::Second,
+        );
+        let context = context();
+        let output: DatetimeOutput = context
+            .resolve_at(&tomorrow, reference)
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        assert_eq!(
+            Moment(Local.ymd(2014, 6, 2).and_hms(0, 0, 0)),
+            output.moment
+        );
+        let output: DatetimeOutput = context.resolve(&tomorrow).unwrap().attempt_into().unwrap();
+        assert_eq!(
+            Moment(Local.ymd(2013, 2, 13).and_hms(0, 0, 0)),
+            output.moment
+        );
+    }
+
+    #[test]
+    fn test_interval_inclusive() {
+        let inclusive = |datetime: DatetimeValue| match context()
+            .resolve(&Dimension::Datetime(datetime))
+            .unwrap()
+        {
+            Output::DatetimeInterval(DatetimeIntervalOutput {
+                interval_kind: DatetimeIntervalKind::After { inclusive, .. },
+                ..
+            })
+            | Output::DatetimeInterval(DatetimeIntervalOutput {
+                interval_kind: DatetimeIntervalKind::Before { inclusive, .. },
+                ..
+            }) => inclusive,
+            other => panic!("{:?}", other),
+        };
+        let five = helpers::hour(17, false).unwrap();
+        // "from 5pm"
+        assert!(inclusive(five.clone().mark_after_start()));
+        // "after 5pm"
+        assert!(!inclusive(five.clone().mark_after_end()));
+        // "before 5pm"
+        assert!(!inclusive(five.clone().mark_before_start()));
+        // "until 5pm"
+        assert!(inclusive(five.mark_before_end()));
+        let monday = helpers::day_of_week(Weekday::Mon).unwrap();
+        // "after monday", from tuesday 00:00 included
+        assert!(inclusive(monday.clone().mark_after_end_all()));
+        // "until monday", up to tuesday 00:00 excluded
+        assert!(!inclusive(monday.mark_before_end_all()));
+    }
+
+    fn between_grain(from: DatetimeValue, to: DatetimeValue) -> Grain {
+        let span = Dimension::Datetime(from.span_to(&to, false).unwrap());
+        let output: DatetimeIntervalOutput =
+            context().resolve(&span).unwrap().attempt_into().unwrap();
+        match output.interval_kind {
+            DatetimeIntervalKind::Between { grain, .. } => grain,
+            _ => panic!("{:?} is not a between interval", output),
+        }
+    }
+
+    #[test]
+    fn test_between_grain() {
+        // "from monday to wednesday"
+        let monday = helpers::day_of_week(Weekday::Mon).unwrap();
+        let wednesday = helpers::day_of_week(Weekday::Wed).unwrap();
+        assert_eq!(Grain::Day, between_grain(monday, wednesday));
+        // "from 2:00 to 4:00"
+        let two = helpers::hour_minute(14, 0, false).unwrap();
+        let four = helpers::hour_minute(16, 0, false).unwrap();
+        assert_eq!(Grain::Minute, between_grain(two, four));
+        // "from 2pm to 4:30pm"
+        let two = helpers::hour(14, false).unwrap();
+        let half_past_four = helpers::hour_minute(16, 30, false).unwrap();
+        assert_eq!(Grain::Minute, between_grain(two, half_past_four));
+    }
+
+    #[test]
+    fn test_between_bound_grains() {
+        // "between monday and 5pm tuesday"
+        let monday = helpers::day_of_week(Weekday::Mon).unwrap();
+        let tuesday_five = helpers::day_of_week(Weekday::Tue)
+            .unwrap()
+            .intersect(&helpers::hour(17, false).unwrap())
+            .unwrap();
+        let span = Dimension::Datetime(monday.span_to(&tuesday_five, false).unwrap());
+        let output: DatetimeIntervalOutput =
+            context().resolve(&span).unwrap().attempt_into().unwrap();
+        match output.interval_kind {
+            DatetimeIntervalKind::Between {
+                start,
+                end,
+                grain,
+                start_grain,
+                end_grain,
+                ..
+            } => {
+                assert_eq!(Grain::Hour, grain);
+                assert_eq!(Grain::Day, start_grain);
+                assert_eq!(Grain::Hour, end_grain);
+                assert_eq!(start.round_to(Grain::Day), start);
+                assert_eq!(start + PeriodComp::days(1) + PeriodComp::hours(17), end);
+            }
+            other => panic!("{:?} is not a between interval", other),
+        }
+    }
+
+    #[test]
+    fn test_precision_propagation() {
+        let three = helpers::hour(15, false)
+            .unwrap()
+            .precision(Precision::Rough);
+        let output =
+            |datetime: DatetimeValue| context().resolve(&Dimension::Datetime(datetime)).unwrap();
+        if let Output::Datetime(datetime) = output(three.clone()) {
+            assert_eq!(Precision::Rough, datetime.precision);
+        } else {
+            panic!()
+        }
+        for bounded in vec![
+            three.clone().mark_after_start(),
+            three.clone().mark_before_start(),
+        ] {
+            match output(bounded) {
+                Output::DatetimeInterval(DatetimeIntervalOutput {
+                    interval_kind: DatetimeIntervalKind::After { datetime, .. },
+                    ..
+                })
+                | Output::DatetimeInterval(DatetimeIntervalOutput {
+                    interval_kind: DatetimeIntervalKind::Before { datetime, .. },
+                    ..
+                }) => assert_eq!(Precision::Rough, datetime.precision),
+                other => panic!("{:?}", other),
+            }
+        }
+        let five = helpers::hour(17, false)
+            .unwrap()
+            .precision(Precision::Approximate);
+        match output(three.span_to(&five, false).unwrap()) {
+            Output::DatetimeInterval(DatetimeIntervalOutput {
+                interval_kind: DatetimeIntervalKind::Between { precision, .. },
+                ..
+            }) => assert_eq!(Precision::Rough, precision),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_builder() {
+        let now = Interval::starting_at(
+            Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        );
+        let friday = Dimension::Datetime(helpers::day_of_week(Weekday::Fri).unwrap());
+        let built = ResolverContext::builder().reference(now).build().unwrap();
+        assert_eq!(context().resolve(&friday), built.resolve(&friday));
+        let in_tz = ResolverContext::builder()
+            .reference(now)
+            .timezone(Tz::Europe__Paris)
+            .build()
+            .unwrap();
+        assert_eq!(Some(Tz::Europe__Paris), in_tz.timezone());
+        assert!(ResolverContext::builder()
+            .reference(now)
+            .window(1)
+            .build()
+            .is_ok());
+        let before = Interval::starting_at(
+            Moment(Local.ymd(2012, 1, 1).and_hms(0, 0, 0)),
+            Grain::Second,
+        );
+        assert!(ResolverContext::builder()
+            .reference(now)
+            .max(before)
+            .build()
+            .is_err());
+        assert!(ResolverContext::builder()
+            .reference(before)
+            .min(now)
+            .build()
+            .is_err());
+    }
+
+    fn next_year_from(year: i32) -> Option<Output> {
+        let context = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Local.ymd(year, 6, 15).and_hms(12, 0, 0)),
+            Grain::Second,
+        ));
+        context.resolve(&Dimension::Datetime(
+            helpers::cycle_nth(Grain::Year, 1).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn test_resolve_out_of_unix_time() {
+        for year in vec![1900, 2100] {
+            let secs = Utc.ymd(year, 6, 15).and_hms(12, 0, 0).timestamp();
+            assert!(ResolverContext::from_secs(secs).is_some());
+            let output: DatetimeOutput = next_year_from(year).unwrap().attempt_into().unwrap();
+            assert_eq!(
+                Moment(Local.ymd(year + 1, 1, 1).and_hms(0, 0, 0)),
+                output.moment
+            );
+            assert_eq!(Grain::Year, output.grain);
+        }
+    }
+
+    #[test]
+    fn test_from_datetime() {
+        let utc = Utc.ymd(2013, 2, 12).and_hms_milli(3, 30, 0, 250);
+        let paris = utc.with_timezone(&FixedOffset::east(3600));
+        let from_secs = ResolverContext::from_secs(utc.timestamp()).unwrap();
+        assert_eq!(
+            from_secs.ctx.reference,
+            ResolverContext::from_datetime(utc).ctx.reference
+        );
+        assert_eq!(
+            from_secs.ctx.reference,
+            ResolverContext::from_datetime(paris).ctx.reference
+        );
+        assert_eq!(
+            Grain::Second,
+            ResolverContext::from_datetime(paris).ctx.reference.grain
+        );
+    }
+
+    #[test]
+    fn test_from_datetime_offset() {
+        // 1pm in UTC is already the 13th at UTC+14
+        let kiribati = FixedOffset::east(14 * 3600);
+        let reference = Utc
+            .ymd(2013, 2, 12)
+            .and_hms(13, 0, 0)
+            .with_timezone(&kiribati);
+        let tomorrow = Dimension::Datetime(helpers::cycle_nth(Grain::Day, 1).unwrap());
+        let output: DatetimeOutput = ResolverContext::from_datetime(reference)
+            .resolve(&tomorrow)
+            .unwrap()
+            .attempt_into()
+            .unwrap();
+        assert_eq!(
+            kiribati.ymd(2013, 2, 14).and_hms(0, 0, 0),
+            output.moment.0.with_timezone(&kiribati)
+        );
+        assert_eq!(Grain::Day, output.grain);
+    }
+
+    #[test]
+    fn test_from_secs() {
+        assert!(ResolverContext::from_secs(1360639800).is_some());
+        assert!(ResolverContext::from_secs(::std::i64::MAX).is_none());
+        assert!(ResolverContext::from_secs(::std::i64::MIN).is_none());
+    }
+}
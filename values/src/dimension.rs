@@ -1,7 +1,14 @@
-use moment::{Grain, Local, Period, RcConstraint};
+use moment::{Grain, Local, Period, RcConstraint, Tz, Weekday};
 use rustling::*;
+#[cfg(feature = "serde")]
+use serde_crate::{Deserialize, Serialize};
 use std::{fmt, result};
 
+pub use crate::numbers::{
+    CombinationDirection, FloatValue, IntegerValue, NumberValue, OrdinalValue, PercentageValue,
+    Precision,
+};
+
 // Union of all possible values parsed by the ontology.
 
 rustling_value! {
@@ -11,6 +18,7 @@ rustling_value! {
         Number(NumberValue),
         AmountOfMoney(AmountOfMoneyValue),
         Ordinal(OrdinalValue),
+        OrdinalRange(OrdinalRangeValue),
         Temperature(TemperatureValue),
         MoneyUnit(MoneyUnitValue),
         Datetime(DatetimeValue),
@@ -19,6 +27,12 @@ rustling_value! {
         Cycle(CycleValue),
         UnitOfDuration(UnitOfDurationValue),
         RelativeMinute(RelativeMinuteValue),
+        Quantity(QuantityValue),
+        PhoneNumber(PhoneNumberValue),
+        Age(AgeValue),
+        NumberRange(NumberRangeValue),
+        NumberComparison(NumberComparisonValue),
+        TemperatureRange(TemperatureRangeValue),
     }
 
     fn latent(v: &Dimension) -> bool {
@@ -27,6 +41,7 @@ rustling_value! {
             &Dimension::Percentage(_) => false,
             &Dimension::AmountOfMoney(_) => false,
             &Dimension::Ordinal(_) => false,
+            &Dimension::OrdinalRange(_) => false,
             &Dimension::Temperature(ref temp) => temp.latent,
             &Dimension::MoneyUnit(_) => true,
             &Dimension::Datetime(ref dtv) => dtv.latent,
@@ -34,6 +49,12 @@ rustling_value! {
             &Dimension::Cycle(_) => true,
             &Dimension::UnitOfDuration(_) => true,
             &Dimension::RelativeMinute(_) => true,
+            &Dimension::Quantity(_) => false,
+            &Dimension::PhoneNumber(_) => false,
+            &Dimension::Age(_) => false,
+            &Dimension::NumberRange(_) => false,
+            &Dimension::NumberComparison(_) => false,
+            &Dimension::TemperatureRange(_) => false,
         }
     }
 
@@ -43,6 +64,7 @@ rustling_value! {
             &Dimension::Percentage(_) => None,
             &Dimension::AmountOfMoney(_) => None,
             &Dimension::Ordinal(_) => None,
+            &Dimension::OrdinalRange(_) => None,
             &Dimension::Temperature(_) => None,
             &Dimension::MoneyUnit(_) => None,
             &Dimension::Datetime(ref dtv) => Some(Payload(dtv.constraint.grain())),
@@ -50,6 +72,12 @@ rustling_value! {
             &Dimension::Cycle(_) => None,
             &Dimension::UnitOfDuration(_) => None,
             &Dimension::RelativeMinute(_) => None,
+            &Dimension::Quantity(_) => None,
+            &Dimension::PhoneNumber(_) => None,
+            &Dimension::Age(_) => None,
+            &Dimension::NumberRange(_) => None,
+            &Dimension::NumberComparison(_) => None,
+            &Dimension::TemperatureRange(_) => None,
         }
     }
 }
@@ -61,6 +89,7 @@ impl Dimension {
             &Dimension::Percentage(_) => false,
             &Dimension::AmountOfMoney(_) => false,
             &Dimension::Ordinal(_) => false,
+            &Dimension::OrdinalRange(_) => false,
             &Dimension::Temperature(_) => false,
             &Dimension::MoneyUnit(_) => false,
             &Dimension::Datetime(ref dtv) => dtv.is_too_ambiguous(),
@@ -68,6 +97,12 @@ impl Dimension {
             &Dimension::Cycle(_) => true,
             &Dimension::UnitOfDuration(_) => true,
             &Dimension::RelativeMinute(_) => true,
+            &Dimension::Quantity(_) => false,
+            &Dimension::PhoneNumber(_) => false,
+            &Dimension::Age(_) => false,
+            &Dimension::NumberRange(_) => false,
+            &Dimension::NumberComparison(_) => false,
+            &Dimension::TemperatureRange(_) => false,
         }
     }
 }
@@ -84,6 +119,7 @@ impl fmt::Display for Dimension {
             },
             &Dimension::Percentage(ref v) => write!(fmt, "Percentage: {}", v.0),
             &Dimension::Ordinal(_) => write!(fmt, "Ordinal"),
+            &Dimension::OrdinalRange(_) => write!(fmt, "OrdinalRange"),
             &Dimension::Temperature(_) => write!(fmt, "Temperature"),
             &Dimension::AmountOfMoney(_) => write!(fmt, "AmountOfMoney"),
             &Dimension::MoneyUnit(_) => write!(fmt, "MoneyUnit"),
@@ -92,56 +128,16 @@ impl fmt::Display for Dimension {
             &Dimension::Cycle(_) => write!(fmt, "Cycle"),
             &Dimension::UnitOfDuration(_) => write!(fmt, "UnitOfDuration"),
             &Dimension::RelativeMinute(_) => write!(fmt, "RelativeMinute"),
+            &Dimension::Quantity(_) => write!(fmt, "Quantity"),
+            &Dimension::PhoneNumber(_) => write!(fmt, "PhoneNumber"),
+            &Dimension::Age(ref v) => write!(fmt, "Age: {} {:?}", v.value, v.unit),
+            &Dimension::NumberRange(_) => write!(fmt, "NumberRange"),
+            &Dimension::NumberComparison(ref v) => write!(fmt, "NumberComparison: {:?} {}", v.op, v.value),
+            &Dimension::TemperatureRange(_) => write!(fmt, "TemperatureRange"),
         }
     }
 }
 
-/// Payload for the ordinal numbers value of Dimension
-#[derive(Debug, PartialEq, Copy, Clone)]
-pub struct OrdinalValue {
-    pub value: i64,
-    pub prefixed: bool,
-    pub grain: Option<u8>,
-}
-
-impl OrdinalValue {
-    pub fn new(value: i64) -> OrdinalValue {
-        OrdinalValue {
-            value,
-            prefixed: false,
-            grain: None,
-        }
-    }
-
-    pub fn new_with_grain(value: i64, grain: u8) -> OrdinalValue {
-        OrdinalValue {
-            value,
-            prefixed: false,
-            grain: Some(grain),
-        }
-    }
-
-    pub fn prefixed(self) -> OrdinalValue {
-        OrdinalValue {
-            value: self.value,
-            prefixed: true,
-            grain: None,
-        }
-    }
-}
-
-#[derive(Debug, PartialEq, Copy, Clone)]
-pub enum Precision {
-    Approximate,
-    Exact,
-}
-
-impl Default for Precision {
-    fn default() -> Precision {
-        Precision::Exact
-    }
-}
-
 /// Payload for the amount of money value of Dimension
 #[derive(Debug, PartialEq, Copy, Clone, Default)]
 pub struct AmountOfMoneyValue {
@@ -156,30 +152,6 @@ pub struct MoneyUnitValue {
     pub unit: Option<&'static str>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub enum CombinationDirection {
-    Left,
-    Right,
-}
-
-/// Payload for the integral numbers value of Dimension
-#[derive(Debug, PartialEq, Clone, Default)]
-pub struct IntegerValue {
-    pub value: i64,
-    #[doc(hidden)]
-    pub grain: Option<u8>,
-    #[doc(hidden)]
-    pub group: bool,
-    #[doc(hidden)]
-    pub prefixed: bool,
-    #[doc(hidden)]
-    pub suffixed: bool,
-    #[doc(hidden)]
-    pub combine_from: Option<CombinationDirection>,
-    #[doc(hidden)]
-    pub precision: Precision,
-}
-
 impl IntegerValue {
     pub fn new(value: i64) -> RuleResult<IntegerValue> {
         Ok(IntegerValue {
@@ -310,20 +282,6 @@ impl AttemptFrom<Dimension> for FloatValue {
     }
 }
 
-/// Payload for the floating numbers value of Dimension
-#[derive(Debug, PartialEq, Clone, Default)]
-pub struct FloatValue {
-    pub value: f64,
-    #[doc(hidden)]
-    pub prefixed: bool,
-    #[doc(hidden)]
-    pub suffixed: bool,
-    #[doc(hidden)]
-    pub combine_from: Option<CombinationDirection>,
-    #[doc(hidden)]
-    pub precision: Precision,
-}
-
 impl FloatValue {
     pub fn new(value: f64) -> RuleResult<FloatValue> {
         Ok(FloatValue {
@@ -368,13 +326,6 @@ impl From<FloatValue> for NumberValue {
     }
 }
 
-/// Enumeration acting as a Number supertype for IntegerValue and FloatValue.
-#[derive(Debug, PartialEq, Clone)]
-pub enum NumberValue {
-    Float(FloatValue),
-    Integer(IntegerValue),
-}
-
 impl NumberValue {
     #[doc(hidden)]
     pub fn prefixed(&self) -> bool {
@@ -452,6 +403,171 @@ pub struct TemperatureValue {
     pub unit: Option<&'static str>,
     /// true if it can not be confirmed that the value is actually a temperature
     pub latent: bool,
+    /// Modifier given with the temperature, e.g. "feels like", `None` for a plain temperature
+    pub qualifier: Option<TemperatureQualifier>,
+}
+
+/// Payload for the temperature ranges value of Dimension, e.g. "between 20 and 25 degrees". Each
+/// bound keeps the unit given with it, if any, the unit being chosen for both when resolving.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TemperatureRangeValue {
+    pub low: TemperatureValue,
+    pub high: TemperatureValue,
+}
+
+impl TemperatureRangeValue {
+    /// Range between the two temperatures, which must be in order unless they are in different
+    /// units, e.g. "20 °C to 70 °F"
+    pub fn new(low: TemperatureValue, high: TemperatureValue) -> RuleResult<TemperatureRangeValue> {
+        let same_unit = low.unit.is_none() || high.unit.is_none() || low.unit == high.unit;
+        if same_unit && low.value >= high.value {
+            return Err(RuleError::Invalid.into());
+        }
+        Ok(TemperatureRangeValue { low, high })
+    }
+
+    /// Unit given with one of the bounds at least, the one of the low bound first
+    pub fn unit(&self) -> Option<&'static str> {
+        self.low.unit.or(self.high.unit)
+    }
+}
+
+/// What a temperature measures, as told by the words around it
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "snake_case"))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
+pub enum TemperatureQualifier {
+    /// The temperature of the air, e.g. "30 degrees" or "the actual temperature is 30"
+    Actual,
+    /// The temperature felt, e.g. "feels like 30"
+    FeelsLike,
+    /// The temperature felt with the wind, e.g. "wind chill of -5"
+    WindChill,
+    /// The temperature felt with the humidity, e.g. "heat index of 100"
+    HeatIndex,
+}
+
+/// Payload for the ordinal ranges value of Dimension, e.g. "the first three" or "2nd through 5th"
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct OrdinalRangeValue {
+    pub start: i64,
+    /// Included in the range
+    pub end: i64,
+}
+
+impl OrdinalRangeValue {
+    pub fn new(start: i64, end: i64) -> RuleResult<OrdinalRangeValue> {
+        if start < end {
+            Ok(OrdinalRangeValue { start, end })
+        } else {
+            Err(RuleError::Invalid.into())
+        }
+    }
+}
+
+/// Payload for the number ranges value of Dimension, e.g. "between 5 and 10", both bounds
+/// included. The ranges open on one side are comparisons, see `NumberComparisonValue`.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct NumberRangeValue {
+    pub low: f64,
+    pub high: f64,
+    pub low_inclusive: bool,
+    pub high_inclusive: bool,
+}
+
+impl NumberRangeValue {
+    pub fn new(low: f64, high: f64) -> RuleResult<NumberRangeValue> {
+        if low < high {
+            Ok(NumberRangeValue {
+                low,
+                high,
+                low_inclusive: true,
+                high_inclusive: true,
+            })
+        } else {
+            Err(RuleError::Invalid.into())
+        }
+    }
+}
+
+/// Operator of a comparison to a number, e.g. `Gt` for "more than 100" or `Le` for "at most 50"
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "snake_case"))]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum CompareOp {
+    /// "more than", "over"
+    Gt,
+    /// "at least", "no less than"
+    Ge,
+    /// "less than", "under"
+    Lt,
+    /// "at most", "no more than", "up to"
+    Le,
+}
+
+impl CompareOp {
+    /// Whether `value` compares to `reference` with this operator, e.g. 120 to 100 with `Gt`
+    pub fn compare(&self, value: f64, reference: f64) -> bool {
+        match self {
+            &CompareOp::Gt => value > reference,
+            &CompareOp::Ge => value >= reference,
+            &CompareOp::Lt => value < reference,
+            &CompareOp::Le => value <= reference,
+        }
+    }
+}
+
+/// Payload for the number comparisons value of Dimension, e.g. "under 50"
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct NumberComparisonValue {
+    pub op: CompareOp,
+    pub value: f64,
+}
+
+/// Payload for the phone numbers value of Dimension, the digits as written, with their
+/// separators, e.g. "+1 (555) 123-4567"
+#[derive(Debug, PartialEq, Clone)]
+pub struct PhoneNumberValue(pub String);
+
+/// Payload for the ages value of Dimension, e.g. 6 and `Grain::Month` for "six months old"
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct AgeValue {
+    pub value: f64,
+    /// Years for most ages, months or weeks for the infants
+    pub unit: Grain,
+}
+
+impl AgeValue {
+    pub fn new(value: f64, unit: Grain) -> AgeValue {
+        AgeValue { value, unit }
+    }
+}
+
+/// Payload for the physical quantities value of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct QuantityValue {
+    pub value: f64,
+    /// kilogram, litre, mile, ...
+    pub unit: Option<String>,
+    /// What is measured, e.g. "flour" in "3 kg of flour"
+    pub product: Option<String>,
+}
+
+impl QuantityValue {
+    pub fn new(value: f64, unit: &str) -> QuantityValue {
+        QuantityValue {
+            value,
+            unit: Some(unit.to_string()),
+            product: None,
+        }
+    }
+
+    pub fn product(self, product: &str) -> QuantityValue {
+        QuantityValue {
+            product: Some(product.to_string()),
+            ..self
+        }
+    }
 }
 
 /// Payload for the cycle value of Dimension
@@ -496,6 +612,8 @@ pub enum Ambiguity {
     Big,
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "snake_case"))]
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
 pub enum DatetimeKind {
     Date,
@@ -507,6 +625,78 @@ pub enum DatetimeKind {
     Empty,
 }
 
+/// Order of the day, the month and the year in numeric dates, e.g. "03/04/2024" is march 4 in
+/// the `MonthDayYear` order of the United States and april 3 in the `DayMonthYear` order of
+/// France. See `ResolverContext::with_date_order`
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
+pub enum DateOrder {
+    MonthDayYear,
+    DayMonthYear,
+    YearMonthDay,
+}
+
+/// Season of the year, found by the walker between its astronomical bounds in the northern
+/// hemisphere, e.g. from june 21 to september 23 for the summer. The resolver moves it to the
+/// bounds of its `SeasonConvention` and `Hemisphere`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "snake_case"))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
+pub enum Season {
+    Spring,
+    Summer,
+    Fall,
+    Winter,
+}
+
+impl Season {
+    /// Season at the same time of the year in the other hemisphere, e.g. the winter for the
+    /// summer
+    pub fn opposite(&self) -> Season {
+        match *self {
+            Season::Spring => Season::Fall,
+            Season::Summer => Season::Winter,
+            Season::Fall => Season::Spring,
+            Season::Winter => Season::Summer,
+        }
+    }
+}
+
+/// Conventional daily window named by "working hours" or "office hours", or the rest of the day
+/// after it for "after hours", see `ResolverContext::with_office_hours`
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
+pub enum OfficeHours {
+    During,
+    After,
+}
+
+/// Day of a holiday's date moved by the resolver, after the holiday calendar, e.g. for "the day
+/// before christmas" or "the friday after thanksgiving"
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
+pub enum HolidayOffset {
+    /// Number of days after the holiday when positive and before it when negative
+    Days(i64),
+    /// First day of the week strictly after the holiday, or before it
+    Weekday { weekday: Weekday, after: bool },
+}
+
+/// Part of a period named by "the beginning of", "mid" or "the end of", e.g. "the end of march".
+/// The period is cut in units of the next finer grain, e.g. the months of a year or the days of
+/// a month. The start and the end are the first and the last tenth of these units, rounded and at
+/// least one, and the middle is their middle third. "the beginning of 2025" is then its january,
+/// "the end of march" from the 29th to the 31st and "mid july" from the 11th to the 21st. The
+/// early and late parts are the first and last thirds, e.g. "early march" from the 1st to the
+/// 10th. A week is cut in monday and tuesday, wednesday and thursday, and its last three days.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "snake_case"))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
+pub enum PartOfPeriod {
+    Start,
+    Early,
+    Middle,
+    Late,
+    End,
+}
+
 /// Payload for the datetime value of Dimension
 #[derive(Clone)]
 pub struct DatetimeValue {
@@ -517,6 +707,59 @@ pub struct DatetimeValue {
     pub latent: bool,
     pub ambiguity: Ambiguity,
     pub datetime_kind: DatetimeKind,
+    /// Period between two occurrences for recurring datetimes, e.g. a week for "every monday"
+    pub recurrence: Option<Period>,
+    /// Number of recurrence periods from one occurrence to the next, e.g. 2 for "every 2nd
+    /// monday", 1 when every period has an occurrence
+    pub recurrence_interval: u32,
+    /// Name of the holiday the datetime was built from, see `holidays::HolidayProvider`
+    pub holiday: Option<&'static str>,
+    /// Day relative to the holiday the datetime is, e.g. the day before it for "the day before
+    /// christmas"
+    pub holiday_offset: Option<HolidayOffset>,
+    /// Number of working days from the reference for "in 3 business days", see
+    /// `holidays::WorkingDays`
+    pub business_days: Option<i64>,
+    /// Built from "next <day-of-week>", see `WeekdayPolicy::NextStrict`
+    pub next_weekday: bool,
+    /// Built from "noon" or "midnight", which are always to come: the occurrence which started
+    /// before the reference is skipped, e.g. today's noon at 12:30
+    pub upcoming: bool,
+    /// Quarter identified by its number, e.g. "Q1 2025", moved by the resolver to the quarter of
+    /// the fiscal year, see `ResolverContext::with_fiscal_year_start`
+    pub fiscal_quarter: bool,
+    /// Time of day given on the 12-hour clock with am or pm, e.g. "5pm", see
+    /// `DatetimeOutput::was_12_hour`
+    pub was_12_hour: bool,
+    /// Finest grain at which the datetime is computed from the reference, e.g. `Hour` for "in
+    /// 2 hours" or `Day` for "tomorrow", `None` if it doesn't depend on the reference time. See
+    /// `FuzzyReferencePolicy`
+    pub relative_grain: Option<Grain>,
+    /// Timezone named in the sentence, e.g. "3pm EST", which overrides the one of the
+    /// `ResolverContext`
+    pub timezone: Option<Tz>,
+    /// Part of the period the interval is narrowed to by the resolver, e.g. for "the end of march"
+    pub part_of_period: Option<PartOfPeriod>,
+    /// Duration applied by the resolver to the resolved interval, after its end when positive and
+    /// before its start when negative, e.g. 2 hours for "2 hours after 3pm"
+    pub offset: Option<Period>,
+    /// Year given with two digits, e.g. "'99" or "3/4/21", read in the century starting at the
+    /// `TwoDigitYearPivot` of the resolver
+    pub two_digit_year: bool,
+    /// Year given by a lone number, e.g. "2024", which is rather a count when it is out of the
+    /// `ResolverContext::prefer_year_range`. Cleared when the context confirms it, e.g. "in 2024"
+    pub lone_year: bool,
+    /// Numeric date whose day and month can be swapped, e.g. "03/04/2024": the order the grammar
+    /// read it in, with the constraint of the other reading, see `DateOrder`
+    pub swapped_date: Option<(DateOrder, RcConstraint<Local>)>,
+    /// Season the datetime was built from, e.g. "this summer", see `Season`
+    pub season: Option<Season>,
+    /// Constraint of the same season in the southern hemisphere, e.g. from december to march for
+    /// the summer
+    pub southern_season: Option<RcConstraint<Local>>,
+    /// Office hours of the day the datetime was built from, e.g. "working hours today", moved by
+    /// the resolver to the office hours of the context
+    pub office_hours: Option<OfficeHours>,
 }
 
 // We need partial eq to make Dimension partial eq happy, but this is only
@@ -542,11 +785,11 @@ impl ::std::fmt::Debug for DatetimeValue {
 
 impl DatetimeValue {
     pub fn is_coarse_grain_smaller_than(&self, grain: Grain) -> bool {
-        (self.constraint.coarse_grain_step() as usize) < (grain as usize)
+        self.constraint.coarse_grain_step() < grain
     }
 
     pub fn is_coarse_grain_greater_than(&self, grain: Grain) -> bool {
-        (self.constraint.coarse_grain_step() as usize) > (grain as usize)
+        self.constraint.coarse_grain_step() > grain
     }
 
     pub fn has_period_form(&self) -> bool {
@@ -554,6 +797,7 @@ impl DatetimeValue {
             Form::Cycle(grain) => match grain {
                 Grain::Day => false,
                 Grain::Second => false,
+                Grain::Millisecond => false,
                 _ => true,
             },
             Form::Year(_) => true,
@@ -582,6 +826,8 @@ impl DatetimeValue {
             Grain::Month => true,
             Grain::Quarter => true,
             Grain::Year => true,
+            Grain::Decade => true,
+            Grain::Century => true,
             _ => false,
         }
     }
@@ -613,7 +859,7 @@ pub enum Form {
     MonthDay(Option<MonthDayForm>),
     YearMonthDay(Option<YearMonthDayForm>),
     TimeOfDay(TimeOfDayForm),
-    DayOfWeek { not_immediate: bool },
+    DayOfWeek { weekday: Weekday, not_immediate: bool },
     PartOfDay(PartOfDayForm),
     PartOfWeek,
     PartOfMonth,
@@ -635,7 +881,7 @@ impl Form {
             &Form::MonthDay(_) => None,
             &Form::YearMonthDay(_) => None,
             &Form::TimeOfDay(_) => None,
-            &Form::DayOfWeek { not_immediate } => Some(not_immediate),
+            &Form::DayOfWeek { not_immediate, .. } => Some(not_immediate),
             &Form::Empty => None,
             &Form::PartOfDay { .. } => None,
             &Form::Meal => None,
@@ -674,18 +920,24 @@ impl Form {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "snake_case"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Direction {
     After,
     Before,
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "snake_case"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Bound {
     Start,
     End { only_interval: bool },
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BoundedDirection {
     pub bound: Bound,
@@ -860,9 +1112,6 @@ pub struct YearMonthDayForm {
     pub day_of_month: u32,
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct PercentageValue(pub f64);
-
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum FromAddition {
     Left,
@@ -876,6 +1125,8 @@ pub struct DurationValue {
     pub suffixed: bool,
     pub prefixed: bool,
     pub from_addition: Option<FromAddition>,
+    /// Days only count working days, e.g. "3 business days"
+    pub business_days: bool,
 }
 
 impl DurationValue {
@@ -886,6 +1137,15 @@ impl DurationValue {
             suffixed: false,
             prefixed: false,
             from_addition: None,
+            business_days: false,
+        }
+    }
+
+    /// Marks the days of the duration as working days
+    pub fn business_days(self) -> DurationValue {
+        DurationValue {
+            business_days: true,
+            ..self
         }
     }
 
@@ -0,0 +1,700 @@
+//! JSON layout of Facebook duckling, enabled by the `duckling` feature, to ease the migration of
+//! its users.
+//!
+//! The layout is copied as is, including its oddities: datetimes are given with milliseconds
+//! whatever their grain and repeated in a `values` list, durations are keyed by their finest
+//! grain and normalized to seconds counting 30 days per month. As there is a single resolution per
+//! output, `values` only holds this one. The dimensions unknown to duckling, i.e. percentages and
+//! ordinal ranges, get a layout of the same shape.
+//!
+//! Entities of this layout are read back with `from_duckling_json`, e.g. to compare a corpus
+//! labeled with duckling to the resolution of the ontology.
+use moment::*;
+use serde_crate::ser::{Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use crate::dimension::{CompareOp, DatetimeKind, Precision};
+use crate::output::*;
+use crate::serialization::static_unit;
+
+/// Serializes an output as a duckling entity, e.g.
+/// `{"dim": "time", "latent": false, "value": {"value": "2013-02-13T00:00:00.000-02:00",
+/// "grain": "day", "type": "value", "values": [...]}}`
+#[derive(Debug, Clone, Copy)]
+pub struct DucklingCompat<'a> {
+    output: &'a Output,
+    span: Option<(&'a str, usize, usize)>,
+}
+
+impl<'a> DucklingCompat<'a> {
+    pub fn new(output: &'a Output) -> DucklingCompat<'a> {
+        DucklingCompat { output, span: None }
+    }
+
+    /// Adds the matched text and its char range, given by duckling as `body`, `start` and `end`
+    pub fn with_span(self, body: &'a str, start: usize, end: usize) -> DucklingCompat<'a> {
+        DucklingCompat {
+            span: Some((body, start, end)),
+            ..self
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut entity = Map::new();
+        if let Some((body, start, end)) = self.span {
+            entity.insert("body".to_string(), Value::from(body));
+            entity.insert("start".to_string(), Value::from(start));
+            entity.insert("end".to_string(), Value::from(end));
+        }
+        entity.insert("dim".to_string(), Value::from(dim(self.output)));
+        entity.insert("latent".to_string(), Value::from(self.output.is_latent()));
+        entity.insert("value".to_string(), value(self.output));
+        Value::Object(entity)
+    }
+}
+
+impl<'a> Serialize for DucklingCompat<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+fn dim(output: &Output) -> &'static str {
+    match output {
+        &Output::Integer(_)
+        | &Output::Float(_)
+        | &Output::NumberRange(_)
+        | &Output::NumberComparison(_) => "number",
+        &Output::Percentage(_) => "percentage",
+        &Output::Ordinal(_) | &Output::OrdinalRange(_) => "ordinal",
+        &Output::Datetime(_) | &Output::DatetimeInterval(_) | &Output::DatetimeRecurring(_) => "time",
+        &Output::AmountOfMoney(_) => "amount-of-money",
+        &Output::Temperature(_) | &Output::TemperatureRange(_) => "temperature",
+        &Output::Duration(_) => "duration",
+        &Output::Quantity(_) => "quantity",
+        &Output::PhoneNumber(_) => "phone-number",
+        &Output::Age(_) => "age",
+        &Output::Custom(_) => "custom",
+    }
+}
+
+fn object(fields: Vec<(&str, Value)>) -> Value {
+    Value::Object(
+        fields
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+/// Whole numbers are given without decimals, as by duckling
+fn number(value: f64) -> Value {
+    if value.fract() == 0.0 && value.abs() < i64::max_value() as f64 {
+        Value::from(value as i64)
+    } else {
+        Value::from(value)
+    }
+}
+
+fn simple_value(value: Value) -> Value {
+    object(vec![("value", value), ("type", Value::from("value"))])
+}
+
+/// Adds the `values` list duckling gives along with the datetimes
+fn with_values(value: Value) -> Value {
+    match value {
+        Value::Object(mut fields) => {
+            let values = Value::Array(vec![Value::Object(fields.clone())]);
+            fields.insert("values".to_string(), values);
+            Value::Object(fields)
+        }
+        value => value,
+    }
+}
+
+fn value(output: &Output) -> Value {
+    match output {
+        &Output::Integer(ref integer) => simple_value(Value::from(integer.0)),
+        &Output::Float(ref float) => simple_value(number(float.0)),
+        &Output::Percentage(ref percentage) => simple_value(number(percentage.0)),
+        &Output::Ordinal(ref ordinal) => simple_value(Value::from(ordinal.0)),
+        &Output::OrdinalRange(ref range) => object(vec![
+            ("from", object(vec![("value", Value::from(range.start))])),
+            ("to", object(vec![("value", Value::from(range.end))])),
+            ("type", Value::from("interval")),
+        ]),
+        &Output::Datetime(ref datetime) => with_values(datetime_value(datetime)),
+        &Output::DatetimeInterval(ref interval) => with_values(interval_value(interval)),
+        &Output::DatetimeRecurring(ref recurring) => with_values(datetime_value(&recurring.first)),
+        &Output::AmountOfMoney(ref amount) => object(vec![
+            ("value", number(amount.value)),
+            ("type", Value::from("value")),
+            ("unit", Value::from(amount.unit.unwrap_or("unknown"))),
+        ]),
+        &Output::Temperature(ref temperature) => {
+            let mut fields = vec![
+                ("value", number(temperature.value)),
+                ("type", Value::from("value")),
+            ];
+            if let Some(unit) = temperature.unit {
+                fields.push(("unit", Value::from(unit)));
+            }
+            object(fields)
+        }
+        &Output::TemperatureRange(ref range) => {
+            let bound = |value: f64| {
+                let mut fields = vec![("value", number(value))];
+                if let Some(unit) = range.unit {
+                    fields.push(("unit", Value::from(unit)));
+                }
+                object(fields)
+            };
+            object(vec![
+                ("from", bound(range.low)),
+                ("to", bound(range.high)),
+                ("type", Value::from("interval")),
+            ])
+        }
+        &Output::Duration(ref duration) => duration_value(duration),
+        &Output::Quantity(ref quantity) => {
+            let mut fields = vec![
+                ("value", number(quantity.value)),
+                ("type", Value::from("value")),
+            ];
+            if let Some(ref unit) = quantity.unit {
+                fields.push(("unit", Value::from(unit.as_str())));
+            }
+            if let Some(ref product) = quantity.product {
+                fields.push(("product", Value::from(product.as_str())));
+            }
+            object(fields)
+        }
+        &Output::PhoneNumber(ref phone_number) => simple_value(Value::from(phone_number.0.as_str())),
+        &Output::NumberRange(ref range) => object(vec![
+            ("from", bound(range.low, range.low_inclusive)),
+            ("to", bound(range.high, range.high_inclusive)),
+            ("type", Value::from("interval")),
+        ]),
+        // A comparison is an interval open on one side
+        &Output::NumberComparison(ref comparison) => {
+            let side = match comparison.op {
+                CompareOp::Gt | CompareOp::Ge => "from",
+                CompareOp::Lt | CompareOp::Le => "to",
+            };
+            let inclusive = comparison.op == CompareOp::Ge || comparison.op == CompareOp::Le;
+            object(vec![
+                (side, bound(comparison.value, inclusive)),
+                ("type", Value::from("interval")),
+            ])
+        }
+        &Output::Age(ref age) => object(vec![
+            ("value", number(age.value)),
+            ("unit", Value::from(grain_name(age.unit))),
+            ("type", Value::from("value")),
+        ]),
+        &Output::Custom(ref custom) => object(vec![
+            ("value", Value::from(custom.value.as_str())),
+            ("tag", Value::from(custom.tag.as_str())),
+            ("type", Value::from("value")),
+        ]),
+    }
+}
+
+fn bound(value: f64, inclusive: bool) -> Value {
+    object(vec![("value", number(value)), ("inclusive", Value::from(inclusive))])
+}
+
+/// Duckling has no grain finer than the second nor coarser than the year
+fn grain_name(grain: Grain) -> &'static str {
+    match grain {
+        Grain::Century | Grain::Decade | Grain::Year => "year",
+        Grain::Quarter => "quarter",
+        Grain::Month => "month",
+        Grain::Week => "week",
+        Grain::Day => "day",
+        Grain::Hour => "hour",
+        Grain::Minute => "minute",
+        Grain::Second | Grain::Millisecond => "second",
+    }
+}
+
+fn format_moment(moment: &Moment<Local>, timezone: Option<Tz>) -> Value {
+    let offset = utc_offset_at(moment, timezone);
+    Value::from(
+        moment
+            .0
+            .with_timezone(&offset)
+            .format("%Y-%m-%dT%H:%M:%S%.3f%:z")
+            .to_string(),
+    )
+}
+
+fn datetime_value(datetime: &DatetimeOutput) -> Value {
+    object(vec![
+        ("value", format_moment(&datetime.moment, datetime.timezone)),
+        ("grain", Value::from(grain_name(datetime.grain))),
+        ("type", Value::from("value")),
+    ])
+}
+
+fn interval_value(interval: &DatetimeIntervalOutput) -> Value {
+    let bound = |moment: &Moment<Local>, grain: Grain| {
+        object(vec![
+            ("value", format_moment(moment, interval.timezone)),
+            ("grain", Value::from(grain_name(grain))),
+        ])
+    };
+    let mut fields = vec![];
+    match interval.interval_kind {
+        DatetimeIntervalKind::After { ref datetime, .. } => {
+            fields.push(("from", bound(&datetime.moment, datetime.grain)))
+        }
+        DatetimeIntervalKind::Before { ref datetime, .. } => {
+            fields.push(("to", bound(&datetime.moment, datetime.grain)))
+        }
+        DatetimeIntervalKind::Between {
+            ref start,
+            ref end,
+            start_grain,
+            end_grain,
+            ..
+        } => {
+            fields.push(("from", bound(start, start_grain)));
+            fields.push(("to", bound(end, end_grain)));
+        }
+    }
+    fields.push(("type", Value::from("interval")));
+    object(fields)
+}
+
+/// Number of milliseconds in a grain, with duckling's 30 days months and 365 days years
+fn grain_millis(grain: Grain) -> i64 {
+    let day = 24 * 3600 * 1000;
+    match grain {
+        Grain::Century => 100 * 365 * day,
+        Grain::Decade => 10 * 365 * day,
+        Grain::Year => 365 * day,
+        Grain::Quarter => 90 * day,
+        Grain::Month => 30 * day,
+        Grain::Week => 7 * day,
+        Grain::Day => day,
+        Grain::Hour => 3600 * 1000,
+        Grain::Minute => 60 * 1000,
+        Grain::Second => 1000,
+        Grain::Millisecond => 1,
+    }
+}
+
+fn duration_value(duration: &DurationOutput) -> Value {
+    let millis: i64 = duration
+        .period
+        .comps()
+        .iter()
+        .map(|comp| comp.quantity * grain_millis(comp.grain))
+        .sum();
+    let grain = match duration.period.finer_grain() {
+        Some(Grain::Decade) | Some(Grain::Century) => Grain::Year,
+        Some(Grain::Millisecond) | None => Grain::Second,
+        Some(grain) => grain,
+    };
+    let quantity = if millis % grain_millis(grain) == 0 {
+        Value::from(millis / grain_millis(grain))
+    } else {
+        Value::from(millis as f64 / grain_millis(grain) as f64)
+    };
+    let seconds = if millis % 1000 == 0 {
+        Value::from(millis / 1000)
+    } else {
+        Value::from(millis as f64 / 1000.0)
+    };
+    object(vec![
+        ("value", quantity.clone()),
+        (grain_name(grain), quantity),
+        ("type", Value::from("value")),
+        ("unit", Value::from(grain_name(grain))),
+        (
+            "normalized",
+            object(vec![("value", seconds), ("unit", Value::from("second"))]),
+        ),
+    ])
+}
+
+/// Error returned by `from_duckling_json`
+#[derive(Debug, Fail)]
+pub enum DucklingError {
+    #[fail(display = "Missing or invalid field {} in {}", field, json)]
+    InvalidField { field: &'static str, json: Value },
+    #[fail(display = "Entities of dimension {} can't be read back", dim)]
+    UnsupportedDim { dim: String },
+}
+
+/// Reads back an output from a duckling entity, the inverse of `DucklingCompat::to_json`. The
+/// `values` list is ignored, the entity being read from its `value`. What duckling doesn't give
+/// gets a default: precisions are exact, datetimes are in the local timezone with a kind given by
+/// their grain, "from" alone is inclusive and "to" alone exclusive, and whole numbers are
+/// integers. Durations are read in their single unit, e.g. 90 minutes for "1 hour and a half".
+pub fn from_duckling_json(json: &Value) -> Result<Output, DucklingError> {
+    let dim = str_field(json, "dim")?;
+    let latent = json.get("latent").and_then(Value::as_bool).unwrap_or(false);
+    let value = field(json, "value")?;
+    let is_interval = value.get("type").and_then(Value::as_str) == Some("interval");
+    match dim {
+        "number" if is_interval => {
+            let bound = |name: &str| -> Result<Option<(f64, bool)>, DucklingError> {
+                match value.get(name) {
+                    Some(bound) => Ok(Some((
+                        f64_field(bound, "value")?,
+                        bound.get("inclusive").and_then(Value::as_bool).unwrap_or(true),
+                    ))),
+                    None => Ok(None),
+                }
+            };
+            match (bound("from")?, bound("to")?) {
+                (Some((low, low_inclusive)), Some((high, high_inclusive))) => {
+                    Ok(Output::NumberRange(NumberRangeOutput {
+                        low,
+                        high,
+                        low_inclusive,
+                        high_inclusive,
+                    }))
+                }
+                (Some((value, inclusive)), None) => Ok(Output::NumberComparison(NumberComparisonOutput {
+                    op: if inclusive { CompareOp::Ge } else { CompareOp::Gt },
+                    value,
+                })),
+                (None, Some((value, inclusive))) => Ok(Output::NumberComparison(NumberComparisonOutput {
+                    op: if inclusive { CompareOp::Le } else { CompareOp::Lt },
+                    value,
+                })),
+                (None, None) => Err(invalid("from", value)),
+            }
+        }
+        "number" => match field(value, "value")?.as_i64() {
+            Some(integer) => Ok(Output::Integer(IntegerOutput(integer))),
+            None => Ok(Output::Float(FloatOutput(f64_field(value, "value")?))),
+        },
+        "percentage" => Ok(Output::Percentage(PercentageOutput(f64_field(value, "value")?))),
+        "ordinal" if is_interval => Ok(Output::OrdinalRange(OrdinalRangeOutput {
+            start: i64_field(field(value, "from")?, "value")?,
+            end: i64_field(field(value, "to")?, "value")?,
+        })),
+        "ordinal" => Ok(Output::Ordinal(OrdinalOutput(i64_field(value, "value")?))),
+        "time" if is_interval => interval_from_json(value, latent).map(Output::DatetimeInterval),
+        "time" => datetime_from_json(value, latent, false).map(Output::Datetime),
+        "amount-of-money" => {
+            let unit = match str_field(value, "unit")? {
+                "unknown" => None,
+                unit => Some(static_unit(unit)),
+            };
+            Ok(Output::AmountOfMoney(AmountOfMoneyOutput {
+                value: f64_field(value, "value")?,
+                precision: Precision::Exact,
+                unit,
+                currency: unit.and_then(Currency::from_unit),
+            }))
+        }
+        "temperature" if is_interval => {
+            let (from, to) = (field(value, "from")?, field(value, "to")?);
+            let unit = from
+                .get("unit")
+                .or_else(|| to.get("unit"))
+                .and_then(Value::as_str)
+                .map(static_unit);
+            Ok(Output::TemperatureRange(TemperatureRangeOutput {
+                low: f64_field(from, "value")?,
+                high: f64_field(to, "value")?,
+                unit,
+            }))
+        }
+        "temperature" => Ok(Output::Temperature(TemperatureOutput {
+            value: f64_field(value, "value")?,
+            unit: value
+                .get("unit")
+                .and_then(Value::as_str)
+                .map(static_unit),
+            latent,
+            qualifier: None,
+        })),
+        "duration" => {
+            let grain = grain_from_name(str_field(value, "unit")?).ok_or_else(|| invalid("unit", value))?;
+            let quantity = f64_field(value, "value")?;
+            // A fraction of the grain, e.g. 0.25 second for "250ms", is read in milliseconds
+            let period = if quantity.fract() == 0.0 {
+                PeriodComp::new(grain, quantity as i64)
+            } else {
+                PeriodComp::new(Grain::Millisecond, (quantity * grain_millis(grain) as f64).round() as i64)
+            };
+            Ok(Output::Duration(DurationOutput {
+                period: period.into(),
+                precision: Precision::Exact,
+            }))
+        }
+        dim => Err(DucklingError::UnsupportedDim { dim: dim.to_string() }),
+    }
+}
+
+fn invalid(field: &'static str, json: &Value) -> DucklingError {
+    DucklingError::InvalidField {
+        field,
+        json: json.clone(),
+    }
+}
+
+fn field<'a>(json: &'a Value, name: &'static str) -> Result<&'a Value, DucklingError> {
+    json.get(name).ok_or_else(|| invalid(name, json))
+}
+
+fn str_field<'a>(json: &'a Value, name: &'static str) -> Result<&'a str, DucklingError> {
+    field(json, name)?.as_str().ok_or_else(|| invalid(name, json))
+}
+
+fn f64_field(json: &Value, name: &'static str) -> Result<f64, DucklingError> {
+    field(json, name)?.as_f64().ok_or_else(|| invalid(name, json))
+}
+
+fn i64_field(json: &Value, name: &'static str) -> Result<i64, DucklingError> {
+    field(json, name)?.as_i64().ok_or_else(|| invalid(name, json))
+}
+
+fn grain_from_name(name: &str) -> Option<Grain> {
+    match name {
+        "year" => Some(Grain::Year),
+        "quarter" => Some(Grain::Quarter),
+        "month" => Some(Grain::Month),
+        "week" => Some(Grain::Week),
+        "day" => Some(Grain::Day),
+        "hour" => Some(Grain::Hour),
+        "minute" => Some(Grain::Minute),
+        "second" => Some(Grain::Second),
+        _ => None,
+    }
+}
+
+fn datetime_kind(grain: Grain, period: bool) -> DatetimeKind {
+    match (grain.is_date_grain(), period) {
+        (true, false) => DatetimeKind::Date,
+        (true, true) => DatetimeKind::DatePeriod,
+        (false, false) => DatetimeKind::Time,
+        (false, true) => DatetimeKind::TimePeriod,
+    }
+}
+
+/// Moment and grain of a datetime or of a bound of an interval
+fn moment_from_json(json: &Value) -> Result<(Moment<Local>, Grain), DucklingError> {
+    let moment = DateTime::parse_from_rfc3339(str_field(json, "value")?).map_err(|_| invalid("value", json))?;
+    let grain = grain_from_name(str_field(json, "grain")?).ok_or_else(|| invalid("grain", json))?;
+    Ok((Moment(moment.with_timezone(&Local)), grain))
+}
+
+fn datetime_from_json(json: &Value, latent: bool, period: bool) -> Result<DatetimeOutput, DucklingError> {
+    let (moment, grain) = moment_from_json(json)?;
+    Ok(DatetimeOutput {
+        moment,
+        grain,
+        precision: Precision::Exact,
+        latent,
+        datetime_kind: datetime_kind(grain, period),
+        timezone: None,
+        part_of_period: None,
+        was_12_hour: false,
+    })
+}
+
+fn interval_from_json(json: &Value, latent: bool) -> Result<DatetimeIntervalOutput, DucklingError> {
+    let (interval_kind, grain) = match (json.get("from"), json.get("to")) {
+        (Some(from), Some(to)) => {
+            let (start, start_grain) = moment_from_json(from)?;
+            let (end, end_grain) = moment_from_json(to)?;
+            let grain = ::std::cmp::max(start_grain, end_grain);
+            let between = DatetimeIntervalKind::Between {
+                start,
+                end,
+                grain,
+                start_grain,
+                end_grain,
+                precision: Precision::Exact,
+                latent,
+            };
+            (between, grain)
+        }
+        (Some(from), None) => {
+            let datetime = datetime_from_json(from, latent, true)?;
+            (DatetimeIntervalKind::After { datetime, inclusive: true }, datetime.grain)
+        }
+        (None, Some(to)) => {
+            let datetime = datetime_from_json(to, latent, true)?;
+            (DatetimeIntervalKind::Before { datetime, inclusive: false }, datetime.grain)
+        }
+        (None, None) => return Err(invalid("from", json)),
+    };
+    Ok(DatetimeIntervalOutput {
+        interval_kind,
+        datetime_kind: datetime_kind(grain, true),
+        timezone: None,
+        part_of_period: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(moment: Moment<Local>, grain: Grain) -> DatetimeOutput {
+        DatetimeOutput {
+            moment,
+            grain,
+            precision: Precision::Exact,
+            latent: false,
+            datetime_kind: DatetimeKind::Date,
+            timezone: Some(Tz::America__Sao_Paulo),
+            part_of_period: None,
+            was_12_hour: false,
+        }
+    }
+
+    fn sao_paulo(y: i32, m: u32, d: u32, h: u32) -> Moment<Local> {
+        Moment(Tz::America__Sao_Paulo.ymd(y, m, d).and_hms(h, 0, 0).with_timezone(&Local))
+    }
+
+    #[test]
+    fn test_duckling_interval() {
+        let interval = Output::DatetimeInterval(DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Between {
+                start: sao_paulo(2013, 2, 13, 15),
+                end: sao_paulo(2013, 2, 13, 18),
+                grain: Grain::Hour,
+                start_grain: Grain::Hour,
+                end_grain: Grain::Hour,
+                precision: Precision::Exact,
+                latent: false,
+            },
+            datetime_kind: DatetimeKind::DatetimeComplement {
+                date_and_time: true,
+                today: false,
+            },
+            timezone: Some(Tz::America__Sao_Paulo),
+            part_of_period: None,
+        });
+        let expected: Value = serde_json::from_str(
+            r#"{"dim": "time", "latent": false, "value": {
+                "values": [{
+                    "to": {"value": "2013-02-13T18:00:00.000-02:00", "grain": "hour"},
+                    "from": {"value": "2013-02-13T15:00:00.000-02:00", "grain": "hour"},
+                    "type": "interval"
+                }],
+                "to": {"value": "2013-02-13T18:00:00.000-02:00", "grain": "hour"},
+                "from": {"value": "2013-02-13T15:00:00.000-02:00", "grain": "hour"},
+                "type": "interval"
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(expected, DucklingCompat::new(&interval).to_json());
+    }
+
+    #[test]
+    fn test_duckling_recurring() {
+        let every_day = Output::DatetimeRecurring(DatetimeRecurringOutput {
+            first: datetime(sao_paulo(2013, 2, 13, 0), Grain::Day),
+            period: PeriodComp::days(1).into(),
+            interval: 1,
+        });
+        let json = DucklingCompat::new(&every_day).to_json();
+        assert_eq!("2013-02-13T00:00:00.000-02:00", json["value"]["value"]);
+        assert_eq!(1, json["value"]["values"].as_array().unwrap().len());
+    }
+
+    #[test]
+    fn test_duckling_duration() {
+        let duration = Output::Duration(DurationOutput {
+            period: Period::from(PeriodComp::hours(1)) + PeriodComp::minutes(30),
+            precision: Precision::Exact,
+        });
+        let json = DucklingCompat::new(&duration).to_json();
+        assert_eq!(90, json["value"]["minute"]);
+        assert_eq!("minute", json["value"]["unit"]);
+        assert_eq!(5400, json["value"]["normalized"]["value"]);
+    }
+
+    #[test]
+    fn test_from_duckling_json() {
+        let round_trip = |output: Output| {
+            assert_eq!(output, from_duckling_json(&DucklingCompat::new(&output).to_json()).unwrap());
+        };
+        round_trip(Output::Datetime(DatetimeOutput {
+            timezone: None,
+            ..datetime(Moment(Local.ymd(2013, 2, 13).and_hms(0, 0, 0)), Grain::Day)
+        }));
+        round_trip(Output::DatetimeInterval(DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Between {
+                start: Moment(Local.ymd(2013, 2, 13).and_hms(15, 0, 0)),
+                end: Moment(Local.ymd(2013, 2, 13).and_hms(18, 0, 0)),
+                grain: Grain::Hour,
+                start_grain: Grain::Hour,
+                end_grain: Grain::Hour,
+                precision: Precision::Exact,
+                latent: false,
+            },
+            datetime_kind: DatetimeKind::TimePeriod,
+            timezone: None,
+            part_of_period: None,
+        }));
+        round_trip(Output::NumberRange(NumberRangeOutput {
+            low: 5.0,
+            high: 10.0,
+            low_inclusive: true,
+            high_inclusive: true,
+        }));
+        round_trip(Output::NumberComparison(NumberComparisonOutput {
+            op: CompareOp::Gt,
+            value: 100.0,
+        }));
+        round_trip(Output::NumberComparison(NumberComparisonOutput {
+            op: CompareOp::Le,
+            value: 50.0,
+        }));
+        round_trip(Output::TemperatureRange(TemperatureRangeOutput {
+            low: 20.0,
+            high: 25.0,
+            unit: Some("celsius"),
+        }));
+        round_trip(Output::AmountOfMoney(AmountOfMoneyOutput {
+            value: 10.5,
+            precision: Precision::Exact,
+            unit: Some("EUR"),
+            currency: Some(Currency::Eur),
+        }));
+        round_trip(Output::AmountOfMoney(AmountOfMoneyOutput {
+            value: 3.0,
+            precision: Precision::Exact,
+            unit: None,
+            currency: None,
+        }));
+        round_trip(Output::Integer(IntegerOutput(42)));
+        round_trip(Output::Float(FloatOutput(4.2)));
+        round_trip(Output::Duration(DurationOutput {
+            period: PeriodComp::new(Grain::Hour, 3).into(),
+            precision: Precision::Exact,
+        }));
+        // "250ms" is given as 0.25 second
+        round_trip(Output::Duration(DurationOutput {
+            period: PeriodComp::new(Grain::Millisecond, 250).into(),
+            precision: Precision::Exact,
+        }));
+        // Duckling's offset is kept, the moment being read in the local timezone
+        let json: Value = serde_json::from_str(
+            r#"{"dim": "time", "latent": false, "value": {
+                "from": {"value": "2013-02-13T15:00:00.000-02:00", "grain": "hour"},
+                "type": "interval"
+            }}"#,
+        )
+        .unwrap();
+        match from_duckling_json(&json).unwrap() {
+            Output::DatetimeInterval(DatetimeIntervalOutput {
+                interval_kind: DatetimeIntervalKind::After { datetime, .. },
+                ..
+            }) => assert_eq!(sao_paulo(2013, 2, 13, 15), datetime.moment),
+            other => panic!("{:?}", other),
+        }
+        assert!(from_duckling_json(&serde_json::from_str(r#"{"dim": "email", "value": {}}"#).unwrap()).is_err());
+    }
+}
@@ -2,6 +2,7 @@ use crate::dimension::*;
 use moment::*;
 use regex::Regex;
 use rustling::{RuleError, RuleResult};
+use std::cmp;
 use std::ops;
 
 pub fn compose_numbers(a: &NumberValue, b: &NumberValue) -> RuleResult<NumberValue> {
@@ -75,6 +76,20 @@ pub fn decimal_hour_in_minute(a: &str, b: &str) -> RuleResult<i64> {
     Ok((b_value * 6) / 10i64.pow(b.len() as u32 - 1) + a_value * 60)
 }
 
+/// Number in scientific notation from its mantissa and exponent, e.g. "1.5" and "3" for "1.5e3",
+/// an integer if it is whole, a float otherwise
+pub fn scientific_number(mantissa: &str, exponent: &str) -> RuleResult<NumberValue> {
+    let value: f64 = format!("{}e{}", mantissa, exponent).parse()?;
+    if !value.is_finite() {
+        return Err(RuleError::Invalid.into());
+    }
+    if value.fract() == 0.0 && value.abs() < 1e18 {
+        Ok(NumberValue::Integer(IntegerValue::new(value as i64)?))
+    } else {
+        Ok(NumberValue::Float(FloatValue::new(value)?))
+    }
+}
+
 pub fn compose_money(
     a: &AmountOfMoneyValue,
     b: &AmountOfMoneyValue,
@@ -165,10 +180,24 @@ impl TimeOfDayForm {
 }
 
 pub fn precision_resolution(lhs: Precision, rhs: Precision) -> Precision {
-    if lhs == Precision::Approximate || rhs == Precision::Approximate {
-        Precision::Approximate
-    } else {
-        Precision::Exact
+    lhs.loosest(rhs)
+}
+// A holiday is only kept when narrowed down by a coarser datetime, e.g. "easter 2025", since the
+// holiday calendar gives whole days.
+fn holiday_resolution(lhs: &DatetimeValue, rhs: &DatetimeValue) -> Option<&'static str> {
+    match (lhs.holiday, rhs.holiday) {
+        (Some(name), None) if rhs.constraint.grain() < Grain::Day => Some(name),
+        (None, Some(name)) if lhs.constraint.grain() < Grain::Day => Some(name),
+        _ => None,
+    }
+}
+/// The part of a period is kept when the other datetime doesn't narrow the period, e.g. for "the
+/// end of march 2025" but not for "the end of march at 3pm"
+fn part_of_period_resolution(lhs: &DatetimeValue, rhs: &DatetimeValue) -> Option<PartOfPeriod> {
+    match (lhs.part_of_period, rhs.part_of_period) {
+        (Some(part), None) if rhs.constraint.grain() <= lhs.constraint.grain() => Some(part),
+        (None, Some(part)) if lhs.constraint.grain() <= rhs.constraint.grain() => Some(part),
+        _ => None,
     }
 }
 fn from_addition_resolution(lhs: Option<FromAddition>, rhs: Option<FromAddition>) -> FromAddition {
@@ -191,6 +220,25 @@ impl DatetimeValue {
             // Could be filled through rules too, not only in tagger
             // (but could be overridden before the end of parsing)
             datetime_kind: DatetimeKind::Empty,
+            recurrence: None,
+            recurrence_interval: 1,
+            holiday: None,
+            holiday_offset: None,
+            business_days: None,
+            next_weekday: false,
+            upcoming: false,
+            fiscal_quarter: false,
+            was_12_hour: false,
+            relative_grain: None,
+            timezone: None,
+            part_of_period: None,
+            offset: None,
+            two_digit_year: false,
+            lone_year: false,
+            swapped_date: None,
+            season: None,
+            southern_season: None,
+            office_hours: None,
         }
     }
 
@@ -219,6 +267,14 @@ impl DatetimeValue {
         DatetimeValue {
             latent: false,
             ambiguity: Ambiguity::No,
+            lone_year: false,
+            ..self
+        }
+    }
+
+    pub fn lone_year(self) -> DatetimeValue {
+        DatetimeValue {
+            lone_year: true,
             ..self
         }
     }
@@ -246,6 +302,109 @@ impl DatetimeValue {
         }
     }
 
+    pub fn offset(self, offset: Period) -> DatetimeValue {
+        DatetimeValue {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    pub fn recurrence(self, recurrence: Option<Period>) -> DatetimeValue {
+        DatetimeValue { recurrence, ..self }
+    }
+
+    pub fn recurring(self, period: Period) -> DatetimeValue {
+        self.recurrence(Some(period))
+    }
+
+    /// Recurrence with an occurrence every `interval` periods, e.g. a week and 2 for "every 2nd
+    /// monday"
+    pub fn recurring_every(self, period: Period, interval: u32) -> DatetimeValue {
+        DatetimeValue {
+            recurrence_interval: interval,
+            ..self.recurring(period)
+        }
+    }
+
+    pub fn is_recurring(&self) -> bool {
+        self.recurrence.is_some()
+    }
+
+    pub fn holiday(self, name: &'static str) -> DatetimeValue {
+        DatetimeValue {
+            holiday: Some(name),
+            ..self
+        }
+    }
+
+    /// Moves a holiday to a day relative to it, e.g. "the friday after thanksgiving". Invalid if
+    /// the datetime isn't a holiday or is already moved.
+    pub fn holiday_offset(&self, offset: HolidayOffset) -> RuleResult<DatetimeValue> {
+        if self.holiday.is_none() || self.holiday_offset.is_some() {
+            return Err(RuleError::Invalid.into());
+        }
+        Ok(DatetimeValue {
+            holiday_offset: Some(offset),
+            ..self.clone()
+        })
+    }
+
+    pub fn business_days(self, n: i64) -> DatetimeValue {
+        DatetimeValue {
+            business_days: Some(n),
+            ..self
+        }
+    }
+
+    pub fn relative_grain(self, grain: Grain) -> DatetimeValue {
+        DatetimeValue {
+            relative_grain: Some(grain),
+            ..self
+        }
+    }
+
+    pub fn timezone(self, timezone: Tz) -> DatetimeValue {
+        DatetimeValue {
+            timezone: Some(timezone),
+            ..self
+        }
+    }
+
+    pub fn part_of_period(self, part_of_period: PartOfPeriod) -> DatetimeValue {
+        DatetimeValue {
+            part_of_period: Some(part_of_period),
+            ..self
+        }
+    }
+
+    pub fn mark_next_weekday(self) -> DatetimeValue {
+        DatetimeValue {
+            next_weekday: true,
+            ..self
+        }
+    }
+
+    pub fn mark_upcoming(self) -> DatetimeValue {
+        DatetimeValue {
+            upcoming: true,
+            ..self
+        }
+    }
+
+    pub fn mark_fiscal_quarter(self) -> DatetimeValue {
+        DatetimeValue {
+            fiscal_quarter: true,
+            ..self
+        }
+    }
+
+    pub fn mark_12_hour(self) -> DatetimeValue {
+        DatetimeValue {
+            was_12_hour: true,
+            ..self
+        }
+    }
+
     pub fn direction(self, direction: Option<BoundedDirection>) -> DatetimeValue {
         DatetimeValue { direction, ..self }
     }
@@ -301,11 +460,35 @@ impl DatetimeValue {
     }
 
     pub fn intersect(&self, other: &DatetimeValue) -> RuleResult<DatetimeValue> {
-        Ok(
-            DatetimeValue::constraint(self.constraint.intersect(&other.constraint))
+        Ok(DatetimeValue {
+            holiday: holiday_resolution(self, other),
+            holiday_offset: self.holiday_offset.or(other.holiday_offset),
+            business_days: self.business_days.or(other.business_days),
+            next_weekday: self.next_weekday || other.next_weekday,
+            was_12_hour: self.was_12_hour || other.was_12_hour,
+            relative_grain: cmp::max(self.relative_grain, other.relative_grain),
+            timezone: self.timezone.or(other.timezone),
+            part_of_period: part_of_period_resolution(self, other),
+            offset: self.offset.clone().or_else(|| other.offset.clone()),
+            two_digit_year: self.two_digit_year || other.two_digit_year,
+            swapped_date: match (&self.swapped_date, &other.swapped_date) {
+                (&Some((order, ref swapped)), _) => Some((order, swapped.intersect(&other.constraint))),
+                (&None, &Some((order, ref swapped))) => Some((order, self.constraint.intersect(swapped))),
+                (&None, &None) => None,
+            },
+            season: self.season.or(other.season),
+            southern_season: match (&self.southern_season, &other.southern_season) {
+                (&Some(ref southern), _) => Some(southern.intersect(&other.constraint)),
+                (&None, &Some(ref southern)) => Some(self.constraint.intersect(southern)),
+                (&None, &None) => None,
+            },
+            office_hours: self.office_hours.or(other.office_hours),
+            recurrence_interval: cmp::max(self.recurrence_interval, other.recurrence_interval),
+            ..DatetimeValue::constraint(self.constraint.intersect(&other.constraint))
                 .direction(self.direction.or(other.direction))
-                .precision(precision_resolution(self.precision, other.precision)),
-        )
+                .precision(precision_resolution(self.precision, other.precision))
+                .recurrence(self.recurrence.clone().or_else(|| other.recurrence.clone()))
+        })
     }
 
     pub fn last_of(&self, other: &DatetimeValue) -> RuleResult<DatetimeValue> {
@@ -315,15 +498,37 @@ impl DatetimeValue {
         )
     }
 
+    /// The `n`th occurrence of this datetime within each occurrence of `other`, from 0, e.g.
+    /// "the second tuesday of march". There is no result for an occurrence of `other` which is too
+    /// short, e.g. a month with four mondays for "the fifth monday of the month".
+    pub fn nth_of(&self, n: i64, other: &DatetimeValue) -> RuleResult<DatetimeValue> {
+        if n < 0 {
+            return Err(RuleError::Invalid.into());
+        }
+        Ok(
+            DatetimeValue::constraint(self.constraint.nth_of(n, &other.constraint))
+                .precision(precision_resolution(self.precision, other.precision)),
+        )
+    }
+
     pub fn the_nth(&self, n: i64) -> RuleResult<DatetimeValue> {
-        Ok(DatetimeValue::constraint(self.constraint.take_the_nth(n)).precision(self.precision))
+        Ok(DatetimeValue {
+            season: self.season,
+            southern_season: self.southern_season.as_ref().map(|southern| southern.take_the_nth(n)),
+            ..DatetimeValue::constraint(self.constraint.take_the_nth(n)).precision(self.precision)
+        })
     }
 
     pub fn the_nth_not_immediate(&self, n: i64) -> RuleResult<DatetimeValue> {
-        Ok(
-            DatetimeValue::constraint(self.constraint.take_the_nth_not_immediate(n))
-                .precision(self.precision),
-        )
+        Ok(DatetimeValue {
+            season: self.season,
+            southern_season: self
+                .southern_season
+                .as_ref()
+                .map(|southern| southern.take_the_nth_not_immediate(n)),
+            ..DatetimeValue::constraint(self.constraint.take_the_nth_not_immediate(n))
+                .precision(self.precision)
+        })
     }
 
     pub fn the_nth_after(&self, n: i64, after_value: &DatetimeValue) -> RuleResult<DatetimeValue> {
@@ -364,21 +569,20 @@ impl DatetimeValue {
     }
 
     pub fn span_to(&self, to: &DatetimeValue, is_inclusive: bool) -> RuleResult<DatetimeValue> {
-        if is_inclusive
+        let constraint = if is_inclusive
             || (self.constraint.grain() == Grain::Day && to.constraint.grain() == Grain::Day)
         {
-            Ok(
-                DatetimeValue::constraint(self.constraint.span_inclusive_to(&to.constraint))
-                    .form(Form::Span)
-                    .precision(precision_resolution(self.precision, to.precision)),
-            )
+            self.constraint.span_inclusive_to(&to.constraint)
         } else {
-            Ok(
-                DatetimeValue::constraint(self.constraint.span_to(&to.constraint))
-                    .form(Form::Span)
-                    .precision(precision_resolution(self.precision, to.precision)),
-            )
-        }
+            self.constraint.span_to(&to.constraint)
+        };
+        Ok(DatetimeValue {
+            relative_grain: cmp::max(self.relative_grain, to.relative_grain),
+            timezone: self.timezone.or(to.timezone),
+            ..DatetimeValue::constraint(constraint)
+                .form(Form::Span)
+                .precision(precision_resolution(self.precision, to.precision))
+        })
     }
 
     pub fn form_month(&self) -> RuleResult<u32> {
@@ -444,6 +648,10 @@ impl DatetimeValue {
     }
 }
 
+fn is_two_digit_year(y: i32) -> bool {
+    y <= 99 && y >= 0
+}
+
 pub fn normalize_year(y: i32) -> RuleResult<i32> {
     if y <= 99 && y >= 0 {
         Ok((y + 50) % 100 + 2000 - 50)
@@ -466,8 +674,39 @@ impl<T> MomentToRuleError for MomentResult<T> {
 
 pub fn year(y: i32) -> RuleResult<DatetimeValue> {
     // year between 0 and 99 will be normalized after 1950, e.g. 45 => 2045, 60 => 1960, 99 => 1999
+    let two_digit_year = is_two_digit_year(y);
     let y = normalize_year(y)?;
-    Ok(DatetimeValue::constraint(Year::new(y)).form(Form::Year(y)))
+    Ok(DatetimeValue {
+        two_digit_year,
+        ..DatetimeValue::constraint(Year::new(y)).form(Form::Year(y))
+    })
+}
+
+/// Decade starting with the given year, which must be a multiple of 10, e.g. 1980 for "the 1980s".
+/// A year given with two digits is read like the ones of `year`, e.g. 80 for "the 80s" is 1980.
+pub fn decade(y: i32) -> RuleResult<DatetimeValue> {
+    let two_digit_year = is_two_digit_year(y);
+    let y = normalize_year(y)?;
+    Ok(DatetimeValue {
+        two_digit_year,
+        ..DatetimeValue::constraint(Decade::new(y).invalid_if_err()?)
+    })
+}
+
+/// Century starting with the given year, which must be a multiple of 100, e.g. 1800 for "the
+/// 19th century"
+pub fn century(y: i32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(Century::new(y).invalid_if_err()?))
+}
+
+/// ISO 8601 week of the year, from 1 to 53, e.g. "week 23"
+pub fn iso_week(w: u32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(IsoWeek::new(w).invalid_if_err()?))
+}
+
+/// ISO 8601 week of the given ISO year, e.g. "week 1 of 2021" which starts in december 2020
+pub fn iso_year_week(y: i32, w: u32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(IsoYearWeek::new(y, w).invalid_if_err()?))
 }
 
 pub fn month(m: u32) -> RuleResult<DatetimeValue> {
@@ -487,6 +726,7 @@ pub fn day_of_month(dom: u32) -> RuleResult<DatetimeValue> {
 pub fn day_of_week(weekday: Weekday) -> RuleResult<DatetimeValue> {
     Ok(
         DatetimeValue::constraint(DayOfWeek::new(weekday)).form(Form::DayOfWeek {
+            weekday,
             not_immediate: true,
         }),
     )
@@ -503,17 +743,42 @@ pub fn month_day(m: u32, d: u32) -> RuleResult<DatetimeValue> {
     )
 }
 
+/// Date written with numbers in the given order, e.g. "03/04/2024" or "03/04" without the year.
+/// When both the day and the month can be read from the first and the second numbers the other
+/// reading is kept for the resolver, see `ResolverContext::with_date_order`.
+pub fn numeric_date(order: DateOrder, y: Option<i32>, first: u32, second: u32) -> RuleResult<DatetimeValue> {
+    let date = |m: u32, d: u32| match y {
+        Some(y) => year_month_day(y, m, d),
+        None => month_day(m, d),
+    };
+    let (m, d) = match order {
+        DateOrder::DayMonthYear => (second, first),
+        DateOrder::MonthDayYear | DateOrder::YearMonthDay => (first, second),
+    };
+    let value = date(m, d)?;
+    if first == second || first > 12 || second > 12 {
+        return Ok(value);
+    }
+    let swapped = date(d, m)?.constraint;
+    Ok(DatetimeValue {
+        swapped_date: Some((order, swapped)),
+        ..value
+    })
+}
+
 pub fn year_month_day(y: i32, m: u32, d: u32) -> RuleResult<DatetimeValue> {
+    let two_digit_year = is_two_digit_year(y);
     let y = normalize_year(y)?;
-    Ok(
-        DatetimeValue::constraint(YearMonthDay::new(y, m, d).invalid_if_err()?).form(
+    Ok(DatetimeValue {
+        two_digit_year,
+        ..DatetimeValue::constraint(YearMonthDay::new(y, m, d).invalid_if_err()?).form(
             Form::YearMonthDay(Some(YearMonthDayForm {
                 year: y,
                 month: m,
                 day_of_month: d,
             })),
-        ),
-    )
+        )
+    })
 }
 
 pub fn hour(h: u32, is_12_clock: bool) -> RuleResult<DatetimeValue> {
@@ -538,6 +803,10 @@ pub fn second(s: u32) -> RuleResult<DatetimeValue> {
     Ok(DatetimeValue::constraint(Second::new(s).invalid_if_err()?))
 }
 
+pub fn millisecond(ms: u32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(Millisecond::new(ms).invalid_if_err()?))
+}
+
 pub fn hour_minute(h: u32, m: u32, is_12_clock: bool) -> RuleResult<DatetimeValue> {
     if is_12_clock {
         Ok(
@@ -558,6 +827,39 @@ pub fn hour_minute_second(h: u32, m: u32, s: u32, is_12_clock: bool) -> RuleResu
         .form(Form::time_of_day_hour_minute_second(h, m, s, is_12_clock)))
 }
 
+pub fn hour_minute_second_millisecond(h: u32, m: u32, s: u32, ms: u32, is_12_clock: bool) -> RuleResult<DatetimeValue> {
+    Ok(hour_minute(h, m, is_12_clock)?
+        .intersect(&second(s)?)?
+        .intersect(&millisecond(ms)?)?
+        .form(Form::time_of_day_hour_minute_second(h, m, s, is_12_clock)))
+}
+
+/// Timezone of a common abbreviation, case insensitive, e.g. "est" or "UTC". Standard and
+/// daylight time abbreviations give the same timezone, e.g. "EST" and "EDT" are both the time in
+/// New York whatever the season. Ambiguous abbreviations are given their most common meaning:
+/// "CST" is the central time of North America, not China's, "IST" is the time in India and "BST"
+/// the British summer time, "WET" and "WEST" the time in Lisbon. The two-letter forms, e.g. "ET"
+/// or "PT", are not read as they are also ordinary words once lowercased.
+pub fn timezone_abbreviation(abbreviation: &str) -> Option<Tz> {
+    let timezone = match abbreviation.to_lowercase().as_str() {
+        "utc" | "gmt" | "z" => Tz::UTC,
+        "est" | "edt" => Tz::America__New_York,
+        "cst" | "cdt" => Tz::America__Chicago,
+        "mst" | "mdt" => Tz::America__Denver,
+        "pst" | "pdt" => Tz::America__Los_Angeles,
+        "bst" => Tz::Europe__London,
+        "wet" | "west" => Tz::Europe__Lisbon,
+        "cet" | "cest" => Tz::Europe__Paris,
+        "eet" | "eest" => Tz::Europe__Athens,
+        "msk" => Tz::Europe__Moscow,
+        "ist" => Tz::Asia__Kolkata,
+        "jst" => Tz::Asia__Tokyo,
+        "aest" | "aedt" => Tz::Australia__Sydney,
+        _ => return None,
+    };
+    Some(timezone)
+}
+
 pub fn hour_relative_minute(h: u32, m: i32, is_12_clock: bool) -> RuleResult<DatetimeValue> {
     if !(h <= 23) {
         Err(format_err!("Invalid hour {:?}", h))?
@@ -594,7 +896,28 @@ pub fn cycle(grain: Grain) -> RuleResult<DatetimeValue> {
 }
 
 pub fn cycle_nth(grain: Grain, n: i64) -> RuleResult<DatetimeValue> {
-    Ok(DatetimeValue::constraint(Cycle::rc(grain).take_the_nth(n)).form(Form::Cycle(grain)))
+    Ok(DatetimeValue::constraint(Cycle::rc(grain).take_the_nth(n))
+        .form(Form::Cycle(grain))
+        .relative_grain(grain))
+}
+
+/// Few years or months around the start of the current century, decade or year, e.g. "the
+/// turn of the century" from 1999 to 2001 from 2024: the unit before the start, the one starting
+/// the period and the one after it, in years for centuries and decades and in months for years.
+/// The turn is always the one which started the current period, not the upcoming one.
+pub fn turn_of(grain: Grain) -> RuleResult<DatetimeValue> {
+    let unit = match grain {
+        Grain::Century | Grain::Decade => Grain::Year,
+        Grain::Year => Grain::Month,
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    let period = Cycle::rc(grain).take_the_nth(0);
+    let start = Cycle::rc(unit).the_nth(-1).after(&period);
+    let end = Cycle::rc(unit).the_nth(1).after(&period);
+    Ok(DatetimeValue::constraint(start.span_inclusive_to(&end))
+        .precision(Precision::Approximate)
+        .datetime_kind(DatetimeKind::DatePeriod)
+        .relative_grain(grain))
 }
 
 pub fn cycle_nth_after(
@@ -602,6 +925,10 @@ pub fn cycle_nth_after(
     n: i64,
     after_value: &DatetimeValue,
 ) -> RuleResult<DatetimeValue> {
+    if grain == Grain::Day && after_value.holiday.is_some() && after_value.holiday_offset.is_none() {
+        // Moved by the resolver, the date of the holiday depending on its calendar
+        return Ok(after_value.holiday_offset(HolidayOffset::Days(n))?.form(Form::Cycle(grain)));
+    }
     Ok(
         DatetimeValue::constraint(Cycle::rc(grain).the_nth(n).after(&after_value.constraint))
             .form(Form::Cycle(grain)),
@@ -622,11 +949,70 @@ pub fn cycle_nth_after_not_immediate(
 }
 
 pub fn cycle_n(grain: Grain, n: i64) -> RuleResult<DatetimeValue> {
-    Ok(DatetimeValue::constraint(Cycle::rc(grain).take(n)).form(Form::Cycle(grain)))
+    Ok(DatetimeValue::constraint(Cycle::rc(grain).take(n))
+        .form(Form::Cycle(grain))
+        .relative_grain(grain))
 }
 
 pub fn cycle_n_not_immediate(grain: Grain, n: i64) -> RuleResult<DatetimeValue> {
-    Ok(DatetimeValue::constraint(Cycle::rc(grain).take_not_immediate(n)).form(Form::Cycle(grain)))
+    Ok(DatetimeValue::constraint(Cycle::rc(grain).take_not_immediate(n))
+        .form(Form::Cycle(grain))
+        .relative_grain(grain))
+}
+
+/// Interval from now for "the next 3 days" or "the coming 2 weeks", up to the start of the day
+/// `n` days, or weeks, after today, so that today is covered. Other grains give the `n` cycles
+/// following the current one, e.g. from march to may for "the next 3 months" in february.
+pub fn cycle_n_from_now(grain: Grain, n: i64) -> RuleResult<DatetimeValue> {
+    let days = match grain {
+        Grain::Day => n,
+        Grain::Week => 7 * n,
+        _ => return cycle_n_not_immediate(grain, n),
+    };
+    cycle_nth(Grain::Second, 0)?.span_to(&cycle_nth(Grain::Day, days)?, false)
+}
+
+/// Conventional hours of a part of the day, as an interval: the morning from 4 to noon, the
+/// afternoon from noon to 18, the evening from 18 to midnight and the night from midnight to 4
+pub fn part_of_day(part_of_day: PartOfDayForm) -> RuleResult<DatetimeValue> {
+    let (start, end) = match part_of_day {
+        PartOfDayForm::Morning => (4, 12),
+        PartOfDayForm::Afternoon => (12, 18),
+        PartOfDayForm::Evening => (18, 0),
+        PartOfDayForm::Night => (0, 4),
+        PartOfDayForm::None => return Err(RuleError::Invalid.into()),
+    };
+    Ok(hour(start, false)?
+        .span_to(&hour(end, false)?, false)?
+        .form(Form::PartOfDay(part_of_day)))
+}
+
+/// The current period of the given grain moved by `n` periods of `shift`, keeping the finer
+/// fields of the reference, e.g. the time of day for "this time next month" or the day of the
+/// week for "same day next week". Days past the end of a shorter month give its last day, e.g.
+/// the 28th of february for "this time next month" on the 31st of january.
+pub fn cycle_shifted(grain: Grain, shift: Grain, n: i64) -> RuleResult<DatetimeValue> {
+    let datetime_kind = if grain.is_date_grain() {
+        DatetimeKind::Date
+    } else {
+        DatetimeKind::Datetime
+    };
+    Ok(DatetimeValue::constraint(
+        Cycle::rc(grain)
+            .take_the_nth(0)
+            .shift_by(PeriodComp::new(shift, n).into()),
+    )
+    .datetime_kind(datetime_kind)
+    .relative_grain(grain))
+}
+
+/// Office hours of every day, or the rest of the day after them, the hours being given by the
+/// resolver, see `ResolverContext::with_office_hours`
+pub fn office_hours(office_hours: OfficeHours) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue {
+        office_hours: Some(office_hours),
+        ..DatetimeValue::constraint(Cycle::rc(Grain::Day)).datetime_kind(DatetimeKind::TimePeriod)
+    })
 }
 
 pub fn weekend() -> RuleResult<DatetimeValue> {
@@ -637,6 +1023,79 @@ pub fn weekend() -> RuleResult<DatetimeValue> {
         .datetime_kind(DatetimeKind::DatePeriod))
 }
 
+/// Saturday and Sunday, at the day grain, as opposed to `weekend` which starts on friday evening
+pub fn weekend_days() -> RuleResult<DatetimeValue> {
+    Ok(day_of_week(Weekday::Sat)?
+        .span_to(&day_of_week(Weekday::Sun)?, true)?
+        .form(Form::PartOfWeek)
+        .datetime_kind(DatetimeKind::DatePeriod))
+}
+
+/// Week containing the given date, e.g. "the week of july 4th", from the start of the week to
+/// the start of the next one. The date is pinned like the date itself, and a numeric date whose
+/// day and month can be swapped gives the week of the other reading too.
+pub fn week_of(date: &DatetimeValue) -> RuleResult<DatetimeValue> {
+    let week_of = |constraint: &RcConstraint<Local>| {
+        let week = Cycle::rc(Grain::Week).the_nth(0).after(constraint);
+        week.span_inclusive_to(&week)
+    };
+    Ok(DatetimeValue {
+        latent: date.latent,
+        ambiguity: date.ambiguity.clone(),
+        swapped_date: date
+            .swapped_date
+            .as_ref()
+            .map(|&(order, ref swapped)| (order, week_of(swapped))),
+        ..DatetimeValue::constraint(week_of(&date.constraint))
+            .precision(date.precision)
+            .datetime_kind(DatetimeKind::DatePeriod)
+    })
+}
+
+/// Months and days on which a season starts and ends in the northern hemisphere, by the
+/// astronomical convention
+fn season_bounds(season: Season) -> ((u32, u32), (u32, u32)) {
+    match season {
+        Season::Spring => ((3, 20), (6, 21)),
+        Season::Summer => ((6, 21), (9, 23)),
+        Season::Fall => ((9, 23), (12, 21)),
+        Season::Winter => ((12, 21), (3, 20)),
+    }
+}
+
+fn season_span(season: Season) -> RuleResult<DatetimeValue> {
+    let ((start_month, start_day), (end_month, end_day)) = season_bounds(season);
+    Ok(month_day(start_month, start_day)?
+        .span_to(&month_day(end_month, end_day)?, false)?
+        .form(Form::Season))
+}
+
+fn season_of_year_span(season: Season, y: i32) -> RuleResult<DatetimeValue> {
+    let ((start_month, start_day), (end_month, end_day)) = season_bounds(season);
+    let end_year = if end_month < start_month { y + 1 } else { y };
+    Ok(year_month_day(y, start_month, start_day)?
+        .span_to(&year_month_day(end_year, end_month, end_day)?, false)?
+        .form(Form::Season))
+}
+
+/// Season, e.g. "summer", resolved in the hemisphere of the resolver
+pub fn season(season: Season) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue {
+        season: Some(season),
+        southern_season: Some(season_span(season.opposite())?.constraint),
+        ..season_span(season)?
+    })
+}
+
+/// Season starting in the given year, e.g. "winter 2014" from december 2014 to march 2015
+pub fn season_of_year(season: Season, y: i32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue {
+        season: Some(season),
+        southern_season: Some(season_of_year_span(season.opposite(), y)?.constraint),
+        ..season_of_year_span(season, y)?
+    })
+}
+
 pub fn easter() -> RuleResult<DatetimeValue> {
     fn offset(i: &Interval<Local>, _: &Context<Local>) -> Option<Interval<Local>> {
         let (year, month, day) = computer_easter(i.start.year());
@@ -684,6 +1143,11 @@ impl DurationValue {
 
     pub fn in_present(&self) -> RuleResult<DatetimeValue> {
         self.check_period()?;
+        if self.business_days {
+            // The day is moved to skip non-working days when resolving
+            let days = *self.period.0.get(Grain::Day as usize).unwrap_or(&0);
+            return Ok(self.in_present_day()?.business_days(days));
+        }
         let grain = self.get_grain();
         let datetime_kind = if grain.is_date_grain() {
             DatetimeKind::Date
@@ -691,12 +1155,13 @@ impl DurationValue {
             DatetimeKind::Time
         };
         Ok(DatetimeValue::constraint(
-            Cycle::rc(Grain::Second)
+            Cycle::rc(cmp::max(grain, Grain::Second))
                 .take_the_nth(0)
                 .shift_by(self.period.clone()),
         )
         .precision(self.precision)
-        .datetime_kind(datetime_kind))
+        .datetime_kind(datetime_kind)
+        .relative_grain(grain))
     }
 
     pub fn in_present_day(&self) -> RuleResult<DatetimeValue> {
@@ -713,7 +1178,8 @@ impl DurationValue {
                 .shift_by(self.period.clone()),
         )
         .precision(self.precision)
-        .datetime_kind(datetime_kind))
+        .datetime_kind(datetime_kind)
+        .relative_grain(grain))
     }
 
     pub fn ago(&self) -> RuleResult<DatetimeValue> {
@@ -724,28 +1190,44 @@ impl DurationValue {
             false => DatetimeKind::Time,
         };
         Ok(DatetimeValue::constraint(
-            Cycle::rc(Grain::Second)
+            Cycle::rc(cmp::max(grain, Grain::Second))
                 .take_the_nth(0)
                 .shift_by(-self.period.clone()),
         )
         .precision(self.precision)
-        .datetime_kind(datetime_kind))
+        .datetime_kind(datetime_kind)
+        .relative_grain(grain))
     }
 
+    /// The moment this duration after the end of the datetime, e.g. 17:00 for "2 hours after
+    /// 3pm" or 16:00 for "2 hours after lunch"
     pub fn after(&self, datetime: &DatetimeValue) -> RuleResult<DatetimeValue> {
-        self.check_period()?;
-        Ok(
-            DatetimeValue::constraint(datetime.constraint.shift_by(self.period.clone()))
-                .precision(self.precision),
-        )
+        self.offset(datetime, self.period.clone())
     }
 
+    /// The moment this duration before the start of the datetime, e.g. 11:30 for "30 minutes
+    /// before noon"
     pub fn before(&self, datetime: &DatetimeValue) -> RuleResult<DatetimeValue> {
+        self.offset(datetime, -self.period.clone())
+    }
+
+    fn offset(&self, datetime: &DatetimeValue, offset: Period) -> RuleResult<DatetimeValue> {
         self.check_period()?;
-        Ok(
-            DatetimeValue::constraint(datetime.constraint.shift_by(-self.period.clone()))
-                .precision(self.precision),
-        )
+        if datetime.offset.is_some() {
+            return Err(RuleError::Invalid.into());
+        }
+        let datetime_kind = if self.get_grain().is_date_grain() {
+            DatetimeKind::Date
+        } else {
+            DatetimeKind::Time
+        };
+        Ok(datetime
+            .clone()
+            .offset(offset)
+            .not_latent()
+            .form(Form::Empty)
+            .precision(precision_resolution(self.precision, datetime.precision))
+            .datetime_kind(datetime_kind))
     }
 }
 impl ops::Add<DurationValue> for DurationValue {
@@ -760,6 +1242,7 @@ impl ops::Add<DurationValue> for DurationValue {
                 self.from_addition,
                 duration.from_addition,
             )),
+            business_days: self.business_days || duration.business_days,
         }
     }
 }
@@ -776,6 +1259,7 @@ impl<'a> ops::Add<&'a DurationValue> for DurationValue {
                 self.from_addition,
                 duration.from_addition,
             )),
+            business_days: self.business_days || duration.business_days,
         }
     }
 }
@@ -792,6 +1276,7 @@ impl<'a, 'b> ops::Add<&'a DurationValue> for &'b DurationValue {
                 self.from_addition,
                 duration.from_addition,
             )),
+            business_days: self.business_days || duration.business_days,
         }
     }
 }
@@ -808,6 +1293,7 @@ impl<'a> ops::Add<DurationValue> for &'a DurationValue {
                 self.from_addition,
                 duration.from_addition,
             )),
+            business_days: self.business_days || duration.business_days,
         }
     }
 }
@@ -0,0 +1,193 @@
+use moment::*;
+use std::fmt;
+
+/// Calendar of named holidays, consulted by `ResolverContext` when a datetime was built from a
+/// holiday rule. Names are the ones given to `DatetimeValue::holiday` by the grammars, e.g.
+/// "christmas", "easter" or "thanksgiving".
+pub trait HolidayProvider: fmt::Debug + Send + Sync {
+    /// Returns the interval of the holiday for the given year, `None` if it is unknown
+    fn resolve(&self, name: &str, year: i32) -> Option<Interval<Local>>;
+}
+
+/// Holidays celebrated in the United States and in France, either on a fixed date, on a given
+/// weekday of a month or relatively to Easter.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct DefaultHolidayProvider;
+
+impl HolidayProvider for DefaultHolidayProvider {
+    fn resolve(&self, name: &str, year: i32) -> Option<Interval<Local>> {
+        let (month, day) = match name {
+            "new_year" => (1, 1),
+            "valentines_day" => (2, 14),
+            "may_day" => (5, 1),
+            "victory_day" => (5, 8),
+            "flag_day" => (6, 14),
+            "independence_day" => (7, 4),
+            "bastille_day" => (7, 14),
+            "assumption" => (8, 15),
+            "womens_equality_day" => (8, 26),
+            "patriot_day" => (9, 11),
+            "halloween" => (10, 31),
+            "all_saints" => (11, 1),
+            "armistice" => (11, 11),
+            "christmas_eve" => (12, 24),
+            "christmas" => (12, 25),
+            "saint_stephen" => (12, 26),
+            "new_years_eve" => (12, 31),
+            "mlk_day" => nth_weekday_of_month(year, 1, 0, 3),
+            "mothers_day_us" => nth_weekday_of_month(year, 5, 6, 2),
+            "memorial_day" => last_weekday_of_month(year, 5, 0, 31),
+            "mothers_day_fr" => last_weekday_of_month(year, 5, 6, 31),
+            "fathers_day" => nth_weekday_of_month(year, 6, 6, 3),
+            "labor_day" => nth_weekday_of_month(year, 9, 0, 1),
+            "thanksgiving" => nth_weekday_of_month(year, 11, 3, 4),
+            "black_friday" => {
+                let (month, day) = nth_weekday_of_month(year, 11, 3, 4);
+                (month, day + 1)
+            }
+            _ => return easter_offset(name).map(|offset| easter(year) + PeriodComp::days(offset)),
+        };
+        Some(Interval::ymd(year, month, day))
+    }
+}
+
+/// Days counted by "in 3 business days": the days of the weekmask, from monday to sunday, which
+/// are not within one of the holidays. The default weekmask is from monday to friday, without
+/// holidays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkingDays {
+    pub weekmask: [bool; 7],
+    pub holidays: Vec<Interval<Local>>,
+}
+
+impl Default for WorkingDays {
+    fn default() -> WorkingDays {
+        WorkingDays::new([true, true, true, true, true, false, false], vec![])
+    }
+}
+
+impl WorkingDays {
+    pub fn new(weekmask: [bool; 7], holidays: Vec<Interval<Local>>) -> WorkingDays {
+        WorkingDays { weekmask, holidays }
+    }
+
+    pub fn is_working_day(&self, day: Moment<Local>) -> bool {
+        self.weekmask[weekday(day.year(), day.month(), day.day()) as usize]
+            && !self
+                .holidays
+                .iter()
+                .any(|holiday| holiday.start <= day && day < holiday.end_moment())
+    }
+
+    /// Start of the day `n` working days after the given one, or before it if `n` is negative,
+    /// e.g. the tuesday for 2 working days after a friday. `None` if the weekmask is empty.
+    pub fn add_working_days(&self, day: Moment<Local>, n: i64) -> Option<Moment<Local>> {
+        if !self.weekmask.iter().any(|working| *working) {
+            return None;
+        }
+        let step = PeriodComp::days(n.signum());
+        let mut day = Moment::ymd(day.year(), day.month(), day.day());
+        let mut remaining = n.abs();
+        while remaining > 0 {
+            day = day + step;
+            if self.is_working_day(day) {
+                remaining -= 1;
+            }
+        }
+        Some(day)
+    }
+}
+
+/// Days between Easter sunday and the holidays which depend on it
+fn easter_offset(name: &str) -> Option<i64> {
+    match name {
+        "palm_sunday" => Some(-7),
+        "holy_thursday" => Some(-3),
+        "good_friday" => Some(-2),
+        "holy_saturday" => Some(-1),
+        "easter" => Some(0),
+        "easter_monday" => Some(1),
+        "ascension" => Some(39),
+        "pentecost" => Some(49),
+        "whit_monday" => Some(50),
+        _ => None,
+    }
+}
+
+/// Easter sunday of the given year, computed with the anonymous gregorian algorithm
+pub fn easter(year: i32) -> Interval<Local> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    Interval::ymd(year, month as u32, day as u32)
+}
+
+/// Day of the week of a date, from 0 for monday to 6 for sunday
+pub(crate) fn weekday(year: i32, month: u32, day: u32) -> u32 {
+    let offsets = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    let sunday_based = (year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+        + offsets[month as usize - 1]
+        + day as i32)
+        .rem_euclid(7) as u32;
+    (sunday_based + 6) % 7
+}
+
+fn nth_weekday_of_month(year: i32, month: u32, weekday_number: u32, n: u32) -> (u32, u32) {
+    let first = (weekday_number + 7 - weekday(year, month, 1)) % 7 + 1;
+    (month, first + 7 * (n - 1))
+}
+
+fn last_weekday_of_month(year: i32, month: u32, weekday_number: u32, days: u32) -> (u32, u32) {
+    let last = days - (weekday(year, month, days) + 7 - weekday_number) % 7;
+    (month, last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_easter() {
+        assert_eq!(Interval::ymd(2013, 3, 31), easter(2013));
+        assert_eq!(Interval::ymd(2019, 4, 21), easter(2019));
+        assert_eq!(Interval::ymd(2025, 4, 20), easter(2025));
+    }
+
+    #[test]
+    fn test_working_days() {
+        let working_days = WorkingDays::default();
+        let friday = Moment::ymd(2013, 2, 15);
+        assert!(working_days.is_working_day(friday));
+        assert!(!working_days.is_working_day(Moment::ymd(2013, 2, 16)));
+        assert_eq!(Some(Moment::ymd(2013, 2, 19)), working_days.add_working_days(friday, 2));
+        assert_eq!(Some(Moment::ymd(2013, 2, 14)), working_days.add_working_days(friday, -1));
+        let with_holiday = WorkingDays::new(working_days.weekmask, vec![Interval::ymd(2013, 2, 18)]);
+        assert_eq!(Some(Moment::ymd(2013, 2, 20)), with_holiday.add_working_days(friday, 2));
+        assert_eq!(None, WorkingDays::new([false; 7], vec![]).add_working_days(friday, 2));
+    }
+
+    #[test]
+    fn test_default_holidays() {
+        let holidays = DefaultHolidayProvider;
+        assert_eq!(Some(Interval::ymd(2025, 4, 20)), holidays.resolve("easter", 2025));
+        assert_eq!(Some(Interval::ymd(2025, 6, 9)), holidays.resolve("whit_monday", 2025));
+        assert_eq!(Some(Interval::ymd(2013, 11, 28)), holidays.resolve("thanksgiving", 2013));
+        assert_eq!(Some(Interval::ymd(2013, 5, 27)), holidays.resolve("memorial_day", 2013));
+        assert_eq!(Some(Interval::ymd(2013, 5, 26)), holidays.resolve("mothers_day_fr", 2013));
+        assert_eq!(Some(Interval::ymd(2013, 5, 12)), holidays.resolve("mothers_day_us", 2013));
+        assert_eq!(Some(Interval::ymd(2013, 7, 14)), holidays.resolve("bastille_day", 2013));
+        assert_eq!(None, holidays.resolve("groundhog_day", 2013));
+    }
+}
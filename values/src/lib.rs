@@ -1,24 +1,65 @@
+//! Values and outputs of the ontology, with the context resolving them.
+//!
+//! The `std` feature, on by default, provides everything. Without it the crate is `no_std` and
+//! only needs `alloc`: it then holds the numbers, ordinals and percentages of the `numbers` module,
+//! resolved by `NumericContext`, and `IdentityContext`. The other dimensions, `Dimension` itself,
+//! `ResolverContext` and the datetimes of chrono are not available.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate failure;
+#[cfg(feature = "std")]
 extern crate log;
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate rustling;
+#[cfg(feature = "std")]
 extern crate rustling_ontology_moment as moment;
 
+#[cfg(feature = "std")]
 extern crate regex;
+#[cfg(feature = "serde")]
+extern crate serde_crate;
+#[cfg(feature = "duckling")]
+extern crate serde_json;
 
+#[cfg(feature = "std")]
 pub mod check;
+#[cfg(feature = "std")]
 pub mod dimension;
+#[cfg(feature = "duckling")]
+pub mod duckling;
+#[cfg(feature = "std")]
 pub mod helpers;
+#[cfg(feature = "std")]
+pub mod holidays;
+#[cfg(feature = "std")]
 #[macro_use]
 pub mod macros_training;
+#[cfg(feature = "std")]
 #[macro_use]
 pub mod macros_rules;
+pub mod numbers;
+#[cfg(feature = "std")]
 pub mod output;
+#[cfg(feature = "std")]
 pub mod context;
+mod parsing;
+#[cfg(feature = "serde")]
+mod serialization;
 
+#[cfg(feature = "std")]
 pub use dimension::Dimension;
+#[cfg(feature = "std")]
 pub use dimension::DimensionKind;
+pub use numbers::{NumericContext, NumericOutput, NumericValue};
+#[cfg(feature = "std")]
 pub use output::Output;
+#[cfg(feature = "std")]
 pub use output::OutputKind;
-pub use context::{ResolverContext, ParsingContext, IdentityContext};
+#[cfg(feature = "std")]
+pub use context::{CustomResolver, ResolverContext, ResolverContextBuilder, ResolverContextError, ResolveError, ResolveOptions, WeekdayPolicy, FuzzyReferencePolicy, TieBreak, PartialDatePolicy, OutOfRangePolicy, AmbiguousHourPolicy, TwoDigitYearPivot, SeasonConvention, Hemisphere};
+pub use parsing::{IdentityContext, ParsingContext};
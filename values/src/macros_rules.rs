@@ -49,6 +49,11 @@ macro_rules! ordinal_check_by_range {
 }
 
 
+#[macro_export]
+macro_rules! ordinal_range_check {
+    () => ( ::rustling::core::AnyNodePattern::<OrdinalRangeValue>::new() );
+}
+
 #[macro_export]
 macro_rules! amount_of_money_check {
     () => ( ::rustling::core::AnyNodePattern::<AmountOfMoneyValue>::new() );
@@ -83,6 +88,13 @@ macro_rules! temperature_check {
 }
 
 
+#[macro_export]
+macro_rules! quantity_check {
+    () => ( ::rustling::core::AnyNodePattern::<QuantityValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<QuantityValue>::filter(vec![b!($predicate)]) );
+}
+
+
 #[macro_export]
 macro_rules! datetime_check {
     () => ( ::rustling::core::AnyNodePattern::<DatetimeValue>::new() );
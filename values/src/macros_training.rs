@@ -20,32 +20,38 @@ macro_rules! check_duration {
 
 #[macro_export]
 macro_rules! check_moment {
-    ($context:expr, [$($item:expr),*]) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, None) );
-    ($context:expr, [$($item:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), $grain, Precision::Exact, None) );
+    ($context:expr, [$($item:expr),*]) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, None) );
+    ($context:expr, [$($item:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), $grain, Precision::Exact, None) );
 }
 
 #[macro_export]
 macro_rules! check_moment_with_precision {
-    ($context:expr, [$($item:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), $precision, None) );
+    ($context:expr, [$($item:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), grain!($( $item ),*), $precision, None) );
 }
 
 #[macro_export]
 macro_rules! check_moment_with_direction {
-    ($context:expr, [$($item:expr),*], $direction:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, Some($direction)) );
-    ($context:expr, [$($item:expr),*], $direction:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), $grain, Precision::Exact, Some($direction)) );
+    ($context:expr, [$($item:expr),*], $direction:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, Some($direction)) );
+    ($context:expr, [$($item:expr),*], $direction:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), $grain, Precision::Exact, Some($direction)) );
 
 }
 
 #[macro_export]
 macro_rules! check_moment_span {
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*]) => ( ::rustling_ontology_values::check::check_moment_span($context, Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context, Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*]) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
 }
 
 #[macro_export]
 macro_rules! check_moment_span_with_precision {
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment_span($context, $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context, $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
+}
+
+#[macro_export]
+macro_rules! check_recurring {
+    ($context:expr, [$($item:expr),*], $period:expr) => ( ::rustling_ontology_values::check::check_recurring($context.clone(), moment!($( $item ),*), grain!($( $item ),*), $period) );
+    ($context:expr, [$($item:expr),*], $period:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_recurring($context.clone(), moment!($( $item ),*), $grain, $period) );
 }
 
 #[macro_export]
@@ -56,6 +62,7 @@ macro_rules! moment {
     ($y:expr, $m:expr, $d:expr, $h:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms($h, 0, 0)) );
     ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms($h, $min, 0)) );
     ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms($h, $min, $sec)) );
+    ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr, $ms:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms_milli($h, $min, $sec, $ms)) );
 }
 
 #[macro_export]
@@ -67,6 +74,7 @@ macro_rules! period {
     ($y:expr, $m:expr, $w:expr, $d:expr, $h:expr) => ( year_period!($y) + month_period!($m) + week_period!($w) + day_period!($d) + hour_period!($h) );
     ($y:expr, $m:expr, $w:expr, $d:expr, $h:expr, $min:expr) => ( year_period!($y) + month_period!($m) + week_period!($w) + day_period!($d) + hour_period!($h) + minute_period!($min) );
     ($y:expr, $m:expr, $w:expr, $d:expr, $h:expr, $min:expr, $sec:expr) => ( year_period!($y) + month_period!($m) + week_period!($w) + day_period!($d) + hour_period!($h) + minute_period!($min) + second_period!($sec) );
+    ($y:expr, $m:expr, $w:expr, $d:expr, $h:expr, $min:expr, $sec:expr, $ms:expr) => ( year_period!($y) + month_period!($m) + week_period!($w) + day_period!($d) + hour_period!($h) + minute_period!($min) + second_period!($sec) + millisecond_period!($ms) );
 }
 
 #[macro_export]
@@ -104,6 +112,11 @@ macro_rules! second_period {
     ($sec:expr) => ( Period::from(PeriodComp::new(Grain::Second, $sec)) );
 }
 
+#[macro_export]
+macro_rules! millisecond_period {
+    ($ms:expr) => ( Period::from(PeriodComp::new(Grain::Millisecond, $ms)) );
+}
+
 #[macro_export]
 macro_rules! grain {
     ($y:expr) => (Grain::Year);
@@ -112,6 +125,7 @@ macro_rules! grain {
     ($y:expr, $m:expr, $d:expr, $h:expr) => (Grain::Hour);
     ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr) => (Grain::Minute);
     ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr) => (Grain::Second);
+    ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr, $ms:expr) => (Grain::Millisecond);
 }
 
 #[macro_export]
@@ -0,0 +1,254 @@
+//! Numbers, ordinals and percentages, which are resolved without `std` when the crate is built
+//! without its default `std` feature, see `NumericContext`.
+use crate::parsing::ParsingContext;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "serde")]
+use serde_crate::{Deserialize, Serialize};
+
+/// Payload for the ordinal numbers value of Dimension
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct OrdinalValue {
+    pub value: i64,
+    pub prefixed: bool,
+    pub grain: Option<u8>,
+}
+
+impl OrdinalValue {
+    pub fn new(value: i64) -> OrdinalValue {
+        OrdinalValue {
+            value,
+            prefixed: false,
+            grain: None,
+        }
+    }
+
+    pub fn new_with_grain(value: i64, grain: u8) -> OrdinalValue {
+        OrdinalValue {
+            value,
+            prefixed: false,
+            grain: Some(grain),
+        }
+    }
+
+    pub fn prefixed(self) -> OrdinalValue {
+        OrdinalValue {
+            value: self.value,
+            prefixed: true,
+            grain: None,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "snake_case"))]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum Precision {
+    /// "about", "around", "approximately"
+    Approximate,
+    Exact,
+    /// Looser than `Approximate`: "roughly", "3-ish"
+    Rough,
+}
+
+impl Precision {
+    /// Orders the precisions from `Exact` to `Rough`
+    fn looseness(&self) -> u8 {
+        match self {
+            &Precision::Exact => 0,
+            &Precision::Approximate => 1,
+            &Precision::Rough => 2,
+        }
+    }
+
+    /// Returns the least precise of both precisions
+    pub fn loosest(self, other: Precision) -> Precision {
+        if other.looseness() > self.looseness() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl Default for Precision {
+    fn default() -> Precision {
+        Precision::Exact
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CombinationDirection {
+    Left,
+    Right,
+}
+
+/// Payload for the integral numbers value of Dimension
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct IntegerValue {
+    pub value: i64,
+    #[doc(hidden)]
+    pub grain: Option<u8>,
+    #[doc(hidden)]
+    pub group: bool,
+    #[doc(hidden)]
+    pub prefixed: bool,
+    #[doc(hidden)]
+    pub suffixed: bool,
+    #[doc(hidden)]
+    pub combine_from: Option<CombinationDirection>,
+    #[doc(hidden)]
+    pub precision: Precision,
+}
+
+/// Payload for the floating numbers value of Dimension
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct FloatValue {
+    pub value: f64,
+    #[doc(hidden)]
+    pub prefixed: bool,
+    #[doc(hidden)]
+    pub suffixed: bool,
+    #[doc(hidden)]
+    pub combine_from: Option<CombinationDirection>,
+    #[doc(hidden)]
+    pub precision: Precision,
+}
+
+/// Enumeration acting as a Number supertype for IntegerValue and FloatValue.
+#[derive(Debug, PartialEq, Clone)]
+pub enum NumberValue {
+    Float(FloatValue),
+    Integer(IntegerValue),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PercentageValue(pub f64);
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, Copy, PartialEq, Debug, Hash)]
+pub struct IntegerOutput(pub i64);
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FloatOutput(pub f64);
+
+impl Hash for FloatOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f64(self.0, state)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PercentageOutput(pub f64);
+
+impl Hash for PercentageOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f64(self.0, state)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, Copy, PartialEq, Debug, Hash)]
+pub struct OrdinalOutput(pub i64);
+
+/// Hashes the bits of the float, 0 and -0 being equal, to hash the outputs holding floats
+pub(crate) fn hash_f64<H: Hasher>(value: f64, state: &mut H) {
+    let value = if value == 0.0 { 0.0 } else { value };
+    value.to_bits().hash(state)
+}
+
+/// Number, ordinal or percentage resolved by `NumericContext`
+#[derive(Debug, PartialEq, Clone)]
+pub enum NumericValue {
+    Number(NumberValue),
+    Ordinal(OrdinalValue),
+    Percentage(PercentageValue),
+}
+
+/// Output of a `NumericValue`
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum NumericOutput {
+    Integer(IntegerOutput),
+    Float(FloatOutput),
+    Ordinal(OrdinalOutput),
+    Percentage(PercentageOutput),
+}
+
+/// Resolves the numbers, ordinals and percentages as `ResolverContext` does, also in a build
+/// without `std` which has no `ResolverContext`. The floats are not rounded.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct NumericContext;
+
+impl NumericContext {
+    pub fn resolve_number(&self, number: &NumberValue) -> NumericOutput {
+        match number {
+            &NumberValue::Integer(ref v) => NumericOutput::Integer(IntegerOutput(v.value)),
+            &NumberValue::Float(ref v) => NumericOutput::Float(FloatOutput(v.value)),
+        }
+    }
+
+    pub fn resolve_ordinal(&self, ordinal: &OrdinalValue) -> OrdinalOutput {
+        OrdinalOutput(ordinal.value)
+    }
+
+    pub fn resolve_percentage(&self, percentage: &PercentageValue) -> PercentageOutput {
+        PercentageOutput(percentage.0)
+    }
+}
+
+impl ParsingContext<NumericValue> for NumericContext {
+    type O = NumericOutput;
+    fn resolve(&self, value: &NumericValue) -> Option<NumericOutput> {
+        Some(match value {
+            &NumericValue::Number(ref number) => self.resolve_number(number),
+            &NumericValue::Ordinal(ref ordinal) => {
+                NumericOutput::Ordinal(self.resolve_ordinal(ordinal))
+            }
+            &NumericValue::Percentage(ref percentage) => {
+                NumericOutput::Percentage(self.resolve_percentage(percentage))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_numeric() {
+        let three = IntegerValue {
+            value: 3,
+            ..IntegerValue::default()
+        };
+        let values = [
+            (
+                NumericValue::Number(NumberValue::Integer(three)),
+                NumericOutput::Integer(IntegerOutput(3)),
+            ),
+            (
+                NumericValue::Number(NumberValue::Float(FloatValue {
+                    value: 0.5,
+                    ..FloatValue::default()
+                })),
+                NumericOutput::Float(FloatOutput(0.5)),
+            ),
+            (
+                NumericValue::Ordinal(OrdinalValue::new(2)),
+                NumericOutput::Ordinal(OrdinalOutput(2)),
+            ),
+            (
+                NumericValue::Percentage(PercentageValue(15.0)),
+                NumericOutput::Percentage(PercentageOutput(15.0)),
+            ),
+        ];
+        for &(ref value, output) in values.iter() {
+            assert_eq!(Some(output), NumericContext.resolve(value));
+        }
+    }
+}
@@ -1,18 +1,38 @@
 use crate::dimension::*;
+use crate::numbers::{hash_f64, NumericOutput};
+pub use crate::numbers::{FloatOutput, IntegerOutput, OrdinalOutput, PercentageOutput};
 use moment::*;
 use rustling::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "serde")]
+use serde_crate::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(crate = "serde_crate", tag = "kind", content = "value", rename_all = "snake_case")
+)]
+#[derive(Clone, PartialEq, Debug, Hash)]
 pub enum Output {
     Integer(IntegerOutput),
     Float(FloatOutput),
     Percentage(PercentageOutput),
     Ordinal(OrdinalOutput),
+    OrdinalRange(OrdinalRangeOutput),
     Datetime(DatetimeOutput),
     DatetimeInterval(DatetimeIntervalOutput),
+    DatetimeRecurring(DatetimeRecurringOutput),
     AmountOfMoney(AmountOfMoneyOutput),
     Temperature(TemperatureOutput),
     Duration(DurationOutput),
+    Quantity(QuantityOutput),
+    PhoneNumber(PhoneNumberOutput),
+    Age(AgeOutput),
+    NumberRange(NumberRangeOutput),
+    NumberComparison(NumberComparisonOutput),
+    TemperatureRange(TemperatureRangeOutput),
+    Custom(CustomOutput),
 }
 
 impl Output {
@@ -21,6 +41,7 @@ impl Output {
             &Output::Integer(_) => OutputKind::Number,
             &Output::Float(_) => OutputKind::Number,
             &Output::Ordinal(_) => OutputKind::Ordinal,
+            &Output::OrdinalRange(_) => OutputKind::OrdinalRange,
             Output::Datetime(datetime_output_value) => {
                 match datetime_output_value.datetime_kind {
                     // Only Date and Time should occur here
@@ -41,10 +62,69 @@ impl Output {
                     _ => OutputKind::Datetime,
                 }
             }
+            // The kind of a recurrence is the kind of its occurrences
+            &Output::DatetimeRecurring(ref recurring) => Output::Datetime(recurring.first).kind(),
             &Output::AmountOfMoney(_) => OutputKind::AmountOfMoney,
             &Output::Temperature(_) => OutputKind::Temperature,
             &Output::Duration(_) => OutputKind::Duration,
             &Output::Percentage(_) => OutputKind::Percentage,
+            &Output::Quantity(_) => OutputKind::Quantity,
+            &Output::PhoneNumber(_) => OutputKind::PhoneNumber,
+            &Output::Age(_) => OutputKind::Age,
+            &Output::NumberRange(_) => OutputKind::NumberRange,
+            &Output::NumberComparison(_) => OutputKind::NumberComparison,
+            &Output::TemperatureRange(_) => OutputKind::TemperatureRange,
+            &Output::Custom(_) => OutputKind::Custom,
+        }
+    }
+
+    /// Whether the output was resolved from a latent dimension, i.e. a low-confidence match.
+    /// Only datetimes and temperatures can be latent.
+    pub fn is_latent(&self) -> bool {
+        match self {
+            &Output::Datetime(ref datetime) => datetime.latent,
+            &Output::DatetimeInterval(ref interval) => match interval.interval_kind {
+                DatetimeIntervalKind::After { ref datetime, .. }
+                | DatetimeIntervalKind::Before { ref datetime, .. } => datetime.latent,
+                DatetimeIntervalKind::Between { latent, .. } => latent,
+            },
+            &Output::DatetimeRecurring(ref recurring) => recurring.first.latent,
+            &Output::Temperature(ref temperature) => temperature.latent,
+            _ => false,
+        }
+    }
+
+    /// Key of the value of the output, to collapse the same entity found several times, e.g.
+    /// "3pm" given twice in a sentence. Outputs which are equal get the same key, so do
+    /// datetimes of the same grain which are `approx_eq`, whatever their latency or precision.
+    /// The key is stable within a build but not across versions of the crate.
+    pub fn dedup_key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Kind of the datetime for the datetime outputs, `None` for the other ones
+    pub fn datetime_kind(&self) -> Option<DatetimeKind> {
+        match self {
+            &Output::Datetime(ref datetime) => Some(datetime.datetime_kind),
+            &Output::DatetimeInterval(ref interval) => Some(interval.datetime_kind),
+            &Output::DatetimeRecurring(ref recurring) => Some(recurring.first.datetime_kind),
+            _ => None,
+        }
+    }
+
+    /// Same output with its moments given in UTC for the datetime outputs, see
+    /// `DatetimeOutput::to_utc`. The other outputs are left as they are.
+    pub fn to_utc(&self) -> Output {
+        match self {
+            &Output::Datetime(ref datetime) => Output::Datetime(datetime.to_utc()),
+            &Output::DatetimeInterval(ref interval) => Output::DatetimeInterval(interval.to_utc()),
+            &Output::DatetimeRecurring(ref recurring) => Output::DatetimeRecurring(DatetimeRecurringOutput {
+                first: recurring.first.to_utc(),
+                ..recurring.clone()
+            }),
+            output => output.clone(),
         }
     }
 }
@@ -52,25 +132,36 @@ impl Output {
 enum_kind!(
     OutputKind,
     [
+        PhoneNumber,
+        Age,
         Number,
         Ordinal,
+        OrdinalRange,
         Date,
         Time,
         DatePeriod,
         TimePeriod,
         Datetime,
+        NumberRange,
+        NumberComparison,
         Duration,
         AmountOfMoney,
         Temperature,
-        Percentage
+        TemperatureRange,
+        Percentage,
+        Quantity,
+        Custom
     ]
 );
 
 impl OutputKind {
+    /// Kind of the dimensions resolved to this kind of output. `Custom` is given the cycles, the
+    /// first of the dimensions only used while parsing it stands for, see `match_dim`.
     pub fn to_dim(&self) -> DimensionKind {
         match self {
             &OutputKind::Number => DimensionKind::Number,
             &OutputKind::Ordinal => DimensionKind::Ordinal,
+            &OutputKind::OrdinalRange => DimensionKind::OrdinalRange,
             &OutputKind::Datetime => DimensionKind::Datetime,
             &OutputKind::Date => DimensionKind::Datetime,
             &OutputKind::Time => DimensionKind::Datetime,
@@ -80,6 +171,13 @@ impl OutputKind {
             &OutputKind::Temperature => DimensionKind::Temperature,
             &OutputKind::Duration => DimensionKind::Duration,
             &OutputKind::Percentage => DimensionKind::Percentage,
+            &OutputKind::Quantity => DimensionKind::Quantity,
+            &OutputKind::PhoneNumber => DimensionKind::PhoneNumber,
+            &OutputKind::Age => DimensionKind::Age,
+            &OutputKind::NumberRange => DimensionKind::NumberRange,
+            &OutputKind::NumberComparison => DimensionKind::NumberComparison,
+            &OutputKind::TemperatureRange => DimensionKind::TemperatureRange,
+            &OutputKind::Custom => DimensionKind::Cycle,
         }
     }
 
@@ -102,23 +200,66 @@ impl OutputKind {
                     _ => false,
                 }
             }
+            // Custom outputs are resolved from the dimensions the context doesn't resolve, see
+            // `ResolverContext::with_custom_resolver`
+            Dimension::Cycle(_)
+            | Dimension::UnitOfDuration(_)
+            | Dimension::RelativeMinute(_)
+            | Dimension::MoneyUnit(_) if *self == OutputKind::Custom => true,
             _ => self.to_dim() == dimension_value.kind(),
         }
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct IntegerOutput(pub i64);
+impl From<NumericOutput> for Output {
+    fn from(output: NumericOutput) -> Output {
+        match output {
+            NumericOutput::Integer(integer) => Output::Integer(integer),
+            NumericOutput::Float(float) => Output::Float(float),
+            NumericOutput::Ordinal(ordinal) => Output::Ordinal(ordinal),
+            NumericOutput::Percentage(percentage) => Output::Percentage(percentage),
+        }
+    }
+}
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct FloatOutput(pub f64);
+impl FloatOutput {
+    /// Whether both values differ by at most `epsilon`
+    pub fn approx_eq(&self, other: &FloatOutput, epsilon: f64) -> bool {
+        (self.0 - other.0).abs() <= epsilon
+    }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct PercentageOutput(pub f64);
+    /// Value rounded to the given number of decimals, halves being rounded away from zero, e.g.
+    /// 3.14 for 3.14159 and 0.13 for 0.125 to two decimals. The rounding applies to the binary
+    /// value, so 2.675, stored as slightly less, gives 2.67 to two decimals.
+    pub fn round(&self, decimals: u32) -> FloatOutput {
+        let factor = 10f64.powi(decimals as i32);
+        let rounded = (self.0 * factor).round() / factor;
+        if rounded.is_finite() {
+            FloatOutput(rounded)
+        } else {
+            *self
+        }
+    }
+}
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct OrdinalOutput(pub i64);
+impl PercentageOutput {
+    /// Whether both values differ by at most `epsilon`
+    pub fn approx_eq(&self, other: &PercentageOutput, epsilon: f64) -> bool {
+        (self.0 - other.0).abs() <= epsilon
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, Copy, PartialEq, Debug, Hash)]
+pub struct OrdinalRangeOutput {
+    pub start: i64,
+    /// Included in the range
+    pub end: i64,
+}
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct DatetimeOutput {
     pub moment: Moment<Local>,
@@ -126,6 +267,24 @@ pub struct DatetimeOutput {
     pub precision: Precision,
     pub latent: bool,
     pub datetime_kind: DatetimeKind,
+    /// Timezone the moment was resolved in, `None` for the local timezone
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::timezone"))]
+    pub timezone: Option<Tz>,
+    /// Part of the period the moment was narrowed to, e.g. january for "the beginning of 2025"
+    pub part_of_period: Option<PartOfPeriod>,
+    /// Whether the time of day was given on the 12-hour clock with am or pm, e.g. "5pm" but not
+    /// "17h", to render it back the same way
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub was_12_hour: bool,
+}
+
+/// Only the grain and the moment rounded to it are hashed, so that the datetimes of the same
+/// grain which are `approx_eq` are hashed the same
+impl Hash for DatetimeOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.grain.hash(state);
+        self.wall_clock().round_to(self.grain).hash(state);
+    }
 }
 
 impl DatetimeOutput {
@@ -135,52 +294,1638 @@ impl DatetimeOutput {
             ..self
         }
     }
+
+    /// Whether both outputs give the same moment at the coarser of their grains, as seen in the
+    /// timezones they were resolved in, e.g. "2013-02-12 04:30" with a minute grain and
+    /// "2013-02-12" with a day grain
+    pub fn approx_eq(&self, other: &DatetimeOutput) -> bool {
+        let grain = ::std::cmp::min(self.grain, other.grain);
+        self.wall_clock().round_to(grain) == other.wall_clock().round_to(grain)
+    }
+
+    /// Moment as seen in the timezone it was resolved in
+    fn wall_clock(&self) -> Moment<Local> {
+        match self.timezone {
+            Some(ref tz) => self.moment.to_wall_clock_in(tz),
+            None => self.moment,
+        }
+    }
+
+    /// Offset from UTC at this moment in the timezone it was resolved in
+    pub fn utc_offset(&self) -> FixedOffset {
+        utc_offset_at(&self.moment, self.timezone)
+    }
+
+    /// Same moment and grain, given in UTC whatever the timezone it was resolved in, e.g.
+    /// "2013-02-12T14:00:00+00:00" for 3pm in Paris. The moment being the same instant, the days
+    /// and coarser grains may then be formatted as the day before or after the one of the
+    /// timezone, e.g. "2013-02-11" for the 12th, from midnight in Paris.
+    pub fn to_utc(&self) -> DatetimeOutput {
+        DatetimeOutput {
+            timezone: Some(Tz::UTC),
+            ..*self
+        }
+    }
+
+    /// Formats the moment as RFC 3339, truncated to its grain: "2013" for a year, "2013-02" for
+    /// a quarter or a month, "2013-02-12" for a week or a day, "2013-02-12T04:30:00+01:00" for
+    /// an hour, a minute or a second, and "2013-02-12T04:30:00.500+01:00" for a millisecond. The
+    /// seconds are always given with the time, as RFC 3339 requires.
+    pub fn to_rfc3339(&self) -> String {
+        format_rfc3339(&self.moment, self.timezone, self.grain)
+    }
+
+    /// Exclusive end of the period of its grain starting at the moment, e.g. the start of the
+    /// next day for a day, on the wall clock of the timezone it was resolved in
+    pub fn end_moment(&self) -> Moment<Local> {
+        let end = self.wall_clock() + PeriodComp::new(self.grain, 1);
+        match self.timezone {
+            Some(ref tz) => end.from_wall_clock_in(tz),
+            None => end,
+        }
+    }
+
+    /// Interval of the given grain containing the moment when the grain is coarser, e.g. the
+    /// week, from monday, of a minute, or the first period of the finer grain starting at it,
+    /// e.g. the first minute of a day. The periods are aligned on the wall clock of the timezone
+    /// the moment was resolved in.
+    pub fn to_grain(&self, grain: Grain) -> DatetimeIntervalOutput {
+        let wall_clock = self.wall_clock();
+        let start = if grain <= self.grain {
+            wall_clock.round_to(grain)
+        } else {
+            wall_clock
+        };
+        let end = start + PeriodComp::new(grain, 1);
+        let (start, end) = match self.timezone {
+            Some(ref tz) => (start.from_wall_clock_in(tz), end.from_wall_clock_in(tz)),
+            None => (start, end),
+        };
+        DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Between {
+                start,
+                end,
+                grain,
+                start_grain: grain,
+                end_grain: grain,
+                precision: self.precision,
+                latent: self.latent,
+            },
+            datetime_kind: if grain.is_date_grain() {
+                DatetimeKind::DatePeriod
+            } else {
+                DatetimeKind::TimePeriod
+            },
+            timezone: self.timezone,
+            part_of_period: None,
+        }
+    }
+}
+
+/// Recurring datetime, e.g. "every monday", given by its first occurrence after the reference,
+/// the period of the pattern and the number of periods between two occurrences, e.g. a week and
+/// 2 for "every 2nd monday"
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, PartialEq, Debug, Hash)]
+pub struct DatetimeRecurringOutput {
+    pub first: DatetimeOutput,
+    pub period: Period,
+    #[cfg_attr(feature = "serde", serde(default = "crate::serialization::default_interval"))]
+    pub interval: u32,
+}
+
+impl DatetimeRecurringOutput {
+    /// Period from one occurrence to the next, e.g. two weeks for "every 2nd monday"
+    pub fn stride(&self) -> Period {
+        (0..self.interval).fold(Period::default(), |stride, _| stride + &self.period)
+    }
+
+    /// Returns the occurrences starting within the given interval, in chronological order.
+    /// Nothing is returned for a null period or interval.
+    pub fn occurrences(&self, within: Interval<Local>) -> Vec<DatetimeOutput> {
+        let mut occurrences = vec![];
+        let stride = self.stride();
+        if stride.coarse_num_millis() <= 0 {
+            return occurrences;
+        }
+        let end = within.end_moment();
+        let mut offset = Period::default();
+        loop {
+            // Always add the whole offset to the first moment, so that months don't drift
+            let moment = self.first.moment + &offset;
+            if moment >= end {
+                break;
+            }
+            if moment >= within.start {
+                occurrences.push(DatetimeOutput { moment, ..self.first });
+            }
+            offset = offset + &stride;
+        }
+        occurrences
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct DatetimeIntervalOutput {
     pub interval_kind: DatetimeIntervalKind,
     pub datetime_kind: DatetimeKind,
+    /// Timezone the interval was resolved in, `None` for the local timezone
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::timezone"))]
+    pub timezone: Option<Tz>,
+    /// Part of the period the interval was narrowed to, e.g. for "the end of march"
+    pub part_of_period: Option<PartOfPeriod>,
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+/// The timezone isn't hashed, the intervals being the same instants whatever it is
+impl Hash for DatetimeIntervalOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.interval_kind.hash(state);
+        self.datetime_kind.hash(state);
+        self.part_of_period.hash(state);
+    }
+}
+
+impl DatetimeIntervalOutput {
+    /// Offset from UTC at the start of the interval, or at its only bound, in the timezone it
+    /// was resolved in
+    pub fn utc_offset(&self) -> FixedOffset {
+        match self.interval_kind {
+            DatetimeIntervalKind::After { ref datetime, .. }
+            | DatetimeIntervalKind::Before { ref datetime, .. } => datetime.utc_offset(),
+            DatetimeIntervalKind::Between { ref start, .. } => {
+                utc_offset_at(start, self.timezone)
+            }
+        }
+    }
+
+    /// Formats the bounds of the interval as RFC 3339, see `DatetimeOutput::to_rfc3339`. A
+    /// `Between` interval gives both its start and end, formatted to the second. `After` and
+    /// `Before` intervals only give their bound, the open side is given by `open_direction`.
+    pub fn to_rfc3339_interval(&self) -> (String, Option<String>) {
+        match self.interval_kind {
+            DatetimeIntervalKind::After { ref datetime, .. }
+            | DatetimeIntervalKind::Before { ref datetime, .. } => (datetime.to_rfc3339(), None),
+            DatetimeIntervalKind::Between { ref start, ref end, .. } => (
+                format_rfc3339(start, self.timezone, Grain::Second),
+                Some(format_rfc3339(end, self.timezone, Grain::Second)),
+            ),
+        }
+    }
+
+    /// False when a `Between` interval was resolved for a datetime of `Date` or `Time` kind,
+    /// which should only be instants
+    pub fn is_kind_consistent(&self) -> bool {
+        match (self.interval_kind, self.datetime_kind) {
+            (DatetimeIntervalKind::Between { .. }, DatetimeKind::Date)
+            | (DatetimeIntervalKind::Between { .. }, DatetimeKind::Time) => false,
+            _ => true,
+        }
+    }
+
+    /// Same interval given in UTC, see `DatetimeOutput::to_utc`
+    pub fn to_utc(&self) -> DatetimeIntervalOutput {
+        let interval_kind = match self.interval_kind {
+            DatetimeIntervalKind::After { datetime, inclusive } => DatetimeIntervalKind::After {
+                datetime: datetime.to_utc(),
+                inclusive,
+            },
+            DatetimeIntervalKind::Before { datetime, inclusive } => DatetimeIntervalKind::Before {
+                datetime: datetime.to_utc(),
+                inclusive,
+            },
+            between => between,
+        };
+        DatetimeIntervalOutput {
+            interval_kind,
+            timezone: Some(Tz::UTC),
+            ..*self
+        }
+    }
+
+    /// Direction towards which the interval is open, `None` for a `Between` interval
+    pub fn open_direction(&self) -> Option<Direction> {
+        match self.interval_kind {
+            DatetimeIntervalKind::After { .. } => Some(Direction::After),
+            DatetimeIntervalKind::Before { .. } => Some(Direction::Before),
+            DatetimeIntervalKind::Between { .. } => None,
+        }
+    }
+
+    /// Duration of a `Between` interval, `None` for the `After` and `Before` ones which are open.
+    /// Intervals of days or coarser are counted in calendar days, e.g. 5 days from monday to
+    /// saturday whatever the daylight saving changes, the finer ones in days, hours, minutes,
+    /// seconds and milliseconds.
+    pub fn duration(&self) -> Option<DurationOutput> {
+        match self.interval_kind {
+            DatetimeIntervalKind::Between { start, end, grain, precision, .. } => {
+                let period = if grain.is_date_grain() {
+                    let days = end.0.naive_local().date().signed_duration_since(start.0.naive_local().date());
+                    PeriodComp::days(days.num_days()).into()
+                } else {
+                    PeriodComp::milliseconds(end.0.signed_duration_since(start.0).num_milliseconds()).into()
+                };
+                Some(DurationOutput { period, precision }.normalized())
+            }
+            DatetimeIntervalKind::After { .. } | DatetimeIntervalKind::Before { .. } => None,
+        }
+    }
+
+    /// Whether the moment is within the interval. The end of a `Between` interval is excluded,
+    /// the bound of an `After` or `Before` interval is included when it is `inclusive`.
+    pub fn contains(&self, moment: &Moment<Local>) -> bool {
+        let (lower, upper) = self.bounds();
+        bounds_meet(lower, Some((*moment, true))) && bounds_meet(Some((*moment, true)), upper)
+    }
+
+    /// Whether both intervals share at least one moment, see `contains`
+    pub fn overlaps(&self, other: &DatetimeIntervalOutput) -> bool {
+        let (lower, upper) = self.bounds();
+        let (other_lower, other_upper) = other.bounds();
+        bounds_meet(lower, upper)
+            && bounds_meet(other_lower, other_upper)
+            && bounds_meet(lower, other_upper)
+            && bounds_meet(other_lower, upper)
+    }
+
+    /// Lower and upper bounds of the interval, with whether they are part of it, `None` on the
+    /// open side
+    fn bounds(&self) -> (Option<(Moment<Local>, bool)>, Option<(Moment<Local>, bool)>) {
+        match self.interval_kind {
+            DatetimeIntervalKind::After { ref datetime, inclusive } => (Some((datetime.moment, inclusive)), None),
+            DatetimeIntervalKind::Before { ref datetime, inclusive } => (None, Some((datetime.moment, inclusive))),
+            DatetimeIntervalKind::Between { start, end, .. } => (Some((start, true)), Some((end, false))),
+        }
+    }
+}
+
+/// Whether a moment can be after the lower bound and before the upper one, `None` being unbounded
+fn bounds_meet(lower: Option<(Moment<Local>, bool)>, upper: Option<(Moment<Local>, bool)>) -> bool {
+    match (lower, upper) {
+        (Some((lower, lower_inclusive)), Some((upper, upper_inclusive))) => {
+            lower < upper || (lower == upper && lower_inclusive && upper_inclusive)
+        }
+        _ => true,
+    }
+}
+
+fn format_rfc3339(moment: &Moment<Local>, timezone: Option<Tz>, grain: Grain) -> String {
+    let format = match grain {
+        Grain::Century | Grain::Decade | Grain::Year => "%Y",
+        Grain::Quarter | Grain::Month => "%Y-%m",
+        Grain::Week | Grain::Day => "%Y-%m-%d",
+        Grain::Hour | Grain::Minute | Grain::Second => "%Y-%m-%dT%H:%M:%S%:z",
+        Grain::Millisecond => "%Y-%m-%dT%H:%M:%S%.3f%:z",
+    };
+    match timezone {
+        Some(tz) => moment.0.with_timezone(&tz).format(format).to_string(),
+        None => moment.0.format(format).to_string(),
+    }
+}
+
+pub(crate) fn utc_offset_at(moment: &Moment<Local>, timezone: Option<Tz>) -> FixedOffset {
+    match timezone {
+        Some(tz) => tz.offset_from_utc_datetime(&moment.0.naive_utc()).fix(),
+        None => moment.0.offset().fix(),
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "snake_case"))]
+#[derive(Clone, Copy, PartialEq, Debug, Hash)]
 pub enum DatetimeIntervalKind {
-    After(DatetimeOutput),
-    Before(DatetimeOutput),
+    /// Interval starting at the datetime, which is part of it when `inclusive`, e.g. "from 5pm"
+    /// but not "after 5pm"
+    After {
+        datetime: DatetimeOutput,
+        inclusive: bool,
+    },
+    /// Interval ending at the datetime, which is part of it when `inclusive`, e.g. "until 5pm"
+    /// but not "before 5pm"
+    Before {
+        datetime: DatetimeOutput,
+        inclusive: bool,
+    },
     Between {
         start: Moment<Local>,
         end: Moment<Local>,
+        /// Finest grain of the bounds, e.g. `Day` for "from monday to wednesday" and `Minute`
+        /// for "from 2:00 to 4:30pm"
+        grain: Grain,
+        /// Grain of the start, e.g. `Day` for "between monday and 5pm tuesday"
+        start_grain: Grain,
+        /// Grain of the end, e.g. `Minute` for "between monday and 5pm tuesday"
+        end_grain: Grain,
         precision: Precision,
         latent: bool,
     },
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, PartialEq, Debug)]
 pub struct AmountOfMoneyOutput {
     pub value: f64,
     pub precision: Precision,
+    /// Unit as given by the grammars, e.g. "$" or "USD", see `currency` for a normalized one
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_unit")
+    )]
     pub unit: Option<&'static str>,
+    /// Currency of the unit, `None` for units which are no currency like "cent"
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub currency: Option<Currency>,
+}
+
+impl Hash for AmountOfMoneyOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f64(self.value, state);
+        self.precision.hash(state);
+        self.unit.hash(state);
+        self.currency.hash(state);
+    }
 }
 
+impl AmountOfMoneyOutput {
+    /// Returns the ISO 4217 code of the unit. Units are the ones given by the grammars, the
+    /// symbols used by en, fr and es are mapped as follows:
+    /// - "$" (dollars, bucks) to "USD"
+    /// - "£" (pounds, livres) to "GBP"
+    /// - "¥" to "JPY"
+    /// - "฿" (bitcoins) to "XBT", which is not ISO 4217 but the commonly used code
+    ///
+    /// Units which are ambiguous like "KR" (crowns) or which are no currency like "cent" have
+    /// no ISO code. Units already given as codes are returned as is.
+    pub fn iso_unit(&self) -> Option<&'static str> {
+        match self.unit {
+            Some("$") => Some("USD"),
+            Some("£") => Some("GBP"),
+            Some("¥") => Some("JPY"),
+            Some("฿") => Some("XBT"),
+            Some("KR") | Some("cent") => None,
+            unit => unit,
+        }
+    }
+
+    /// Returns whether the unit is the one given by `iso_unit`, i.e. not a raw symbol
+    pub fn has_iso_unit(&self) -> bool {
+        self.unit.is_some() && self.iso_unit() == self.unit
+    }
+
+    /// Returns the amount with its unit replaced by its ISO 4217 code when there is one, see
+    /// `iso_unit`. Keep the output as is to preserve the raw unit.
+    pub fn normalize_unit(&self) -> AmountOfMoneyOutput {
+        AmountOfMoneyOutput {
+            unit: self.iso_unit().or(self.unit),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the canonical code of the currency, e.g. "USD" for "$", "usd" or "USD"
+    pub fn unit_str(&self) -> Option<&str> {
+        self.currency.as_ref().map(|currency| currency.code())
+    }
+}
+
+/// Currencies given by the grammars, with their ISO 4217 code. Other currencies, including
+/// ambiguous ones like "KR" (crowns), are kept as `Unknown` with their code in upper case.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate", rename_all = "UPPERCASE"))]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Currency {
+    Aed,
+    Aud,
+    Cad,
+    Chf,
+    Cny,
+    Dkk,
+    Eur,
+    Gbp,
+    Hkd,
+    Inr,
+    Jpy,
+    Krw,
+    Nok,
+    Rub,
+    Sek,
+    Usd,
+    Unknown(String),
+}
+
+impl Currency {
+    /// Currency of a unit given by the grammars, `None` for "cent" which is no currency
+    pub fn from_unit(unit: &str) -> Option<Currency> {
+        match unit {
+            "cent" => None,
+            unit => unit.parse().ok(),
+        }
+    }
+
+    pub fn code(&self) -> &str {
+        match self {
+            &Currency::Aed => "AED",
+            &Currency::Aud => "AUD",
+            &Currency::Cad => "CAD",
+            &Currency::Chf => "CHF",
+            &Currency::Cny => "CNY",
+            &Currency::Dkk => "DKK",
+            &Currency::Eur => "EUR",
+            &Currency::Gbp => "GBP",
+            &Currency::Hkd => "HKD",
+            &Currency::Inr => "INR",
+            &Currency::Jpy => "JPY",
+            &Currency::Krw => "KRW",
+            &Currency::Nok => "NOK",
+            &Currency::Rub => "RUB",
+            &Currency::Sek => "SEK",
+            &Currency::Usd => "USD",
+            &Currency::Unknown(ref code) => code,
+        }
+    }
+
+    /// Symbol of the currency, the same in all the languages, e.g. "€" or "C$" for canadian
+    /// dollars. Currencies without a common symbol, e.g. "AED" or "CHF", are given by their code.
+    pub fn symbol(&self) -> &str {
+        match self {
+            &Currency::Aud => "A$",
+            &Currency::Cad => "C$",
+            &Currency::Cny => "CN¥",
+            &Currency::Dkk | &Currency::Nok | &Currency::Sek => "kr",
+            &Currency::Eur => "€",
+            &Currency::Gbp => "£",
+            &Currency::Hkd => "HK$",
+            &Currency::Inr => "₹",
+            &Currency::Jpy => "¥",
+            &Currency::Krw => "₩",
+            &Currency::Rub => "₽",
+            &Currency::Usd => "$",
+            &Currency::Unknown(ref code) if code == "XBT" => "฿",
+            currency => currency.code(),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Currency {
+    type Err = String;
+
+    /// Parses an ISO 4217 code in any case or one of the symbols mapped by
+    /// `AmountOfMoneyOutput::iso_unit`
+    fn from_str(s: &str) -> Result<Currency, String> {
+        let code = match s.trim() {
+            "" => return Err(format!("{:?} is not a currency", s)),
+            "$" => "USD".to_string(),
+            "£" => "GBP".to_string(),
+            "¥" => "JPY".to_string(),
+            "€" => "EUR".to_string(),
+            "₽" => "RUB".to_string(),
+            "฿" => "XBT".to_string(),
+            code => code.to_uppercase(),
+        };
+        Ok(match code.as_str() {
+            "AED" => Currency::Aed,
+            "AUD" => Currency::Aud,
+            "CAD" => Currency::Cad,
+            "CHF" => Currency::Chf,
+            "CNY" => Currency::Cny,
+            "DKK" => Currency::Dkk,
+            "EUR" => Currency::Eur,
+            "GBP" => Currency::Gbp,
+            "HKD" => Currency::Hkd,
+            "INR" => Currency::Inr,
+            "JPY" => Currency::Jpy,
+            "KRW" => Currency::Krw,
+            "NOK" => Currency::Nok,
+            "RUB" => Currency::Rub,
+            "SEK" => Currency::Sek,
+            "USD" => Currency::Usd,
+            _ => Currency::Unknown(code),
+        })
+    }
+}
+
+impl ::std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct TemperatureOutput {
     pub value: f64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_unit")
+    )]
     pub unit: Option<&'static str>,
     pub latent: bool,
+    /// Modifier given with the temperature, e.g. "feels like", `None` for a plain temperature
+    pub qualifier: Option<TemperatureQualifier>,
 }
 
-#[derive(Clone, PartialEq, Debug)]
+impl Hash for TemperatureOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f64(self.value, state);
+        self.unit.hash(state);
+        self.latent.hash(state);
+        self.qualifier.hash(state);
+    }
+}
+
+impl TemperatureOutput {
+    /// What the temperature measures, `Actual` when no modifier was given
+    pub fn qualifier_or_actual(&self) -> TemperatureQualifier {
+        self.qualifier.unwrap_or(TemperatureQualifier::Actual)
+    }
+
+    /// Converts the temperature to the given scale, keeping its latency. Temperatures without
+    /// scale, e.g. "20 degrees", are returned unchanged as there is no way to know what to
+    /// convert from.
+    pub fn to_unit(&self, target: TemperatureUnit) -> TemperatureOutput {
+        match self.unit.and_then(TemperatureUnit::from_name) {
+            Some(unit) => TemperatureOutput {
+                value: target.from_kelvin(unit.to_kelvin(self.value)),
+                unit: Some(target.name()),
+                latent: self.latent,
+                qualifier: self.qualifier,
+            },
+            None => *self,
+        }
+    }
+}
+
+/// Range of temperatures in a single unit, e.g. from 20 to 25 degrees for "20 to 25 degrees"
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TemperatureRangeOutput {
+    pub low: f64,
+    pub high: f64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialization::deserialize_unit")
+    )]
+    pub unit: Option<&'static str>,
+}
+
+impl Hash for TemperatureRangeOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f64(self.low, state);
+        hash_f64(self.high, state);
+        self.unit.hash(state);
+    }
+}
+
+/// Temperature scales which can be converted into each other
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    /// Name of the unit as given in `TemperatureOutput::unit`
+    pub fn name(&self) -> &'static str {
+        match self {
+            &TemperatureUnit::Celsius => "celsius",
+            &TemperatureUnit::Fahrenheit => "fahrenheit",
+            &TemperatureUnit::Kelvin => "kelvin",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<TemperatureUnit> {
+        match name {
+            "celsius" => Some(TemperatureUnit::Celsius),
+            "fahrenheit" => Some(TemperatureUnit::Fahrenheit),
+            "kelvin" => Some(TemperatureUnit::Kelvin),
+            _ => None,
+        }
+    }
+
+    fn to_kelvin(&self, value: f64) -> f64 {
+        match self {
+            &TemperatureUnit::Celsius => value + 273.15,
+            &TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0 + 273.15,
+            &TemperatureUnit::Kelvin => value,
+        }
+    }
+
+    fn from_kelvin(&self, value: f64) -> f64 {
+        match self {
+            &TemperatureUnit::Celsius => value - 273.15,
+            &TemperatureUnit::Fahrenheit => (value - 273.15) * 9.0 / 5.0 + 32.0,
+            &TemperatureUnit::Kelvin => value,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, PartialEq, Debug, Hash)]
 pub struct DurationOutput {
     pub period: Period,
     pub precision: Precision,
 }
 
+impl DurationOutput {
+    fn quantity(&self, grain: Grain) -> i64 {
+        *self.period.0.get(grain as usize).unwrap_or(&0)
+    }
+
+    /// Number of years of the duration, counting its decades and centuries
+    pub fn years(&self) -> i64 {
+        self.quantity(Grain::Year) + 10 * self.quantity(Grain::Decade) + 100 * self.quantity(Grain::Century)
+    }
+
+    /// Number of months of the duration, counting its quarters but not its years
+    pub fn months(&self) -> i64 {
+        self.quantity(Grain::Month) + 3 * self.quantity(Grain::Quarter)
+    }
+
+    pub fn weeks(&self) -> i64 {
+        self.quantity(Grain::Week)
+    }
+
+    pub fn days(&self) -> i64 {
+        self.quantity(Grain::Day)
+    }
+
+    pub fn hours(&self) -> i64 {
+        self.quantity(Grain::Hour)
+    }
+
+    pub fn minutes(&self) -> i64 {
+        self.quantity(Grain::Minute)
+    }
+
+    pub fn seconds(&self) -> i64 {
+        self.quantity(Grain::Second)
+    }
+
+    pub fn milliseconds(&self) -> i64 {
+        self.quantity(Grain::Millisecond)
+    }
+
+    /// Whether the duration has years or months, whose length depends on the date the duration
+    /// starts at, see `to_seconds`
+    pub fn is_calendar(&self) -> bool {
+        self.years() != 0 || self.months() != 0
+    }
+
+    /// Total number of seconds of the duration, `None` if it has years or coarser grains,
+    /// quarters or months. The length of these depends on the date the duration starts at, e.g.
+    /// a month lasts from 28 to 31 days, so they can't be converted to a fixed number of seconds.
+    /// Days are considered to last 24 hours. Milliseconds are truncated.
+    pub fn to_seconds(&self) -> Option<i64> {
+        self.to_milliseconds().map(|milliseconds| milliseconds / 1000)
+    }
+
+    /// Total number of milliseconds of the duration, see `to_seconds`
+    pub fn to_milliseconds(&self) -> Option<i64> {
+        let mut milliseconds = 0;
+        for comp in self.period.comps() {
+            match comp.grain {
+                Grain::Century | Grain::Decade | Grain::Year | Grain::Quarter | Grain::Month
+                    if comp.quantity != 0 =>
+                {
+                    return None
+                }
+                _ => milliseconds += comp.coarse_num_millis(),
+            }
+        }
+        Some(milliseconds)
+    }
+
+    /// Returns the same duration where milliseconds, seconds, minutes and hours are carried over
+    /// to the next grain, e.g. "90 minutes" gives 1 hour and 30 minutes. Coarser grains are kept
+    /// as given.
+    pub fn normalized(&self) -> DurationOutput {
+        let quantity = |grain: Grain| self.quantity(grain);
+        let mut period = Period::default();
+        for grain in vec![
+            Grain::Century,
+            Grain::Decade,
+            Grain::Year,
+            Grain::Quarter,
+            Grain::Month,
+            Grain::Week,
+        ] {
+            if quantity(grain) != 0 {
+                period += PeriodComp::new(grain, quantity(grain));
+            }
+        }
+        let total = quantity(Grain::Day) * 24 * 3600 * 1000
+            + quantity(Grain::Hour) * 3600 * 1000
+            + quantity(Grain::Minute) * 60 * 1000
+            + quantity(Grain::Second) * 1000
+            + quantity(Grain::Millisecond);
+        let days = total / (24 * 3600 * 1000);
+        let hours = total % (24 * 3600 * 1000) / (3600 * 1000);
+        let minutes = total % (3600 * 1000) / (60 * 1000);
+        let seconds = total % (60 * 1000) / 1000;
+        let milliseconds = total % 1000;
+        for comp in vec![
+            PeriodComp::days(days),
+            PeriodComp::hours(hours),
+            PeriodComp::minutes(minutes),
+            PeriodComp::seconds(seconds),
+            PeriodComp::milliseconds(milliseconds),
+        ] {
+            if comp.quantity != 0 {
+                period += comp;
+            }
+        }
+        DurationOutput {
+            period,
+            precision: self.precision,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, PartialEq, Debug)]
+pub struct QuantityOutput {
+    pub value: f64,
+    pub unit: Option<String>,
+    pub product: Option<String>,
+}
+
+impl Hash for QuantityOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f64(self.value, state);
+        self.unit.hash(state);
+        self.product.hash(state);
+    }
+}
+
+/// Phone number as written, with its separators
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, PartialEq, Debug, Hash)]
+pub struct PhoneNumberOutput(pub String);
+
+/// Range of numbers, e.g. from 5 to 10 included for "between 5 and 10"
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NumberRangeOutput {
+    pub low: f64,
+    pub high: f64,
+    pub low_inclusive: bool,
+    pub high_inclusive: bool,
+}
+
+impl Hash for NumberRangeOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f64(self.low, state);
+        hash_f64(self.high, state);
+        self.low_inclusive.hash(state);
+        self.high_inclusive.hash(state);
+    }
+}
+
+impl NumberRangeOutput {
+    /// Whether the number is in the range
+    pub fn contains(&self, value: f64) -> bool {
+        let above_low = value > self.low || (self.low_inclusive && value == self.low);
+        let below_high = value < self.high || (self.high_inclusive && value == self.high);
+        above_low && below_high
+    }
+}
+
+/// Comparison to a number, e.g. `CompareOp::Lt` and 50 for "under 50"
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NumberComparisonOutput {
+    pub op: CompareOp,
+    pub value: f64,
+}
+
+impl Hash for NumberComparisonOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.op.hash(state);
+        hash_f64(self.value, state);
+    }
+}
+
+impl NumberComparisonOutput {
+    /// Whether the number satisfies the comparison, e.g. 45 for "under 50"
+    pub fn accepts(&self, value: f64) -> bool {
+        self.op.compare(value, self.value)
+    }
+}
+
+/// Age of someone or something, e.g. 6 months for "a six-month-old"
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AgeOutput {
+    pub value: f64,
+    pub unit: Grain,
+}
+
+impl Hash for AgeOutput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f64(self.value, state);
+        self.unit.hash(state);
+    }
+}
+
+/// Output of a `CustomResolver`, the value being tagged by the resolver, e.g. "sku"
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde_crate"))]
+#[derive(Clone, PartialEq, Debug, Hash)]
+pub struct CustomOutput {
+    pub tag: String,
+    pub value: String,
+}
+
+/// Keeps the outputs which are not latent, see `Output::is_latent`
+pub fn retain_non_latent(mut outputs: Vec<Output>) -> Vec<Output> {
+    outputs.retain(|output| !output.is_latent());
+    outputs
+}
+
+/// Outputs grouped by type, in the order they were added, see
+/// `ResolverContext::resolve_batch`
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ResolvedSet {
+    integers: Vec<IntegerOutput>,
+    floats: Vec<FloatOutput>,
+    percentages: Vec<PercentageOutput>,
+    ordinals: Vec<OrdinalOutput>,
+    ordinal_ranges: Vec<OrdinalRangeOutput>,
+    datetimes: Vec<DatetimeOutput>,
+    datetime_intervals: Vec<DatetimeIntervalOutput>,
+    datetime_recurrences: Vec<DatetimeRecurringOutput>,
+    amounts_of_money: Vec<AmountOfMoneyOutput>,
+    temperatures: Vec<TemperatureOutput>,
+    durations: Vec<DurationOutput>,
+    quantities: Vec<QuantityOutput>,
+    phone_numbers: Vec<PhoneNumberOutput>,
+    ages: Vec<AgeOutput>,
+    number_ranges: Vec<NumberRangeOutput>,
+    number_comparisons: Vec<NumberComparisonOutput>,
+    temperature_ranges: Vec<TemperatureRangeOutput>,
+    customs: Vec<CustomOutput>,
+}
+
+impl ResolvedSet {
+    /// Adds the output to the ones of its type
+    pub fn push(&mut self, output: Output) {
+        match output {
+            Output::Integer(value) => self.integers.push(value),
+            Output::Float(value) => self.floats.push(value),
+            Output::Percentage(value) => self.percentages.push(value),
+            Output::Ordinal(value) => self.ordinals.push(value),
+            Output::OrdinalRange(value) => self.ordinal_ranges.push(value),
+            Output::Datetime(value) => self.datetimes.push(value),
+            Output::DatetimeInterval(value) => self.datetime_intervals.push(value),
+            Output::DatetimeRecurring(value) => self.datetime_recurrences.push(value),
+            Output::AmountOfMoney(value) => self.amounts_of_money.push(value),
+            Output::Temperature(value) => self.temperatures.push(value),
+            Output::Duration(value) => self.durations.push(value),
+            Output::Quantity(value) => self.quantities.push(value),
+            Output::PhoneNumber(value) => self.phone_numbers.push(value),
+            Output::Age(value) => self.ages.push(value),
+            Output::NumberRange(value) => self.number_ranges.push(value),
+            Output::NumberComparison(value) => self.number_comparisons.push(value),
+            Output::TemperatureRange(value) => self.temperature_ranges.push(value),
+            Output::Custom(value) => self.customs.push(value),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.integers.len()
+            + self.floats.len()
+            + self.percentages.len()
+            + self.ordinals.len()
+            + self.ordinal_ranges.len()
+            + self.datetimes.len()
+            + self.datetime_intervals.len()
+            + self.datetime_recurrences.len()
+            + self.amounts_of_money.len()
+            + self.temperatures.len()
+            + self.durations.len()
+            + self.quantities.len()
+            + self.phone_numbers.len()
+            + self.ages.len()
+            + self.number_ranges.len()
+            + self.number_comparisons.len()
+            + self.temperature_ranges.len()
+            + self.customs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn integers(&self) -> &[IntegerOutput] {
+        &self.integers
+    }
+
+    pub fn floats(&self) -> &[FloatOutput] {
+        &self.floats
+    }
+
+    pub fn percentages(&self) -> &[PercentageOutput] {
+        &self.percentages
+    }
+
+    pub fn ordinals(&self) -> &[OrdinalOutput] {
+        &self.ordinals
+    }
+
+    pub fn ordinal_ranges(&self) -> &[OrdinalRangeOutput] {
+        &self.ordinal_ranges
+    }
+
+    pub fn datetimes(&self) -> &[DatetimeOutput] {
+        &self.datetimes
+    }
+
+    pub fn datetime_intervals(&self) -> &[DatetimeIntervalOutput] {
+        &self.datetime_intervals
+    }
+
+    pub fn datetime_recurrences(&self) -> &[DatetimeRecurringOutput] {
+        &self.datetime_recurrences
+    }
+
+    pub fn amounts_of_money(&self) -> &[AmountOfMoneyOutput] {
+        &self.amounts_of_money
+    }
+
+    pub fn temperatures(&self) -> &[TemperatureOutput] {
+        &self.temperatures
+    }
+
+    pub fn durations(&self) -> &[DurationOutput] {
+        &self.durations
+    }
+
+    pub fn quantities(&self) -> &[QuantityOutput] {
+        &self.quantities
+    }
+
+    pub fn phone_numbers(&self) -> &[PhoneNumberOutput] {
+        &self.phone_numbers
+    }
+
+    pub fn ages(&self) -> &[AgeOutput] {
+        &self.ages
+    }
+
+    pub fn number_ranges(&self) -> &[NumberRangeOutput] {
+        &self.number_ranges
+    }
+
+    pub fn number_comparisons(&self) -> &[NumberComparisonOutput] {
+        &self.number_comparisons
+    }
+
+    pub fn temperature_ranges(&self) -> &[TemperatureRangeOutput] {
+        &self.temperature_ranges
+    }
+
+    pub fn customs(&self) -> &[CustomOutput] {
+        &self.customs
+    }
+}
+
+impl Extend<Output> for ResolvedSet {
+    fn extend<I: IntoIterator<Item = Output>>(&mut self, outputs: I) {
+        for output in outputs {
+            self.push(output)
+        }
+    }
+}
+
+impl ::std::iter::FromIterator<Output> for ResolvedSet {
+    fn from_iter<I: IntoIterator<Item = Output>>(outputs: I) -> ResolvedSet {
+        let mut set = ResolvedSet::default();
+        set.extend(outputs);
+        set
+    }
+}
+
 variant_converters!(Output, Integer, IntegerOutput);
 variant_converters!(Output, Float, FloatOutput);
 variant_converters!(Output, Percentage, PercentageOutput);
 variant_converters!(Output, Ordinal, OrdinalOutput);
+variant_converters!(Output, OrdinalRange, OrdinalRangeOutput);
 variant_converters!(Output, Datetime, DatetimeOutput);
 variant_converters!(Output, DatetimeInterval, DatetimeIntervalOutput);
+variant_converters!(Output, DatetimeRecurring, DatetimeRecurringOutput);
 variant_converters!(Output, AmountOfMoney, AmountOfMoneyOutput);
 variant_converters!(Output, Temperature, TemperatureOutput);
 variant_converters!(Output, Duration, DurationOutput);
+variant_converters!(Output, Quantity, QuantityOutput);
+variant_converters!(Output, PhoneNumber, PhoneNumberOutput);
+variant_converters!(Output, Age, AgeOutput);
+variant_converters!(Output, NumberRange, NumberRangeOutput);
+variant_converters!(Output, NumberComparison, NumberComparisonOutput);
+variant_converters!(Output, TemperatureRange, TemperatureRangeOutput);
+variant_converters!(Output, Custom, CustomOutput);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temperature(value: f64, unit: &'static str) -> TemperatureOutput {
+        TemperatureOutput {
+            value,
+            unit: Some(unit),
+            latent: false,
+            qualifier: None,
+        }
+    }
+
+    fn assert_temperature(expected: TemperatureOutput, actual: TemperatureOutput) {
+        assert_eq!(expected.unit, actual.unit);
+        assert_eq!(expected.latent, actual.latent);
+        assert!((expected.value - actual.value).abs() < 1e-9, "{:?} != {:?}", expected, actual);
+    }
+
+    fn datetime(grain: Grain) -> DatetimeOutput {
+        DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            grain,
+            precision: Precision::Exact,
+            latent: false,
+            datetime_kind: DatetimeKind::Datetime,
+            timezone: None,
+            part_of_period: None,
+            was_12_hour: false,
+        }
+    }
+
+    #[test]
+    fn test_float_round() {
+        assert_eq!(FloatOutput(3.14), FloatOutput(3.14159).round(2));
+        assert_eq!(FloatOutput(3.1416), FloatOutput(3.14159265).round(4));
+        assert_eq!(FloatOutput(0.13), FloatOutput(0.125).round(2));
+        assert_eq!(FloatOutput(-0.13), FloatOutput(-0.125).round(2));
+        assert_eq!(FloatOutput(3.0), FloatOutput(2.5).round(0));
+        assert_eq!(FloatOutput(1e300), FloatOutput(1e300).round(10));
+    }
+
+    #[test]
+    fn test_datetime_to_rfc3339() {
+        let offset = datetime(Grain::Second).moment.0.format("%:z").to_string();
+        assert_eq!("2013", datetime(Grain::Year).to_rfc3339());
+        assert_eq!("2013-02", datetime(Grain::Month).to_rfc3339());
+        assert_eq!("2013-02-12", datetime(Grain::Day).to_rfc3339());
+        assert_eq!(format!("2013-02-12T04:30:00{}", offset), datetime(Grain::Minute).to_rfc3339());
+        assert_eq!(
+            format!("2013-02-12T04:00:00{}", offset),
+            DatetimeOutput {
+                moment: Moment(Local.ymd(2013, 2, 12).and_hms(4, 0, 0)),
+                ..datetime(Grain::Hour)
+            }
+            .to_rfc3339()
+        );
+        assert_eq!(
+            format!("2013-02-12T04:30:00{}", offset),
+            datetime(Grain::Second).to_rfc3339()
+        );
+        let noon = DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 12).and_hms_milli(12, 0, 0, 500)),
+            ..datetime(Grain::Millisecond)
+        };
+        assert_eq!(format!("2013-02-12T12:00:00.500{}", offset), noon.to_rfc3339());
+    }
+
+    #[test]
+    fn test_datetime_interval_to_rfc3339() {
+        let after = DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::After {
+                datetime: datetime(Grain::Day),
+                inclusive: true,
+            },
+            datetime_kind: DatetimeKind::Date,
+            timezone: None,
+            part_of_period: None,
+        };
+        assert_eq!(("2013-02-12".to_string(), None), after.to_rfc3339_interval());
+        assert_eq!(Some(Direction::After), after.open_direction());
+        let between = DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Between {
+                start: Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+                end: Moment(Local.ymd(2013, 2, 12).and_hms(6, 0, 0)),
+                grain: Grain::Minute,
+                start_grain: Grain::Minute,
+                end_grain: Grain::Minute,
+                precision: Precision::Exact,
+                latent: false,
+            },
+            datetime_kind: DatetimeKind::TimePeriod,
+            timezone: Some(Tz::UTC),
+            part_of_period: None,
+        };
+        let (start, end) = between.to_rfc3339_interval();
+        assert!(start.ends_with("+00:00"));
+        assert!(end.unwrap().ends_with("+00:00"));
+        assert_eq!(None, between.open_direction());
+    }
+
+    #[test]
+    fn test_datetime_interval_arithmetic() {
+        let day = |day: u32| Moment(Local.ymd(2013, 2, day).and_hms(0, 0, 0));
+        let between = |start: Moment<Local>, end: Moment<Local>, grain: Grain| DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Between {
+                start,
+                end,
+                grain,
+                start_grain: grain,
+                end_grain: grain,
+                precision: Precision::Exact,
+                latent: false,
+            },
+            datetime_kind: DatetimeKind::DatePeriod,
+            timezone: None,
+            part_of_period: None,
+        };
+        // Between monday and friday, included
+        let week = between(day(18), day(23), Grain::Day);
+        assert_eq!(5, week.duration().unwrap().days());
+        let morning = between(day(18) + PeriodComp::hours(9), day(18) + PeriodComp::minutes(690), Grain::Minute);
+        let duration = morning.duration().unwrap();
+        assert_eq!((2, 30), (duration.hours(), duration.minutes()));
+        assert!(week.contains(&day(18)));
+        assert!(week.contains(&(day(22) + PeriodComp::hours(23))));
+        assert!(!week.contains(&day(23)));
+        assert!(week.overlaps(&morning));
+        assert!(!week.overlaps(&between(day(23), day(25), Grain::Day)));
+
+        let after = DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::After {
+                datetime: DatetimeOutput { moment: day(22), ..datetime(Grain::Day) },
+                inclusive: false,
+            },
+            ..week
+        };
+        assert_eq!(None, after.duration());
+        assert!(!after.contains(&day(22)));
+        assert!(after.contains(&day(28)));
+        assert!(after.overlaps(&week));
+        let before = DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Before {
+                datetime: DatetimeOutput { moment: day(18), ..datetime(Grain::Day) },
+                inclusive: true,
+            },
+            ..week
+        };
+        assert!(before.contains(&day(18)));
+        assert!(before.overlaps(&week));
+        assert!(!before.overlaps(&after));
+    }
+
+    fn amount(unit: &'static str) -> AmountOfMoneyOutput {
+        AmountOfMoneyOutput {
+            value: 5.0,
+            precision: Precision::Approximate,
+            unit: Some(unit),
+            currency: Currency::from_unit(unit),
+        }
+    }
+
+    #[test]
+    fn test_currency() {
+        for unit in vec!["USD", "usd", "$", " Usd "] {
+            assert_eq!(Ok(Currency::Usd), unit.parse());
+        }
+        assert_eq!(Ok(Currency::Unknown("KR".to_string())), "kr".parse());
+        assert!("".parse::<Currency>().is_err());
+        assert_eq!("EUR", Currency::Eur.to_string());
+        assert_eq!("€", Currency::Eur.symbol());
+        assert_eq!("C$", Currency::Cad.symbol());
+        assert_eq!("CHF", Currency::Chf.symbol());
+        assert_eq!("฿", Currency::Unknown("XBT".to_string()).symbol());
+        assert_eq!(None, Currency::from_unit("cent"));
+        assert_eq!(Some("USD"), amount("$").unit_str());
+        assert_eq!(Some("XBT"), amount("฿").unit_str());
+        assert_eq!(None, amount("cent").unit_str());
+    }
+
+    #[test]
+    fn test_amount_of_money_normalize_unit() {
+        assert_eq!(amount("USD"), amount("$").normalize_unit());
+        assert_eq!(amount("USD"), amount("USD").normalize_unit());
+        assert_eq!(amount("GBP"), amount("£").normalize_unit());
+        assert_eq!(amount("KR"), amount("KR").normalize_unit());
+        assert_eq!(amount("cent"), amount("cent").normalize_unit());
+        assert!(!amount("$").has_iso_unit());
+        assert!(amount("$").normalize_unit().has_iso_unit());
+        assert!(!amount("KR").has_iso_unit());
+    }
+
+    #[test]
+    fn test_temperature_to_unit() {
+        let points = vec![
+            (0.0, 32.0, 273.15),
+            (100.0, 212.0, 373.15),
+        ];
+        for (celsius, fahrenheit, kelvin) in points {
+            let scales = vec![
+                temperature(celsius, "celsius"),
+                temperature(fahrenheit, "fahrenheit"),
+                temperature(kelvin, "kelvin"),
+            ];
+            for from in scales.iter() {
+                assert_temperature(scales[0], from.to_unit(TemperatureUnit::Celsius));
+                assert_temperature(scales[1], from.to_unit(TemperatureUnit::Fahrenheit));
+                assert_temperature(scales[2], from.to_unit(TemperatureUnit::Kelvin));
+            }
+        }
+    }
+
+    #[test]
+    fn test_temperature_without_scale_to_unit() {
+        let degrees = TemperatureOutput {
+            value: 20.0,
+            unit: Some("degree"),
+            latent: true,
+            qualifier: None,
+        };
+        assert_eq!(degrees, degrees.to_unit(TemperatureUnit::Kelvin));
+        let latent = TemperatureOutput {
+            value: 20.0,
+            unit: None,
+            latent: true,
+            qualifier: None,
+        };
+        assert_eq!(latent, latent.to_unit(TemperatureUnit::Celsius));
+    }
+
+    #[test]
+    fn test_temperature_qualifier() {
+        let temperature = |qualifier| TemperatureOutput {
+            value: -5.0,
+            unit: Some("degree"),
+            latent: false,
+            qualifier,
+        };
+        let wind_chill = temperature(Some(TemperatureQualifier::WindChill)).qualifier_or_actual();
+        assert_eq!(TemperatureQualifier::WindChill, wind_chill);
+        let actual = temperature(None).qualifier_or_actual();
+        assert_eq!(TemperatureQualifier::Actual, actual);
+    }
+
+    #[test]
+    fn test_recurring_occurrences() {
+        let every_tuesday = DatetimeRecurringOutput {
+            first: datetime(Grain::Minute),
+            period: PeriodComp::weeks(1).into(),
+            interval: 1,
+        };
+        let day = |d, m| Interval::starting_at(Moment(Local.ymd(2013, m, d).and_hms(0, 0, 0)), Grain::Day);
+        let february = day(13, 2).to(day(1, 3));
+        let moments = every_tuesday
+            .occurrences(february)
+            .into_iter()
+            .map(|occurrence| occurrence.moment)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Moment(Local.ymd(2013, 2, 19).and_hms(4, 30, 0)),
+                Moment(Local.ymd(2013, 2, 26).and_hms(4, 30, 0)),
+            ],
+            moments
+        );
+        let never = DatetimeRecurringOutput {
+            period: Period::default(),
+            ..every_tuesday
+        };
+        assert!(never.occurrences(february).is_empty());
+        let every_third_day = DatetimeRecurringOutput {
+            period: PeriodComp::days(1).into(),
+            interval: 3,
+            ..every_tuesday
+        };
+        assert_eq!(Period::from(PeriodComp::days(3)), every_third_day.stride());
+        let days = every_third_day
+            .occurrences(day(12, 2).to(day(20, 2)))
+            .into_iter()
+            .map(|occurrence| occurrence.moment.day())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![12, 15, 18], days);
+    }
+
+    fn duration(period: Period) -> DurationOutput {
+        DurationOutput {
+            period,
+            precision: Precision::Exact,
+        }
+    }
+
+    #[test]
+    fn test_duration_to_seconds() {
+        assert_eq!(Some(5400), duration(PeriodComp::minutes(90).into()).to_seconds());
+        assert_eq!(Some(172800), duration(PeriodComp::days(2).into()).to_seconds());
+        assert_eq!(None, duration(PeriodComp::months(1).into()).to_seconds());
+        assert_eq!(Some(250), duration(PeriodComp::milliseconds(250).into()).to_milliseconds());
+        assert_eq!(Some(0), duration(PeriodComp::milliseconds(250).into()).to_seconds());
+        assert_eq!(
+            Some(61500),
+            duration(Period::from(PeriodComp::minutes(1)) + PeriodComp::milliseconds(1500)).to_milliseconds()
+        );
+    }
+
+    #[test]
+    fn test_duration_components() {
+        let compound = duration(
+            Period::from(PeriodComp::years(1))
+                + PeriodComp::quarters(1)
+                + PeriodComp::months(2)
+                + PeriodComp::weeks(3)
+                + PeriodComp::days(4)
+                + PeriodComp::hours(5)
+                + PeriodComp::minutes(6)
+                + PeriodComp::seconds(7)
+                + PeriodComp::milliseconds(8),
+        );
+        assert_eq!(1, compound.years());
+        assert_eq!(5, compound.months());
+        assert_eq!(3, compound.weeks());
+        assert_eq!(4, compound.days());
+        assert_eq!(5, compound.hours());
+        assert_eq!(6, compound.minutes());
+        assert_eq!(7, compound.seconds());
+        assert_eq!(8, compound.milliseconds());
+        assert!(compound.is_calendar());
+        let decades = duration(Period::from(PeriodComp::decades(2)) + PeriodComp::years(3));
+        assert_eq!(23, decades.years());
+        assert!(decades.is_calendar());
+        let hours = duration(Period::from(PeriodComp::hours(1)) + PeriodComp::minutes(30));
+        assert_eq!(0, hours.years());
+        assert_eq!(0, hours.days());
+        assert_eq!(30, hours.minutes());
+        assert!(!hours.is_calendar());
+        assert!(!duration(PeriodComp::months(0).into()).is_calendar());
+    }
+
+    #[test]
+    fn test_duration_normalized() {
+        assert_eq!(
+            duration(Period::from(PeriodComp::hours(1)) + PeriodComp::minutes(30)),
+            duration(PeriodComp::minutes(90).into()).normalized()
+        );
+        assert_eq!(
+            duration(PeriodComp::hours(1).into()),
+            duration(PeriodComp::minutes(60).into()).normalized()
+        );
+        assert_eq!(
+            duration(PeriodComp::days(2).into()),
+            duration(PeriodComp::days(2).into()).normalized()
+        );
+        assert_eq!(
+            duration(PeriodComp::months(1).into()),
+            duration(PeriodComp::months(1).into()).normalized()
+        );
+        assert_eq!(
+            duration(Period::from(PeriodComp::seconds(1)) + PeriodComp::milliseconds(250)),
+            duration(PeriodComp::milliseconds(1250).into()).normalized()
+        );
+    }
+
+    #[test]
+    fn test_datetime_to_grain() {
+        let between = |output: DatetimeIntervalOutput| match output.interval_kind {
+            DatetimeIntervalKind::Between { start, end, grain, .. } => (start, end, grain),
+            other => panic!("{:?}", other),
+        };
+        // 3pm on friday the 15th
+        let friday = DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 15).and_hms(15, 0, 0)),
+            ..datetime(Grain::Minute)
+        };
+        let week = friday.to_grain(Grain::Week);
+        assert_eq!(
+            (
+                Moment(Local.ymd(2013, 2, 11).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 2, 18).and_hms(0, 0, 0)),
+                Grain::Week
+            ),
+            between(week)
+        );
+        assert_eq!(DatetimeKind::DatePeriod, week.datetime_kind);
+        assert_eq!(
+            (
+                Moment(Local.ymd(2013, 2, 1).and_hms(0, 0, 0)),
+                Moment(Local.ymd(2013, 3, 1).and_hms(0, 0, 0)),
+                Grain::Month
+            ),
+            between(friday.to_grain(Grain::Month))
+        );
+        let second = friday.to_grain(Grain::Second);
+        assert_eq!(
+            (friday.moment, Moment(Local.ymd(2013, 2, 15).and_hms(15, 0, 1)), Grain::Second),
+            between(second)
+        );
+        assert_eq!(DatetimeKind::TimePeriod, second.datetime_kind);
+    }
+
+    #[test]
+    fn test_datetime_end_moment() {
+        let day = DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 12).and_hms(0, 0, 0)),
+            ..datetime(Grain::Day)
+        };
+        assert_eq!(Moment(Local.ymd(2013, 2, 13).and_hms(0, 0, 0)), day.end_moment());
+        let month = DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 1).and_hms(0, 0, 0)),
+            ..datetime(Grain::Month)
+        };
+        assert_eq!(Moment(Local.ymd(2013, 3, 1).and_hms(0, 0, 0)), month.end_moment());
+        assert_eq!(Moment(Local.ymd(2013, 2, 12).and_hms(4, 31, 0)), datetime(Grain::Minute).end_moment());
+        // The day the clocks go forward in Paris lasts 23 hours
+        let paris = Tz::Europe__Paris;
+        let day = DatetimeOutput {
+            moment: Moment(Local.ymd(2024, 3, 31).and_hms(0, 0, 0)).from_wall_clock_in(&paris),
+            timezone: Some(paris),
+            ..datetime(Grain::Day)
+        };
+        let end = day.end_moment();
+        assert_eq!(Moment(Local.ymd(2024, 4, 1).and_hms(0, 0, 0)).from_wall_clock_in(&paris), end);
+        assert_eq!(23 * 3600, (end.0 - day.moment.0).num_seconds());
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let minute = DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 12).and_hms(4, 31, 0)),
+            ..datetime(Grain::Minute)
+        };
+        let day = DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 12).and_hms(0, 0, 0)),
+            ..datetime(Grain::Day)
+        };
+        assert!(minute.approx_eq(&day));
+        assert!(day.approx_eq(&minute));
+        assert!(!minute.approx_eq(&datetime(Grain::Minute)));
+        let next_day = DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 13).and_hms(0, 0, 0)),
+            ..day
+        };
+        assert!(!next_day.approx_eq(&minute));
+        assert!(FloatOutput(0.1 + 0.2).approx_eq(&FloatOutput(0.3), 1e-9));
+        assert!(!FloatOutput(0.31).approx_eq(&FloatOutput(0.3), 1e-9));
+        assert!(PercentageOutput(33.333).approx_eq(&PercentageOutput(100.0 / 3.0), 1e-2));
+    }
+
+    #[test]
+    fn test_dedup_key() {
+        let key = |datetime: DatetimeOutput| Output::Datetime(datetime).dedup_key();
+        let minute = DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 20)),
+            ..datetime(Grain::Minute)
+        };
+        let latent = DatetimeOutput {
+            latent: true,
+            precision: Precision::Approximate,
+            was_12_hour: true,
+            ..datetime(Grain::Minute)
+        };
+        assert_eq!(key(minute), key(latent));
+        assert_ne!(key(minute), key(datetime(Grain::Hour)));
+        assert_ne!(key(minute), Output::DatetimeInterval(minute.to_grain(Grain::Minute)).dedup_key());
+        assert_eq!(
+            Output::Float(FloatOutput(0.0)).dedup_key(),
+            Output::Float(FloatOutput(-0.0)).dedup_key()
+        );
+        assert_ne!(
+            Output::Float(FloatOutput(3.0)).dedup_key(),
+            Output::Percentage(PercentageOutput(3.0)).dedup_key()
+        );
+    }
+
+    #[test]
+    fn test_is_latent() {
+        let latent = DatetimeOutput {
+            latent: true,
+            ..datetime(Grain::Hour)
+        };
+        let outputs = vec![
+            Output::Datetime(latent),
+            Output::Datetime(datetime(Grain::Hour)),
+            Output::Temperature(TemperatureOutput {
+                value: 20.0,
+                unit: None,
+                latent: true,
+                qualifier: None,
+            }),
+            Output::DatetimeInterval(DatetimeIntervalOutput {
+                interval_kind: DatetimeIntervalKind::Before {
+                    datetime: latent,
+                    inclusive: false,
+                },
+                datetime_kind: DatetimeKind::TimePeriod,
+                timezone: None,
+                part_of_period: None,
+            }),
+            Output::Integer(IntegerOutput(20)),
+        ];
+        assert_eq!(
+            vec![false, true, false, false, true],
+            outputs.iter().map(|output| !output.is_latent()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Output::Datetime(datetime(Grain::Hour)), Output::Integer(IntegerOutput(20))],
+            retain_non_latent(outputs)
+        );
+    }
+
+    #[test]
+    fn test_resolved_set() {
+        let set = vec![
+            Output::Integer(IntegerOutput(3)),
+            Output::Datetime(datetime(Grain::Day)),
+            Output::Integer(IntegerOutput(5)),
+            Output::Float(FloatOutput(1.5)),
+        ]
+        .into_iter()
+        .collect::<ResolvedSet>();
+        assert_eq!(4, set.len());
+        assert_eq!(&[IntegerOutput(3), IntegerOutput(5)], set.integers());
+        assert_eq!(&[FloatOutput(1.5)], set.floats());
+        assert_eq!(&[datetime(Grain::Day)], set.datetimes());
+        assert!(set.durations().is_empty());
+        assert!(ResolvedSet::default().is_empty());
+    }
+
+    #[test]
+    fn test_datetime_kind() {
+        let date = datetime(Grain::Day).datetime_kind(DatetimeKind::Date);
+        assert_eq!(Some(DatetimeKind::Date), Output::Datetime(date).datetime_kind());
+        assert_eq!(None, Output::Integer(IntegerOutput(3)).datetime_kind());
+        let between = |datetime_kind| DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Between {
+                start: date.moment,
+                end: date.moment + PeriodComp::days(2),
+                grain: Grain::Day,
+                start_grain: Grain::Day,
+                end_grain: Grain::Day,
+                precision: Precision::Exact,
+                latent: false,
+            },
+            datetime_kind,
+            timezone: None,
+            part_of_period: None,
+        };
+        assert!(between(DatetimeKind::DatePeriod).is_kind_consistent());
+        assert!(!between(DatetimeKind::Date).is_kind_consistent());
+        let after = DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::After {
+                datetime: date,
+                inclusive: true,
+            },
+            datetime_kind: DatetimeKind::Date,
+            timezone: None,
+            part_of_period: None,
+        };
+        assert!(after.is_kind_consistent());
+        assert_eq!(
+            Some(DatetimeKind::DatePeriod),
+            Output::DatetimeInterval(between(DatetimeKind::DatePeriod)).datetime_kind()
+        );
+    }
+
+    #[test]
+    fn test_number_range_contains() {
+        let between = NumberRangeOutput {
+            low: 5.0,
+            high: 10.0,
+            low_inclusive: true,
+            high_inclusive: true,
+        };
+        assert!(between.contains(5.0) && between.contains(10.0));
+        assert!(!between.contains(4.5) && !between.contains(10.5));
+        let excluded = NumberRangeOutput {
+            high_inclusive: false,
+            ..between
+        };
+        assert!(!excluded.contains(10.0));
+    }
+
+    #[test]
+    fn test_number_comparison_accepts() {
+        let more = NumberComparisonOutput {
+            op: CompareOp::Gt,
+            value: 100.0,
+        };
+        assert!(more.accepts(101.0) && !more.accepts(100.0));
+        let at_most = NumberComparisonOutput {
+            op: CompareOp::Le,
+            value: 50.0,
+        };
+        assert!(at_most.accepts(50.0) && !at_most.accepts(51.0));
+    }
+}
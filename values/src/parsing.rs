@@ -0,0 +1,32 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+pub trait ParsingContext<V> {
+    type O;
+    fn resolve(&self, value: &V) -> Option<Self::O>;
+
+    /// Resolves each of the given values, in the same order
+    fn resolve_all(&self, values: &[V]) -> Vec<Option<Self::O>> {
+        values.iter().map(|value| self.resolve(value)).collect()
+    }
+}
+
+pub struct IdentityContext<V: Clone> {
+    _phantom: PhantomData<V>,
+}
+
+impl<V: Clone> IdentityContext<V> {
+    pub fn new() -> IdentityContext<V> {
+        IdentityContext {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<V: Clone> ParsingContext<V> for IdentityContext<V> {
+    type O = V;
+    fn resolve(&self, value: &V) -> Option<V> {
+        Some(value.clone())
+    }
+}
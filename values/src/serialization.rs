@@ -0,0 +1,202 @@
+use moment::Tz;
+use serde_crate::de::{self, Deserialize, Deserializer};
+use serde_crate::ser::Serializer;
+use std::sync::Mutex;
+
+/// Units given by the grammars for amounts of money and temperatures, read back without
+/// allocating
+const UNITS: [&str; 28] = [
+    "$", "AED", "AUD", "CAD", "CHF", "CNY", "DKK", "EUR", "GBP", "HKD", "INR", "JPY", "KR", "KRW",
+    "NOK", "PTS", "RUB", "SEK", "USD", "cent", "£", "¥", "฿", "₽", "celsius", "fahrenheit",
+    "degree", "kelvin",
+];
+
+/// Units read back which none of the grammars give, each one leaked once
+static INTERNED_UNITS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+/// Units are static strings coming from the grammars, deserialized units are mapped back to them,
+/// see `static_unit`.
+pub fn deserialize_unit<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<&'static str>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.map(|unit| static_unit(&unit)))
+}
+
+/// Recurrences written before their interval was added have an occurrence every period
+pub(crate) fn default_interval() -> u32 {
+    1
+}
+
+/// Gives the unit of the grammars equal to `unit`. Units none of them gives, e.g. ones of another
+/// version, are interned: they are leaked the first time and the same string is given afterwards.
+pub(crate) fn static_unit(unit: &str) -> &'static str {
+    if let Some(known) = UNITS.iter().find(|known| **known == unit) {
+        return known;
+    }
+    let mut interned = INTERNED_UNITS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(interned_unit) = interned
+        .iter()
+        .find(|interned_unit| **interned_unit == unit)
+    {
+        return interned_unit;
+    }
+    let leaked: &'static str = Box::leak(unit.to_string().into_boxed_str());
+    interned.push(leaked);
+    leaked
+}
+
+/// Timezones are serialized with their IANA name, e.g. "Europe/Paris"
+pub mod timezone {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(tz: &Option<Tz>, serializer: S) -> Result<S::Ok, S::Error> {
+        match tz {
+            &Some(ref tz) => serializer.serialize_some(tz.name()),
+            &None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Tz>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|name| name.parse::<Tz>().map_err(de::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dimension::*;
+    use crate::output::*;
+    use moment::*;
+
+    fn round_trip(output: Output) {
+        let json = serde_json::to_string(&output).unwrap();
+        assert_eq!(output, serde_json::from_str::<Output>(&json).unwrap());
+    }
+
+    #[test]
+    fn test_serialize_number() {
+        let output = Output::Integer(IntegerOutput(42));
+        assert_eq!(
+            "{\"kind\":\"integer\",\"value\":42}",
+            serde_json::to_string(&output).unwrap()
+        );
+        round_trip(output);
+        round_trip(Output::Float(FloatOutput(4.2)));
+        round_trip(Output::Percentage(PercentageOutput(12.5)));
+        round_trip(Output::Ordinal(OrdinalOutput(3)));
+    }
+
+    #[test]
+    fn test_serialize_datetime() {
+        let datetime = DatetimeOutput {
+            moment: Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            grain: Grain::Hour,
+            precision: Precision::Approximate,
+            latent: false,
+            datetime_kind: DatetimeKind::DatetimeComplement {
+                date_and_time: true,
+                today: false,
+            },
+            timezone: Some(Tz::Europe__Paris),
+            part_of_period: None,
+            was_12_hour: true,
+        };
+        round_trip(Output::Datetime(datetime));
+        round_trip(Output::DatetimeInterval(DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::After {
+                datetime,
+                inclusive: false,
+            },
+            datetime_kind: DatetimeKind::Datetime,
+            timezone: None,
+            part_of_period: None,
+        }));
+        round_trip(Output::DatetimeInterval(DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Between {
+                start: Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+                end: Moment(Local.ymd(2013, 2, 12).and_hms(6, 0, 0)),
+                grain: Grain::Minute,
+                start_grain: Grain::Hour,
+                end_grain: Grain::Minute,
+                precision: Precision::Exact,
+                latent: true,
+            },
+            datetime_kind: DatetimeKind::TimePeriod,
+            timezone: None,
+            part_of_period: Some(PartOfPeriod::End),
+        }));
+    }
+
+    #[test]
+    fn test_serialize_quantities() {
+        round_trip(Output::AmountOfMoney(AmountOfMoneyOutput {
+            value: 10.0,
+            precision: Precision::Exact,
+            unit: Some("USD"),
+            currency: Some(Currency::Usd),
+        }));
+        round_trip(Output::Temperature(TemperatureOutput {
+            value: -3.0,
+            unit: None,
+            latent: false,
+            qualifier: None,
+        }));
+        round_trip(Output::TemperatureRange(TemperatureRangeOutput {
+            low: 18.0,
+            high: 30.0,
+            unit: None,
+        }));
+        round_trip(Output::OrdinalRange(OrdinalRangeOutput { start: 1, end: 3 }));
+        round_trip(Output::Quantity(QuantityOutput {
+            value: 3.0,
+            unit: Some("kilogram".to_string()),
+            product: Some("flour".to_string()),
+        }));
+        round_trip(Output::PhoneNumber(PhoneNumberOutput("+1 (555) 123-4567".to_string())));
+        round_trip(Output::NumberRange(NumberRangeOutput {
+            low: 5.0,
+            high: 10.0,
+            low_inclusive: true,
+            high_inclusive: true,
+        }));
+        round_trip(Output::NumberComparison(NumberComparisonOutput {
+            op: CompareOp::Lt,
+            value: 50.0,
+        }));
+        round_trip(Output::Age(AgeOutput {
+            value: 6.0,
+            unit: Grain::Month,
+        }));
+        round_trip(Output::Custom(CustomOutput {
+            tag: "sku".to_string(),
+            value: "SKU-12345678".to_string(),
+        }));
+        round_trip(Output::Duration(DurationOutput {
+            period: Period::from(PeriodComp::hours(2)),
+            precision: Precision::Approximate,
+        }));
+    }
+
+    #[test]
+    fn test_deserialize_unknown_unit() {
+        let output = Output::Temperature(TemperatureOutput {
+            value: -3.0,
+            unit: Some("kelvin"),
+            latent: false,
+            qualifier: None,
+        });
+        round_trip(output.clone());
+        let json = serde_json::to_string(&output).unwrap().replace("kelvin", "rankine");
+        let unit = |json: &str| match serde_json::from_str::<Output>(json).unwrap() {
+            Output::Temperature(temperature) => temperature.unit.unwrap(),
+            other => panic!("{:?}", other),
+        };
+        assert_eq!("rankine", unit(&json));
+        // The unknown unit is only leaked once
+        assert!(::std::ptr::eq(unit(&json), unit(&json)));
+        assert!(::std::ptr::eq(super::static_unit("kelvin"), super::static_unit("kelvin")));
+    }
+}